@@ -8,6 +8,7 @@
 
 pub mod models {
     pub mod traits;
+    pub mod element;
     pub mod algorithm;
     pub mod metrics;
     pub mod config;
@@ -15,6 +16,7 @@ pub mod models {
     pub mod configuration;
     pub mod interactive_mode;
     pub mod session;
+    pub mod lane;
     pub mod display_mode;
     pub mod memory_metrics;
 }
@@ -28,6 +30,21 @@ pub mod services {
         pub mod heap;
         pub mod merge;
         pub mod shell;
+        pub mod tim;
+        pub mod intro;
+        pub mod pdq;
+        pub mod radix;
+        pub mod counting;
+        pub mod bucket;
+        pub mod cocktail;
+        pub mod comb;
+        pub mod gnome;
+        pub mod odd_even;
+        pub mod cycle;
+        pub mod network;
+        pub(crate) mod budget;
+        pub(crate) mod hybrid;
+        pub(crate) mod buckets;
     }
     
     pub mod fairness {
@@ -35,20 +52,30 @@ pub mod services {
         pub mod weighted;
         pub mod walltime;
         pub mod adaptive;
+        pub mod equal_steps;
+        pub mod factory;
     }
     
     pub mod generator;
+    pub mod source;
+    pub mod lanes;
+    pub mod registry;
     pub mod snapshot;
+    pub mod verify;
     pub mod memory;
 }
 
 pub mod lib {
+    pub mod app;
     pub mod bar_chart;
     pub mod controller;
     pub mod input;
     pub mod interactive;
     pub mod memory_graph;
     pub mod progress;
+    pub mod replay;
+    pub mod report;
     pub mod sparkline;
+    pub mod tournament;
     pub mod visualization;
 }
\ No newline at end of file
@@ -0,0 +1,1313 @@
+//! Command line application shared by the `sorting-race` binary
+//!
+//! Downstream crates can register their own sorters and then call [`run`]
+//! to get the full CLI and terminal UI with those algorithms included.
+
+use anyhow::Result;
+
+// Application constants
+const DEFAULT_ARRAY_SIZE: usize = 50;
+const DEFAULT_SEED: u64 = 12345;
+const DEFAULT_BUDGET: usize = 16;
+const DEFAULT_WALLTIME_SLICE_MS: u64 = 50;
+const TARGET_FPS: u32 = 30;
+const TICK_RATE_MS: u64 = 30;
+const HEADLESS_MAX_SNAPSHOTS: usize = 1;
+const DEFAULT_TOURNAMENT_SEEDS: usize = 5;
+const HISTORY_MAX_SNAPSHOTS: usize = 20_000;
+use clap::{
+    ArgGroup, Parser, ValueEnum,
+    builder::{PossibleValue, TypedValueParser},
+};
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+use crate::{
+    lib::{
+        bar_chart::BarChart, controller::RaceController, interactive::InteractiveConfigMenu,
+        memory_graph::MemoryGraph, progress::ProgressBars,
+        replay::{HistoryCursor, ReplayPlayer},
+        report::{RaceReport, RankBy, ReportFormat},
+        sparkline::SparklineCollection,
+        tournament::{Tournament, TournamentConfig},
+    },
+    models::{
+        config::{Distribution, DistributionParams, FairnessMode, RunConfiguration},
+        configuration::{ConfigurationState, DistributionType},
+        interactive_mode::ApplicationMode,
+        lane::LaneSpec,
+        session::SessionState,
+    },
+    services::{
+        fairness::factory::create_fairness_model,
+        generator::ArrayGenerator,
+        lanes::create_lanes,
+        source::{InputFormat, InputSource},
+        registry::registered_sorters,
+        snapshot::{AlgorithmSnapshot, SnapshotService},
+        verify::Stability,
+    },
+};
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+#[command(group(ArgGroup::new("batch").args(["headless", "tournament"])))]
+#[command(group(ArgGroup::new("mode").args(["headless", "tournament", "replay"])))]
+struct Args {
+    /// Array size for sorting
+    #[arg(short, long, default_value_t = DEFAULT_ARRAY_SIZE)]
+    size: usize,
+
+    /// Random seed for deterministic execution
+    #[arg(short = 'S', long, default_value_t = DEFAULT_SEED)]
+    seed: u64,
+
+    /// Distribution type; give a parameterized one its parameter inline with
+    /// `name=value`, e.g. `nearly-sorted=5`, `uniform=1..10` or `antiqsort=quick+median3`
+    #[arg(short, long, value_name = "TYPE", value_parser = DistributionArgParser, default_value = "shuffled")]
+    distribution: DistributionArg,
+
+    /// Standard deviation of the gaussian distribution, as a fraction of the array size
+    #[arg(long)]
+    stddev: Option<f64>,
+
+    /// Exponent of the zipf distribution; larger values mean fewer distinct values
+    #[arg(long)]
+    zipf_exponent: Option<f64>,
+
+    /// Inclusive value range of the uniform distribution, e.g. `1..100`
+    #[arg(long, value_parser = parse_value_range)]
+    value_range: Option<(i32, i32)>,
+
+    /// Number of sorted runs in the runs distribution
+    #[arg(long)]
+    runs: Option<usize>,
+
+    /// Percentage of elements swapped out of place in the nearly-sorted distribution
+    #[arg(long)]
+    swap_percent: Option<f64>,
+
+    /// Race the integers in a file instead of a generated array; `-` reads stdin
+    #[arg(short, long, value_name = "FILE", value_parser = InputSource::parse,
+          conflicts_with_all = ["size", "distribution", "tournament", "replay"])]
+    input: Option<InputSource>,
+
+    /// Layout of the --input values (default: from the file extension, or
+    /// JSON for stdin starting with `[` and whitespace-separated text otherwise)
+    #[arg(long, value_enum, requires = "input")]
+    input_format: Option<InputFormat>,
+
+    /// Lane the antiqsort distribution builds its worst case against, e.g. `quick+median3`
+    /// (default: quick)
+    #[arg(long, value_parser = LaneSpec::parse)]
+    adversary: Option<LaneSpec>,
+
+    /// Fairness mode
+    #[arg(short, long, value_enum, default_value_t = FairnessKind::Comp)]
+    fair: FairnessKind,
+
+    /// Comparison budget for comp fairness mode (element operations for radix, counting and bucket sorts)
+    #[arg(short = 'k', long, default_value_t = DEFAULT_BUDGET)]
+    budget: usize,
+
+    /// Alpha weight for weighted fairness (comparisons, or element operations for comparison-free sorts)
+    #[arg(long, default_value_t = 1.0)]
+    alpha: f32,
+
+    /// Beta weight for weighted fairness (moves)
+    #[arg(long, default_value_t = 1.0)]
+    beta: f32,
+
+    /// Learning rate for adaptive fairness (0.0-1.0)
+    #[arg(long, default_value_t = 0.2)]
+    learning_rate: f32,
+
+    /// Time slice per algorithm for walltime fairness, in milliseconds
+    #[arg(long, default_value_t = DEFAULT_WALLTIME_SLICE_MS)]
+    slice_ms: u64,
+
+    /// Run the race without a terminal UI and print the results
+    #[arg(long)]
+    headless: bool,
+
+    /// Run a tournament over every size, distribution, seed and fairness mode
+    #[arg(long)]
+    tournament: bool,
+
+    /// Array sizes for the tournament, comma separated
+    #[arg(long, value_delimiter = ',', default_value = "50,100", requires = "tournament")]
+    sizes: Vec<usize>,
+
+    /// Number of seeds per tournament cell, counting up from --seed
+    #[arg(long, default_value_t = DEFAULT_TOURNAMENT_SEEDS, requires = "tournament")]
+    seeds: usize,
+
+    /// Distributions for the tournament, comma separated, written as for --distribution
+    /// (default: all that take no parameter)
+    #[arg(long, value_name = "TYPE", value_delimiter = ',', value_parser = DistributionArgParser, requires = "tournament")]
+    distributions: Vec<DistributionArg>,
+
+    /// Fairness modes for the tournament, comma separated (default: --fair)
+    #[arg(long, value_enum, value_delimiter = ',', requires = "tournament")]
+    fairness_modes: Vec<FairnessKind>,
+
+    /// Output format for headless and tournament results
+    #[arg(long, value_enum, default_value_t = ReportFormat::Json, requires = "batch")]
+    format: ReportFormat,
+
+    /// Metric to rank headless results by, or to decide tournament wins by
+    #[arg(long, value_enum, default_value_t = RankBy::Finish, requires = "batch")]
+    rank_by: RankBy,
+
+    /// Write headless or tournament results to a file instead of stdout
+    #[arg(short, long, requires = "batch")]
+    output: Option<PathBuf>,
+
+    /// Record every snapshot of a headless race to a JSON-lines file
+    #[arg(long, requires = "headless")]
+    record: Option<PathBuf>,
+
+    /// Algorithms to race, comma separated; repeat one with `key:label` for extra lanes
+    /// and pick a variant with `key+option`, e.g. `quick+median3+hoare`
+    /// (see --list-algos for keys and options; default: all)
+    #[arg(long, value_delimiter = ',', value_parser = LaneSpec::parse, conflicts_with = "replay")]
+    algos: Vec<LaneSpec>,
+
+    /// Check whether each algorithm keeps equal keys in input order
+    /// (most telling with few-unique input)
+    #[arg(long)]
+    check_stability: bool,
+
+    /// List the available algorithms and exit
+    #[arg(long, exclusive = true)]
+    list_algos: bool,
+
+    /// Replay a race recorded with --record
+    #[arg(long, value_name = "FILE")]
+    replay: Option<PathBuf>,
+}
+
+/// Fairness modes accepted by --fair and --fairness-modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum FairnessKind {
+    /// Equal comparison budget per step (--budget)
+    Comp,
+    /// Budget weighted by comparisons and moves (--alpha, --beta)
+    Weighted,
+    /// Equal wall-clock time per step (--slice-ms)
+    Walltime,
+    /// Budget learned from each algorithm's progress (--learning-rate)
+    Adaptive,
+    /// One operation per algorithm per step
+    EqualSteps,
+}
+
+/// Distribution named on the command line, with an optional inline parameter
+#[derive(Debug, Clone)]
+struct DistributionArg {
+    kind: DistributionType,
+    parameter: Option<String>,
+}
+
+impl DistributionArg {
+    /// Parse `name` or `name=value`, checking the name and the parameter
+    fn parse(s: &str) -> std::result::Result<Self, String> {
+        let (name, parameter) = match s.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (s, None),
+        };
+        let kind = DistributionType::from_str(name, false).map_err(|_| {
+            let names: Vec<_> = DistributionType::value_variants()
+                .iter()
+                .filter_map(|kind| kind.to_possible_value())
+                .map(|value| value.get_name().to_string())
+                .collect();
+            format!("unknown distribution '{}' (expected one of: {})", name, names.join(", "))
+        })?;
+
+        let arg = Self { kind, parameter };
+        arg.apply(&mut DistributionParams::default())?;
+        Ok(arg)
+    }
+
+    /// Set the inline parameter, if any, on `params`
+    fn apply(&self, params: &mut DistributionParams) -> std::result::Result<(), String> {
+        let Some(value) = &self.parameter else {
+            return Ok(());
+        };
+        let name = self.kind.to_possible_value().map(|value| value.get_name().to_string()).unwrap_or_default();
+        let invalid = || format!("invalid parameter '{}' for distribution '{}'", value, name);
+        match self.kind {
+            DistributionType::NearlySorted => params.swap_percent = Some(value.parse().map_err(|_| invalid())?),
+            DistributionType::Gaussian => params.stddev = Some(value.parse().map_err(|_| invalid())?),
+            DistributionType::Zipf => params.zipf_exponent = Some(value.parse().map_err(|_| invalid())?),
+            DistributionType::Uniform => params.value_range = Some(parse_value_range(value)?),
+            DistributionType::Runs => params.runs = Some(value.parse().map_err(|_| invalid())?),
+            DistributionType::Antiqsort => params.adversary = Some(LaneSpec::parse(value)?),
+            _ => return Err(format!("distribution '{}' takes no parameter", name)),
+        }
+        Ok(())
+    }
+}
+
+/// Value parser for [`DistributionArg`] that lists every distribution kind
+/// in `--help`
+#[derive(Debug, Clone, Copy)]
+struct DistributionArgParser;
+
+impl TypedValueParser for DistributionArgParser {
+    type Value = DistributionArg;
+
+    fn parse_ref(&self, cmd: &clap::Command, arg: Option<&clap::Arg>, value: &OsStr) -> std::result::Result<DistributionArg, clap::Error> {
+        DistributionArg::parse.parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(DistributionType::value_variants().iter().filter_map(|kind| kind.to_possible_value())))
+    }
+}
+
+/// Parse the command line and run the selected mode
+pub fn run() -> Result<()> {
+    let args = Args::parse();
+
+    if args.list_algos {
+        return list_algorithms();
+    }
+
+    let distribution = parse_distribution(&args.distribution, &args)?;
+    let fairness_mode = parse_fairness_mode(args.fair, &args);
+    let input = match &args.input {
+        Some(source) => Some(Input {
+            data: source.load(args.input_format)?,
+            source: source.clone(),
+        }),
+        None => None,
+    };
+
+    // Create configuration
+    let config = RunConfiguration {
+        array_size: input.as_ref().map_or(args.size, |input| input.data.len()),
+        distribution,
+        seed: args.seed,
+        fairness_mode,
+        target_fps: TARGET_FPS,
+        algorithms: if args.algos.is_empty() {
+            LaneSpec::defaults()
+        } else {
+            LaneSpec::with_unique_labels(args.algos.clone())
+        },
+        check_stability: args.check_stability,
+    };
+    config.validate().map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))?;
+
+    if args.headless {
+        return run_headless(config, input, args.format, args.rank_by, args.output, args.record);
+    }
+
+    if args.tournament {
+        let distributions = if args.distributions.is_empty() {
+            Distribution::all()
+        } else {
+            args.distributions
+                .iter()
+                .map(|d| parse_distribution(d, &args))
+                .collect::<Result<_>>()?
+        };
+        let fairness_modes = if args.fairness_modes.is_empty() {
+            vec![config.fairness_mode.clone()]
+        } else {
+            args.fairness_modes.iter().map(|&kind| parse_fairness_mode(kind, &args)).collect()
+        };
+        let tournament_config = TournamentConfig::new()
+            .with_sizes(args.sizes.clone())
+            .with_distributions(distributions)
+            .with_seed_count(config.seed, args.seeds)
+            .with_fairness_modes(fairness_modes)
+            .with_rank_by(args.rank_by);
+
+        return run_tournament(tournament_config, config.algorithms, args.format, args.output);
+    }
+
+    let replay = match &args.replay {
+        Some(path) => {
+            let frames = SnapshotService::load_recording(path)?;
+            if frames.is_empty() {
+                anyhow::bail!("Recording {} contains no snapshots", path.display());
+            }
+            Some(ReplayPlayer::new(frames))
+        }
+        None => None,
+    };
+
+    // Setup terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Run the application
+    let res = match replay {
+        Some(player) => run_replay(&mut terminal, player),
+        None => run_app(&mut terminal, config, input),
+    };
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(err) = res {
+        println!("{:?}", err)
+    }
+
+    Ok(())
+}
+
+fn list_algorithms() -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    let mut write_row = |columns: [&str; 9]| {
+        let row = format!(
+            "{:<12} {:<16} {:<11} {:<11} {:<11} {:<9} {:<7} {:<9} {}",
+            columns[0], columns[1], columns[2], columns[3], columns[4], columns[5], columns[6], columns[7], columns[8],
+        );
+        writeln!(stdout, "{}", row.trim_end())
+    };
+
+    write_row(["KEY", "NAME", "BEST", "AVERAGE", "WORST", "MEMORY", "STABLE", "IN-PLACE", "OPTIONS"])?;
+    for info in registered_sorters() {
+        let options = info.options.join(",");
+        write_row([
+            info.key,
+            info.name,
+            info.complexity.best,
+            info.complexity.average,
+            info.complexity.worst,
+            info.memory,
+            if info.stable { "yes" } else { "no" },
+            if info.in_place { "yes" } else { "no" },
+            &options,
+        ])?;
+    }
+    Ok(())
+}
+
+/// Build the distribution `arg` names, its inline parameter overriding the
+/// matching parameter flag
+fn parse_distribution(arg: &DistributionArg, args: &Args) -> Result<Distribution> {
+    let mut params = DistributionParams {
+        stddev: args.stddev,
+        zipf_exponent: args.zipf_exponent,
+        value_range: args.value_range,
+        runs: args.runs,
+        swap_percent: args.swap_percent,
+        adversary: args.adversary.clone(),
+    };
+    arg.apply(&mut params).map_err(|e| anyhow::anyhow!(e))?;
+
+    let distribution = arg.kind.with_params(&params);
+    distribution.validate().map_err(|e| anyhow::anyhow!(e))?;
+    Ok(distribution)
+}
+
+/// Parse an inclusive value range written `min..max` or `min..=max`
+fn parse_value_range(s: &str) -> std::result::Result<(i32, i32), String> {
+    let (min, max) = s
+        .split_once("..=")
+        .or_else(|| s.split_once(".."))
+        .ok_or_else(|| format!("expected a range like 1..100, got '{}'", s))?;
+    let parse = |v: &str| v.trim().parse::<i32>().map_err(|e| format!("invalid bound '{}': {}", v, e));
+    Ok((parse(min)?, parse(max)?))
+}
+
+fn parse_fairness_mode(kind: FairnessKind, args: &Args) -> FairnessMode {
+    match kind {
+        FairnessKind::Comp => FairnessMode::ComparisonBudget { k: args.budget },
+        FairnessKind::Weighted => FairnessMode::Weighted {
+            alpha: args.alpha,
+            beta: args.beta,
+        },
+        FairnessKind::Walltime => FairnessMode::WallTime {
+            slice_ms: args.slice_ms,
+        },
+        FairnessKind::Adaptive => FairnessMode::Adaptive {
+            learning_rate: args.learning_rate,
+        },
+        FairnessKind::EqualSteps => FairnessMode::EqualSteps,
+    }
+}
+
+/// Array loaded with --input, raced in place of a generated one
+struct Input {
+    source: InputSource,
+    data: Vec<i32>,
+}
+
+/// Array to race for `config`: the loaded input, or one generated from the
+/// configured seed and distribution
+fn race_array(config: &RunConfiguration, input: Option<&Input>) -> Vec<i32> {
+    match input {
+        Some(input) => input.data.clone(),
+        None => ArrayGenerator::new(config.seed).generate(config.array_size, &config.distribution),
+    }
+}
+
+fn run_headless(
+    config: RunConfiguration,
+    input: Option<Input>,
+    format: ReportFormat,
+    rank_by: RankBy,
+    output: Option<PathBuf>,
+    record: Option<PathBuf>,
+) -> Result<()> {
+    let array = race_array(&config, input.as_ref());
+
+    let mut controller = RaceController::new(
+        create_lanes(&config.algorithms),
+        create_fairness_model(&config.fairness_mode),
+        HEADLESS_MAX_SNAPSHOTS,
+    );
+    if let Some(path) = &record {
+        controller.get_snapshot_service_mut().start_recording(path)?;
+    }
+    controller
+        .start_race(&config, array)
+        .map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))?;
+    controller.run_to_completion(None);
+    controller.get_snapshot_service_mut().stop_recording()?;
+
+    let mut report = RaceReport::from_controller(&controller, &config).with_rank_by(rank_by);
+    if let Some(input) = &input {
+        report = report.with_input(&input.source);
+    }
+    let mut writer = open_output(output)?;
+    report.write_to(&mut writer, format)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn run_tournament(
+    config: TournamentConfig,
+    lanes: Vec<LaneSpec>,
+    format: ReportFormat,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let tournament = Tournament::new(config, Box::new(move || create_lanes(&lanes)));
+    let result = tournament
+        .run()
+        .map_err(|e| anyhow::anyhow!("Invalid tournament configuration: {}", e))?;
+
+    let mut writer = open_output(output)?;
+    result.write_to(&mut writer, format)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn open_output(output: Option<PathBuf>) -> Result<Box<dyn Write>> {
+    Ok(match output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    })
+}
+
+/// Configuration menu showing the settings `config` starts the TUI with
+fn config_menu(config: &RunConfiguration) -> InteractiveConfigMenu {
+    let config_state = ConfigurationState::from_run_config(config);
+    let mut interactive_menu = InteractiveConfigMenu::new();
+    interactive_menu.interactive_mode.set_config(config_state.clone());
+    interactive_menu.config_state = config_state;
+
+    // Force start in Configuration mode for interactive experience
+    interactive_menu.interactive_mode.current_mode = ApplicationMode::Configuration;
+    interactive_menu
+}
+
+/// Pass `key` to the menu, returning whether it handled the key
+///
+/// Errors are shown in the menu rather than ending the TUI.
+fn handle_menu_key(interactive_menu: &mut InteractiveConfigMenu, key: KeyEvent) -> bool {
+    match interactive_menu.handle_key_event(key) {
+        Ok(handled) => handled,
+        Err(e) => {
+            if interactive_menu.interactive_mode.get_error_message().is_none() {
+                interactive_menu.interactive_mode.set_error_message(e.to_string());
+            }
+            true
+        }
+    }
+}
+
+fn run_app<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    config: RunConfiguration,
+    mut input: Option<Input>,
+) -> Result<()> {
+    let mut interactive_menu = config_menu(&config);
+    let mut session_state = SessionState::new();
+    let mut current_config = config;
+
+    // Generate initial array
+    let mut array = race_array(&current_config, input.as_ref());
+    let startup_distribution = current_config.distribution.clone();
+
+    // The controller steps the sorters and keeps the history used for rewinding
+    let mut controller = RaceController::new(
+        create_lanes(&current_config.algorithms),
+        create_fairness_model(&current_config.fairness_mode),
+        HISTORY_MAX_SNAPSHOTS,
+    );
+    start_race(&mut controller, &current_config, &array)?;
+    // Menu settings the current race was started with
+    let mut race_settings = interactive_menu.config_state.clone();
+
+    let mut last_tick = Instant::now();
+    let tick_rate = Duration::from_millis(TICK_RATE_MS);
+    // History position being viewed while paused
+    let mut history_cursor = HistoryCursor::new();
+
+    // Initialize visualization state
+    let mut memory_graph = MemoryGraph::new();
+    let mut sparklines = SparklineCollection::new(50, 1); // 50-point history
+    let mut progress_bars = ProgressBars::new();
+    let mut rendered_step = None;
+
+    loop {
+        let snapshots = controller.get_snapshot_service();
+
+        // Feed the history widgets once per race step
+        if let Some(latest) = snapshots.get_latest_snapshot()
+            && rendered_step != Some(latest.step) {
+                update_widgets(&latest.algorithm_snapshots, &mut memory_graph, &mut sparklines, &mut progress_bars);
+                rendered_step = Some(latest.step);
+        }
+
+        let viewed = history_cursor
+            .viewed(snapshots.len())
+            .and_then(|index| snapshots.get_snapshot(index));
+        let lanes = match &viewed {
+            Some(snapshot) => snapshot.algorithm_snapshots.as_slice(),
+            None => snapshots
+                .get_latest_snapshot()
+                .map(|snapshot| snapshot.algorithm_snapshots.as_slice())
+                .unwrap_or_default(),
+        };
+        for lane in lanes {
+            progress_bars.add_bar(lane.name.as_str(), lane.telemetry.progress_hint);
+        }
+
+        let show_race = matches!(
+            interactive_menu.interactive_mode.current_mode,
+            ApplicationMode::Racing | ApplicationMode::Paused
+        ) && interactive_menu.interactive_mode.config_focus.is_none();
+        let paused = !interactive_menu.is_racing();
+        let history_position = viewed.as_ref().map(|snapshot| (snapshot.step, snapshots.len()));
+
+        terminal.draw(|f| {
+            // Check if we should render the interactive menu overlay
+            if !show_race {
+                // Render the actual interactive menu
+                let area = f.area();
+                interactive_menu.render(area, f.buffer_mut());
+            } else {
+                ui(
+                    f,
+                    lanes,
+                    live_header(&current_config, paused, history_position),
+                    format!(
+                        "Distribution: {:?} | Fairness: {:?}",
+                        current_config.distribution, current_config.fairness_mode
+                    ),
+                    interactive_menu.interactive_mode.array_view_algorithm,
+                    &memory_graph,
+                    &sparklines,
+                    &progress_bars,
+                );
+            }
+        })?;
+
+        let timeout = tick_rate
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or_else(|| Duration::from_secs(0));
+
+        if crossterm::event::poll(timeout)?
+            && let Event::Key(key) = event::read()? {
+                let previous_mode = interactive_menu.interactive_mode.current_mode;
+
+                // Always handle interactive menu events
+                let menu_handled = handle_menu_key(&mut interactive_menu, key);
+
+                // Leaving the configuration screen starts a new race; resuming from
+                // pause continues the current one unless its settings were changed
+                let resumed = previous_mode == ApplicationMode::Paused
+                    && interactive_menu.config_state == race_settings;
+                if previous_mode != ApplicationMode::Racing
+                    && !resumed
+                    && interactive_menu.should_start_new_race()
+                    && let Some(new_run_config) = interactive_menu.get_run_config() {
+                            current_config = new_run_config;
+
+                            // Regenerate array with new configuration; loaded input
+                            // is kept until the size or distribution is changed
+                            if current_config.array_size != array.len()
+                                || current_config.distribution != startup_distribution
+                            {
+                                input = None;
+                            }
+                            array = race_array(&current_config, input.as_ref());
+
+                            // Restart the race with a new fairness model
+                            controller = RaceController::new(
+                                create_lanes(&current_config.algorithms),
+                                create_fairness_model(&current_config.fairness_mode),
+                                HISTORY_MAX_SNAPSHOTS,
+                            );
+                            start_race(&mut controller, &current_config, &array)?;
+                            race_settings = interactive_menu.config_state.clone();
+
+                            // Reset visualization state
+                            memory_graph.reset_all();  // Reset memory data but keep algorithm names
+                            sparklines = SparklineCollection::new(50, 1);
+                            progress_bars = ProgressBars::new();
+                            rendered_step = None;
+
+                            // Start new race, keeping the array view on an existing lane
+                            if interactive_menu.interactive_mode.array_view_algorithm >= current_config.algorithms.len() {
+                                interactive_menu.interactive_mode.set_array_view_algorithm(0);
+                            }
+                            session_state.update_configuration(interactive_menu.config_state.clone());
+                            let _ = session_state.start_new_race();
+                    }
+
+                // Resuming always continues from the latest state
+                if interactive_menu.is_racing() {
+                    history_cursor.follow_latest();
+                }
+
+                    // Handle additional key events not handled by menu
+                    if !menu_handled {
+                        let history_len = controller.get_snapshot_service().len();
+                        let scrubbing = interactive_menu.interactive_mode.current_mode == ApplicationMode::Paused;
+
+                        match key.code {
+                            KeyCode::Char('q') => return Ok(()),
+                            KeyCode::Left if scrubbing => history_cursor.step_back(history_len),
+                            KeyCode::Right if scrubbing => history_cursor.step_forward(history_len),
+                            KeyCode::Home if scrubbing => history_cursor.jump_to_start(),
+                            KeyCode::End if scrubbing => history_cursor.follow_latest(),
+                            KeyCode::Char('r') => {
+                            // Reset with same seed
+                            start_race(&mut controller, &current_config, &array)?;
+                            history_cursor.follow_latest();
+                            rendered_step = None;
+                            // Reset memory tracking
+                            memory_graph.reset_all();
+                        },
+                        KeyCode::Char('k') | KeyCode::Char('b') | KeyCode::Char('f') | KeyCode::Char('a') => {
+                            // Enter configuration mode
+                            interactive_menu.interactive_mode.current_mode = ApplicationMode::Configuration;
+                            history_cursor.follow_latest();
+
+                            // Set specific focus based on key
+                            use crate::models::interactive_mode::ConfigurationField;
+                            match key.code {
+                                KeyCode::Char('k') => {
+                                    interactive_menu.interactive_mode.set_config_focus(ConfigurationField::ArraySize)?;
+                                },
+                                KeyCode::Char('b') => {
+                                    interactive_menu.interactive_mode.set_config_focus(ConfigurationField::Distribution)?;
+                                },
+                                KeyCode::Char('f') => {
+                                    interactive_menu.interactive_mode.set_config_focus(ConfigurationField::FairnessMode)?;
+                                },
+                                KeyCode::Char('a') => {
+                                    interactive_menu.interactive_mode.set_config_focus(ConfigurationField::Algorithms)?;
+                                },
+                                _ => {}
+                            }
+                        },
+                        _ => {},
+                    }
+                }
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            if interactive_menu.is_racing() {
+                controller.step();
+            }
+            last_tick = Instant::now();
+        }
+    }
+}
+
+fn start_race(controller: &mut RaceController, config: &RunConfiguration, array: &[i32]) -> Result<()> {
+    controller
+        .start_race(config, array.to_vec())
+        .map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))
+}
+
+fn run_replay<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    mut player: ReplayPlayer,
+) -> Result<()> {
+    let tick_rate = Duration::from_millis(TICK_RATE_MS);
+    let mut last_tick = Instant::now();
+    let mut selected_lane = 0;
+
+    let mut memory_graph = MemoryGraph::new();
+    let mut sparklines = SparklineCollection::new(50, 1); // 50-point history
+    let mut progress_bars = ProgressBars::new();
+    let mut rendered_position = None;
+
+    loop {
+        // Widgets keep history, so rebuild them from the start after a jump
+        let position = player.position();
+        match rendered_position {
+            Some(previous) if previous <= position => {
+                for frame in &player.frames()[previous + 1..=position] {
+                    update_widgets(&frame.algorithm_snapshots, &mut memory_graph, &mut sparklines, &mut progress_bars);
+                }
+            }
+            _ => {
+                memory_graph = MemoryGraph::new();
+                sparklines = SparklineCollection::new(50, 1);
+                progress_bars = ProgressBars::new();
+                for frame in &player.frames()[..=position] {
+                    update_widgets(&frame.algorithm_snapshots, &mut memory_graph, &mut sparklines, &mut progress_bars);
+                }
+            }
+        }
+        rendered_position = Some(position);
+
+        let frame = &player.frames()[position];
+        let lane_count = frame.algorithm_snapshots.len().max(1);
+        terminal.draw(|f| {
+            ui(
+                f,
+                &frame.algorithm_snapshots,
+                replay_header(&player),
+                format!(
+                    "Frame {}/{} | Step {} | Speed {}x",
+                    position + 1,
+                    player.len(),
+                    frame.step,
+                    player.speed()
+                ),
+                selected_lane,
+                &memory_graph,
+                &sparklines,
+                &progress_bars,
+            );
+        })?;
+
+        let timeout = tick_rate
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or_else(|| Duration::from_secs(0));
+
+        if crossterm::event::poll(timeout)?
+            && let Event::Key(key) = event::read()? {
+                let jump = (player.len() / 10).max(1) as isize;
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') => player.toggle_playback(),
+                    KeyCode::Right => {
+                        player.pause();
+                        player.seek_relative(1);
+                    }
+                    KeyCode::Left => {
+                        player.pause();
+                        player.seek_relative(-1);
+                    }
+                    KeyCode::PageDown => player.seek_relative(jump),
+                    KeyCode::PageUp => player.seek_relative(-jump),
+                    KeyCode::Home => player.seek(0),
+                    KeyCode::End => player.seek(player.len()),
+                    KeyCode::Char('+') | KeyCode::Char('=') => player.speed_up(),
+                    KeyCode::Char('-') => player.slow_down(),
+                    KeyCode::Char('v') => selected_lane = (selected_lane + 1) % lane_count,
+                    _ => {}
+                }
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            player.tick();
+            last_tick = Instant::now();
+        }
+    }
+}
+
+/// Feed one frame of lane data into the history widgets
+fn update_widgets(
+    lanes: &[AlgorithmSnapshot],
+    memory_graph: &mut MemoryGraph,
+    sparklines: &mut SparklineCollection,
+    progress_bars: &mut ProgressBars,
+) {
+    for lane in lanes {
+        let telemetry = &lane.telemetry;
+        let name = lane.name.as_str();
+
+        // Update memory graph with actual memory usage
+        let memory_to_use = if lane.memory_bytes > 0 {
+            lane.memory_bytes
+        } else {
+            // Fall back to telemetry if get_memory_usage returns 0
+            telemetry.memory_current
+        };
+        memory_graph.update_algorithm(name, memory_to_use);
+
+        // Update sparklines
+        sparklines.update(
+            &format!("{}_comparisons", name),
+            telemetry.total_comparisons as f64,
+        );
+        sparklines.update(&format!("{}_moves", name), telemetry.total_moves as f64);
+
+        // Update progress bars
+        progress_bars.add_bar(name, telemetry.progress_hint);
+    }
+}
+
+fn live_header(
+    config: &RunConfiguration,
+    paused: bool,
+    history_position: Option<(usize, usize)>,
+) -> Vec<Line<'static>> {
+    let status = match history_position {
+        Some((step, _)) => Span::styled(
+            format!("REWOUND to step {}", step),
+            Style::default().fg(Color::Magenta),
+        ),
+        None if paused => Span::styled("PAUSED", Style::default().fg(Color::Yellow)),
+        None => Span::styled("RUNNING", Style::default().fg(Color::Green)),
+    };
+    let controls = if paused {
+        "Paused: LEFT/RIGHT to step through history, HOME/END for first/latest, SPACE to resume"
+    } else {
+        "Interactive: 'k' for array size, 'b' for distribution, 'f' for fairness mode"
+    };
+
+    vec![
+        Line::from(vec![
+            Span::styled(
+                "Sorting Race",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" | "),
+            Span::raw(format!(
+                "Seed: {} | Size: {} | ",
+                config.seed, config.array_size
+            )),
+            status,
+        ]),
+        Line::from("Press 'q' to quit, SPACE to pause/resume, 'v' to switch array view, 'r' to restart"),
+        Line::from(controls),
+    ]
+}
+
+fn replay_header(player: &ReplayPlayer) -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled(
+                "Sorting Race Replay",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" | "),
+            if player.is_playing() {
+                Span::styled("PLAYING", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("PAUSED", Style::default().fg(Color::Yellow))
+            },
+        ]),
+        Line::from("Press 'q' to quit, SPACE to play/pause, '+'/'-' to change speed, 'v' to switch array view"),
+        Line::from("Seek: LEFT/RIGHT one frame, PAGE UP/DOWN 10%, HOME/END start/end"),
+    ]
+}
+
+/// Per-lane badge for stability-checked races
+fn stability_badge(stability: Option<Stability>) -> Span<'static> {
+    match stability {
+        Some(Stability::Stable) => Span::styled(" [stable]", Style::default().fg(Color::Green)),
+        Some(Stability::Unstable) => Span::styled(" [unstable]", Style::default().fg(Color::Yellow)),
+        Some(Stability::Unknown) => Span::styled(" [stable?]", Style::default().fg(Color::DarkGray)),
+        None => Span::raw(""),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ui(
+    f: &mut Frame,
+    lanes: &[AlgorithmSnapshot],
+    header_lines: Vec<Line<'static>>,
+    footer_text: String,
+    selected_algorithm_index: usize,
+    memory_graph: &MemoryGraph,
+    sparklines: &SparklineCollection,
+    progress_bars: &ProgressBars,
+) {
+    // Main layout: header, body, footer
+    let main_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Body
+            Constraint::Length(3), // Footer
+        ])
+        .split(f.area());
+
+    // Header
+    let header = Paragraph::new(header_lines).block(Block::default().borders(Borders::ALL));
+    f.render_widget(header, main_chunks[0]);
+
+    // Body layout: array view at top, progress in middle, bottom panels at bottom
+    let body_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(10),     // Array view (full width)
+            Constraint::Length(8),      // Progress bars (full width)
+            Constraint::Min(0),         // Bottom panels (stats, metrics, memory)
+        ])
+        .split(main_chunks[1]);
+
+    // Render bar chart for the selected algorithm (full width at top)
+    if let Some(selected_lane) = lanes.get(selected_algorithm_index) {
+        let telemetry = &selected_lane.telemetry;
+        let array_data = &selected_lane.array_state;
+
+        // Use viewport mode for large arrays
+        let (bar_chart, viewport_indicator) = BarChart::from_array_with_viewport(
+            array_data,
+            &telemetry.highlights,
+            body_chunks[0].width,
+            telemetry.highlights.first().copied()  // Center on first highlight
+        );
+
+        let bar_chart = bar_chart.markers(&telemetry.markers);
+
+        let lane_name = if selected_lane.error.is_some() {
+            format!("{} [INVALID OUTPUT]", selected_lane.name)
+        } else if let Some(mode) = telemetry.markers.mode_label() {
+            format!("{} [{}]", selected_lane.name, mode.to_uppercase())
+        } else {
+            selected_lane.name.clone()
+        };
+        let title = if viewport_indicator.is_empty() {
+            format!("Array View: {} (Press 'v' to switch)", lane_name)
+        } else {
+            format!("Array View: {} {} (Press 'v' to switch)",
+                    lane_name, viewport_indicator)
+        };
+
+        let bar_chart = bar_chart
+            .scale_for_terminal(body_chunks[0].width, body_chunks[0].height)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title),
+            );
+
+        f.render_widget(bar_chart, body_chunks[0]);
+    } else {
+        let empty_chart = Block::default()
+            .borders(Borders::ALL)
+            .title("Array View: No Algorithm");
+        f.render_widget(empty_chart, body_chunks[0]);
+    }
+
+    // Progress bars (full width in middle)
+    let progress_widget = progress_bars
+        .clone()
+        .block(Block::default().borders(Borders::ALL).title("Progress"));
+    f.render_widget(progress_widget, body_chunks[1]);
+
+    // Bottom panels: split horizontally into three sections
+    let bottom_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(33), // Algorithm stats
+            Constraint::Percentage(34), // Sparklines/Metrics
+            Constraint::Percentage(33), // Memory graph
+        ])
+        .split(body_chunks[2]);
+
+    // Algorithm statistics list
+    let items: Vec<ListItem> = lanes
+        .iter()
+        .map(|lane| {
+            let telemetry = &lane.telemetry;
+            let status = if lane.error.is_some() {
+                "✗"
+            } else if lane.is_complete {
+                "✓"
+            } else {
+                "⟳"
+            };
+
+            let mut content = vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<12}", lane.name),
+                        if lane.error.is_some() {
+                            Style::default().fg(Color::Red)
+                        } else if lane.is_complete {
+                            Style::default().fg(Color::Green)
+                        } else {
+                            Style::default().fg(Color::Cyan)
+                        },
+                    ),
+                    Span::raw(format!(
+                        " {} {:3.0}%",
+                        status,
+                        telemetry.progress_hint * 100.0
+                    )),
+                    stability_badge(lane.stability),
+                ]),
+                Line::from({
+                    let actual_memory = lane.memory_bytes;
+                    let memory_display = if actual_memory > 0 {
+                        format!("{:.1}KB", actual_memory as f64 / 1024.0)
+                    } else if telemetry.memory_current > 0 {
+                        format!("{:.1}KB", telemetry.memory_current as f64 / 1024.0)
+                    } else {
+                        "N/A".to_string()
+                    };
+                    // Sorting networks also show how many parallel steps
+                    // their comparisons take
+                    let depth_display = telemetry
+                        .parallel_depth
+                        .map(|depth| format!(" D:{:4}", depth))
+                        .unwrap_or_default();
+                    format!(
+                        "    C:{:5}{} M:{:5} W:{:5} Mem:{}",
+                        telemetry.total_comparisons,
+                        depth_display,
+                        telemetry.total_moves,
+                        telemetry.total_writes,
+                        memory_display
+                    )
+                }),
+            ];
+            if let Some(error) = &lane.error {
+                content.push(Line::from(Span::styled(
+                    format!("    {}", error),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            ListItem::new(content)
+        })
+        .collect();
+
+    let algorithms_list =
+        List::new(items).block(Block::default().borders(Borders::ALL).title("Statistics"));
+    f.render_widget(algorithms_list, bottom_chunks[0]);
+
+    // Sparklines area (simplified text display)
+    let sparkline_text = if !sparklines.is_empty() {
+        let mut text_lines = Vec::new();
+        let algorithm_names: Vec<String> = lanes.iter().map(|lane| lane.name.clone()).collect();
+
+        for name in &algorithm_names {
+            if let Some(comp_sparkline) = sparklines.get(&format!("{}_comparisons", name)) {
+                text_lines.push(Line::from(format!(
+                    "{}: {}",
+                    &name[..name.len().min(8)],
+                    comp_sparkline.render_string()
+                )));
+            }
+        }
+        text_lines
+    } else {
+        vec![Line::from("No sparkline data yet")]
+    };
+
+    let sparklines_widget = Paragraph::new(sparkline_text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Metrics History"),
+    );
+    f.render_widget(sparklines_widget, bottom_chunks[1]);
+
+    // Memory graph in the third bottom panel
+    let memory_title = if memory_graph.is_empty() {
+        "Memory Usage (No data yet - press Space to start race)"
+    } else {
+        "Memory Usage"
+    };
+    let memory_widget = memory_graph
+        .clone()
+        .block(Block::default().borders(Borders::ALL).title(memory_title));
+    f.render_widget(memory_widget, bottom_chunks[2]);
+
+    // Footer
+    let footer = Paragraph::new(footer_text).block(Block::default().borders(Borders::ALL));
+    f.render_widget(footer, main_chunks[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::config::MAX_ARRAY_SIZE;
+    use clap::CommandFactory;
+
+    fn args(argv: &[&str]) -> std::result::Result<Args, clap::Error> {
+        Args::try_parse_from(std::iter::once("sorting-race").chain(argv.iter().copied()))
+    }
+
+    fn press(interactive_menu: &mut InteractiveConfigMenu, code: KeyCode) -> bool {
+        handle_menu_key(interactive_menu, KeyEvent::from(code))
+    }
+
+    #[test]
+    fn test_menu_races_any_command_line_size() {
+        for size in [1, 5, 2000, MAX_ARRAY_SIZE] {
+            let config = RunConfiguration::new().with_array_size(size);
+            let mut interactive_menu = config_menu(&config);
+            assert!(press(&mut interactive_menu, KeyCode::Char(' ')));
+            assert_eq!(interactive_menu.interactive_mode.get_error_message(), None, "size {}", size);
+            assert_eq!(interactive_menu.get_run_config().unwrap().array_size, size);
+        }
+    }
+
+    #[test]
+    fn test_menu_races_loaded_input_of_any_size() {
+        let content = (0..1500).rev().map(|value| value.to_string()).collect::<Vec<_>>().join(" ");
+        let input = Input {
+            data: crate::services::source::parse_array(&content, InputFormat::Text).unwrap(),
+            source: InputSource::Stdin,
+        };
+        let config = RunConfiguration::new().with_array_size(input.data.len());
+        config.validate().unwrap();
+
+        let mut interactive_menu = config_menu(&config);
+        assert!(press(&mut interactive_menu, KeyCode::Char(' ')));
+        assert_eq!(interactive_menu.interactive_mode.get_error_message(), None);
+        let run_config = interactive_menu.get_run_config().unwrap();
+        assert_eq!(run_config.array_size, 1500);
+        assert_eq!(race_array(&run_config, Some(&input)), input.data);
+    }
+
+    #[test]
+    fn test_menu_shows_errors_instead_of_exiting() {
+        let config = RunConfiguration::new().with_array_size(MAX_ARRAY_SIZE + 1);
+        let mut interactive_menu = config_menu(&config);
+        assert!(press(&mut interactive_menu, KeyCode::Char(' ')));
+        assert_eq!(interactive_menu.interactive_mode.current_mode, ApplicationMode::Configuration);
+        let error = interactive_menu.interactive_mode.get_error_message().unwrap();
+        assert!(error.contains("Array size must be between 1 and"), "{}", error);
+        assert!(interactive_menu.get_run_config().is_none());
+    }
+
+    #[test]
+    fn test_every_distribution_parses() {
+        let parsed = args(&[]).unwrap();
+        for kind in DistributionType::value_variants() {
+            let name = kind.to_possible_value().unwrap().get_name().to_string();
+            let arg = DistributionArg::parse(&name).unwrap();
+            let distribution = parse_distribution(&arg, &parsed).unwrap();
+            assert_eq!(DistributionType::from(distribution), *kind, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_distribution_parameters() {
+        let parsed = args(&["--swap-percent", "20", "--adversary", "quick+first"]).unwrap();
+        let nearly_sorted = DistributionArg::parse("nearly-sorted").unwrap();
+        assert_eq!(parse_distribution(&nearly_sorted, &parsed).unwrap(), Distribution::NearlySorted { swap_percent: 20.0 });
+
+        // Inline parameters win over the flags
+        let inline = DistributionArg::parse("nearly-sorted=5").unwrap();
+        assert_eq!(parse_distribution(&inline, &parsed).unwrap(), Distribution::NearlySorted { swap_percent: 5.0 });
+        let antiqsort = DistributionArg::parse("antiqsort").unwrap();
+        assert_eq!(parse_distribution(&antiqsort, &parsed).unwrap().to_string(), "Antiqsort(Quick Sort (first pivot))");
+    }
+
+    #[test]
+    fn test_help_lists_every_distribution() {
+        let help = Args::command().render_long_help().to_string();
+        for kind in DistributionType::value_variants() {
+            let name = kind.to_possible_value().unwrap().get_name().to_string();
+            assert!(help.contains(&format!("- {}:", name)), "{} missing from --help", name);
+        }
+    }
+
+    #[test]
+    fn test_malformed_distributions_are_rejected() {
+        let unknown = DistributionArg::parse("bogus=3").unwrap_err();
+        assert!(unknown.starts_with("unknown distribution 'bogus' (expected one of: shuffled,"), "{}", unknown);
+
+        for kind in DistributionType::value_variants() {
+            let name = kind.to_possible_value().unwrap().get_name().to_string();
+            assert!(DistributionArg::parse(&format!("{}=", name)).is_err(), "{}= was accepted", name);
+        }
+        assert_eq!(DistributionArg::parse("sorted=3").unwrap_err(), "distribution 'sorted' takes no parameter");
+
+        for malformed in ["nearly-sorted=most", "gaussian=wide", "zipf=2x", "runs=-1", "uniform=1", "uniform=1..x", "antiqsort=bogus"] {
+            assert!(DistributionArg::parse(malformed).is_err(), "{} was accepted", malformed);
+        }
+        assert_eq!(DistributionArg::parse("runs=many").unwrap_err(), "invalid parameter 'many' for distribution 'runs'");
+    }
+
+    #[test]
+    fn test_out_of_range_parameters_are_rejected() {
+        let parsed = args(&[]).unwrap();
+        for out_of_range in ["nearly-sorted=150", "gaussian=0", "zipf=-1", "uniform=9..1", "runs=0"] {
+            let arg = DistributionArg::parse(out_of_range).unwrap();
+            assert!(parse_distribution(&arg, &parsed).is_err(), "{} was accepted", out_of_range);
+        }
+
+        // Flags are checked the same way
+        let parsed = args(&["--runs", "0"]).unwrap();
+        assert!(parse_distribution(&DistributionArg::parse("runs").unwrap(), &parsed).is_err());
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        assert!(args(&["--distribution", "bogus"]).is_err());
+        assert!(args(&["--distribution", "sorted=3"]).is_err());
+        assert!(args(&["--distribution", "runs=many"]).is_err());
+        assert!(args(&["--distribution", "uniform=1..x"]).is_err());
+        assert!(args(&["--tournament", "--distributions", "sorted,zipf=steep"]).is_err());
+        assert!(args(&["--fair", "fastest"]).is_err());
+        assert!(args(&["--tournament", "--fairness-modes", "comp,bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_every_fairness_mode_parses() {
+        let parsed = args(&["--fair", "walltime", "--slice-ms", "20"]).unwrap();
+        assert_eq!(parse_fairness_mode(parsed.fair, &parsed), FairnessMode::WallTime { slice_ms: 20 });
+
+        let parsed = args(&["--tournament", "--fairness-modes", "comp,weighted,walltime,adaptive,equal-steps"]).unwrap();
+        let modes: Vec<_> = parsed.fairness_modes.iter().map(|&kind| parse_fairness_mode(kind, &parsed)).collect();
+        assert_eq!(modes.len(), 5);
+        assert_eq!(modes[4], FairnessMode::EqualSteps);
+        assert!(modes.iter().all(|mode| RunConfiguration::new().with_fairness_mode(mode.clone()).validate().is_ok()));
+    }
+}
@@ -1,12 +1,28 @@
 //! BarChart widget for visualizing array data as vertical bars
 
+use crate::models::markers::Markers;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::{Block, Widget},
 };
 
+/// Alternating bar colors for runs waiting on the merge stack
+const RUN_STACK_COLORS: [Color; 2] = [Color::Cyan, Color::Magenta];
+
+/// Alternating bar colors for the buckets of a distribution pass
+const BUCKET_COLORS: [Color; 2] = [Color::Yellow, Color::Blue];
+
+/// Bar color for a range being heap sorted as a fallback
+const HEAPSORT_COLOR: Color = Color::Red;
+
+/// Bar color for a range being insertion sorted
+const INSERTION_COLOR: Color = Color::Green;
+
+/// Alternating label colors for detected natural runs
+const NATURAL_RUN_COLORS: [Color; 2] = [Color::LightGreen, Color::LightBlue];
+
 /// BarChart widget for rendering arrays as vertical bars
 #[derive(Debug, Clone)]
 pub struct BarChart {
@@ -21,6 +37,14 @@ pub struct BarChart {
     block: Option<Block<'static>>,
     highlight_indices: Vec<usize>,
     highlight_style: Style,
+    /// Per-bar style overrides from markers
+    bar_styles: Vec<Option<Style>>,
+    /// Per-label style overrides from markers
+    label_styles: Vec<Option<Style>>,
+    /// Array index shown by the first bar
+    first_index: usize,
+    /// Array elements per bar when sampling
+    index_stride: usize,
 }
 
 impl BarChart {
@@ -38,6 +62,10 @@ impl BarChart {
             block: None,
             highlight_indices: Vec::new(),
             highlight_style: Style::default().fg(Color::Yellow),
+            bar_styles: Vec::new(),
+            label_styles: Vec::new(),
+            first_index: 0,
+            index_stride: 1,
         }
     }
 
@@ -104,6 +132,66 @@ impl BarChart {
         self
     }
 
+    /// Color bars by algorithm markers
+    ///
+    /// Runs on the merge stack and heap sort or insertion sort ranges color
+    /// their bars, detected natural runs color their labels, and galloping
+    /// switches the highlight color. Call this after the constructor so
+    /// marker indices map through the viewport.
+    pub fn markers(mut self, markers: &Markers) -> Self {
+        self.bar_styles = vec![None; self.data.len()];
+        self.label_styles = vec![None; self.data.len()];
+
+        for (bucket, &(start, end)) in markers.buckets.iter().enumerate() {
+            let style = Style::default().fg(BUCKET_COLORS[bucket % 2]);
+            for bar in self.bars_in_range(start, end) {
+                self.bar_styles[bar] = Some(style);
+            }
+        }
+
+        for (run, &(start, end)) in markers.run_stack.iter().enumerate() {
+            let style = Style::default().fg(RUN_STACK_COLORS[run % 2]);
+            for bar in self.bars_in_range(start, end) {
+                self.bar_styles[bar] = Some(style);
+            }
+        }
+
+        let ranges = [
+            (markers.heapsort_range, HEAPSORT_COLOR),
+            (markers.insertion_range, INSERTION_COLOR),
+        ];
+        for (range, color) in ranges {
+            if let Some((start, end)) = range {
+                for bar in self.bars_in_range(start, end) {
+                    self.bar_styles[bar] = Some(Style::default().fg(color));
+                }
+            }
+        }
+
+        for (run, &(start, end)) in markers.runs.iter().enumerate() {
+            let style = Style::default().fg(NATURAL_RUN_COLORS[run % 2]);
+            for bar in self.bars_in_range(start, end) {
+                self.label_styles[bar] = Some(style);
+            }
+        }
+
+        if markers.galloping {
+            self.highlight_style = Style::default().fg(Color::LightGreen).add_modifier(Modifier::BOLD);
+        }
+
+        self
+    }
+
+    /// Bars showing array indices `start..=end`
+    fn bars_in_range(&self, start: usize, end: usize) -> std::ops::Range<usize> {
+        let first = start.saturating_sub(self.first_index) / self.index_stride;
+        let last = match end.checked_sub(self.first_index) {
+            Some(offset) => (offset / self.index_stride + 1).min(self.data.len()),
+            None => 0,
+        };
+        first.min(last)..last
+    }
+
     /// Convert array data to bar chart data with color mapping
     pub fn from_array_with_colors(array: &[i32], highlights: &[usize]) -> Self {
         let data: Vec<(String, u64)> = array
@@ -174,6 +262,7 @@ impl BarChart {
             .collect();
 
         let mut chart = Self::new(data);
+        chart.index_stride = sample_rate;
 
         // Adjust highlights for sampling
         if sample_rate > 1 {
@@ -244,6 +333,7 @@ impl BarChart {
             .collect();
 
         let mut chart = Self::new(visible_data);
+        chart.first_index = start;
 
         // Adjust highlight indices to viewport
         chart.highlight_indices = highlights
@@ -361,8 +451,9 @@ impl BarChart {
             let current_bar_style = if self.highlight_indices.contains(&i) {
                 self.highlight_style
             } else {
-                self.bar_style
+                self.bar_styles.get(i).copied().flatten().unwrap_or(self.bar_style)
             };
+            let current_label_style = self.label_styles.get(i).copied().flatten().unwrap_or(self.label_style);
 
             // Render bar
             for y in 0..bar_height {
@@ -431,7 +522,7 @@ impl BarChart {
                     if char_x < inner_area.right() {
                         buf[(char_x, label_y)]
                             .set_symbol(&ch.to_string())
-                            .set_style(self.label_style);
+                            .set_style(current_label_style);
                     }
                 }
 
@@ -604,4 +695,57 @@ mod tests {
         assert_eq!(chart.data[2], ("-1".to_string(), 0));
         assert_eq!(chart.data[3], ("8".to_string(), 8));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_markers_color_runs_through_viewport() {
+        let array_data: Vec<i32> = (0..200).collect();
+        let (chart, _) = BarChart::from_array_with_viewport(&array_data, &[], 80, Some(100));
+        let markers = Markers {
+            runs: vec![(0, 89), (90, 199)],
+            run_stack: vec![(0, 95), (96, 199)],
+            galloping: true,
+            ..Default::default()
+        };
+        let chart = chart.markers(&markers);
+
+        // The viewport starts at index 90, so only the tail of the first stack run shows
+        let first = chart.first_index;
+        assert_eq!(first, 90);
+        assert_eq!(chart.bar_styles[95 - first].and_then(|style| style.fg), Some(Color::Cyan));
+        assert_eq!(chart.bar_styles[96 - first].and_then(|style| style.fg), Some(Color::Magenta));
+        assert_eq!(chart.label_styles[0].and_then(|style| style.fg), Some(Color::LightBlue));
+        assert_eq!(chart.highlight_style.fg, Some(Color::LightGreen));
+    }
+
+    #[test]
+    fn test_markers_color_fallback_and_insertion_ranges() {
+        let array_data: Vec<i32> = (0..10).collect();
+        let markers = Markers {
+            heapsort_range: Some((0, 3)),
+            insertion_range: Some((6, 9)),
+            ..Default::default()
+        };
+        let chart = BarChart::from_array_with_colors(&array_data, &[]).markers(&markers);
+
+        assert_eq!(chart.bar_styles[3].and_then(|style| style.fg), Some(Color::Red));
+        assert_eq!(chart.bar_styles[4], None);
+        assert_eq!(chart.bar_styles[6].and_then(|style| style.fg), Some(Color::Green));
+    }
+
+    #[test]
+    fn test_markers_alternate_bucket_colors() {
+        let array_data: Vec<i32> = (0..10).collect();
+        let markers = Markers {
+            buckets: vec![(0, 2), (3, 3), (5, 9)],
+            insertion_range: Some((5, 9)),
+            ..Default::default()
+        };
+        let chart = BarChart::from_array_with_colors(&array_data, &[]).markers(&markers);
+
+        assert_eq!(chart.bar_styles[2].and_then(|style| style.fg), Some(Color::Yellow));
+        assert_eq!(chart.bar_styles[3].and_then(|style| style.fg), Some(Color::Blue));
+        assert_eq!(chart.bar_styles[4], None);
+        // The bucket being insertion sorted stands out
+        assert_eq!(chart.bar_styles[7].and_then(|style| style.fg), Some(Color::Green));
+    }
+}
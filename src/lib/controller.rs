@@ -1,20 +1,70 @@
 //! Main controller for the sorting race
 
+use crate::models::algorithm::AlgorithmState;
 use crate::models::config::RunConfiguration;
-use crate::models::traits::{Sorter, FairnessModel};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{FairnessModel, Granularity, Sorter};
 use crate::services::snapshot::SnapshotService;
-use std::time::Instant;
+use crate::services::verify::{LaneVerification, Stability, check_stability, verify_sorted_permutation};
+use std::time::{Duration, Instant};
+
+/// Per-lane bookkeeping collected while the race runs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LaneStats {
+    /// Number of race steps in which this lane received budget and executed
+    pub steps: usize,
+    /// Wall-clock time spent inside this lane's `step` calls
+    pub busy_time: Duration,
+    /// Highest value reported by `get_memory_usage` so far
+    pub peak_memory: usize,
+    /// Race step on which the lane completed
+    pub finish_step: Option<usize>,
+    /// Time since race start at which the lane completed
+    pub finish_time: Option<Duration>,
+    /// Lane state; `Error` when the output failed verification
+    pub state: AlgorithmState,
+    /// Whether equal keys kept their input order, set on completion in stability-checked races
+    pub stability: Option<Stability>,
+}
+
+impl LaneStats {
+    /// Verification error for this lane, if any
+    pub fn error(&self) -> Option<&str> {
+        match &self.state {
+            AlgorithmState::Error(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Verification results to attach to snapshots
+    fn verification(&self) -> LaneVerification {
+        LaneVerification {
+            error: self.error().map(str::to_string),
+            stability: self.stability,
+        }
+    }
+}
 
 /// Main controller for orchestrating the sorting race
+///
+/// Races `i32` arrays by default. Other element types are raced with
+/// [`RaceController::with_comparator`], passing the same ordering the
+/// lanes' sorters use.
 #[derive(Debug)]
-pub struct RaceController {
-    algorithms: Vec<Box<dyn Sorter>>,
-    fairness_model: Box<dyn FairnessModel>,
+pub struct RaceController<T = i32> {
+    algorithms: Vec<Box<dyn Sorter<T>>>,
+    fairness_model: Box<dyn FairnessModel<T>>,
     snapshot_service: SnapshotService,
     current_step: usize,
     start_time: Option<Instant>,
     is_running: bool,
     is_paused: bool,
+    lane_stats: Vec<LaneStats>,
+    finish_order: Vec<usize>,
+    /// Race input sorted ascending, the expected output of every lane
+    sorted_input: Vec<T>,
+    compare: Comparator<T>,
+    check_stability: bool,
 }
 
 impl RaceController {
@@ -23,6 +73,21 @@ impl RaceController {
         algorithms: Vec<Box<dyn Sorter>>,
         fairness_model: Box<dyn FairnessModel>,
         max_snapshots: usize,
+    ) -> Self {
+        Self::with_comparator(algorithms, fairness_model, max_snapshots, Comparator::natural())
+    }
+}
+
+impl<T: Element> RaceController<T> {
+    /// Create a race controller for any element type
+    ///
+    /// `compare` is used to verify lane outputs and must match the ordering
+    /// the sorters were built with.
+    pub fn with_comparator(
+        algorithms: Vec<Box<dyn Sorter<T>>>,
+        fairness_model: Box<dyn FairnessModel<T>>,
+        max_snapshots: usize,
+        compare: Comparator<T>,
     ) -> Self {
         Self {
             algorithms,
@@ -32,13 +97,22 @@ impl RaceController {
             start_time: None,
             is_running: false,
             is_paused: false,
+            lane_stats: Vec::new(),
+            finish_order: Vec::new(),
+            sorted_input: Vec::new(),
+            compare,
+            check_stability: false,
         }
     }
 
     /// Start the race with the given configuration
-    pub fn start_race(&mut self, config: &RunConfiguration, data: Vec<i32>) -> Result<(), String> {
+    pub fn start_race(&mut self, config: &RunConfiguration, data: Vec<T>) -> Result<(), String> {
         config.validate()?;
 
+        self.sorted_input = data.clone();
+        self.sorted_input.sort_by(|a, b| self.compare.compare(a, b));
+        self.check_stability = config.check_stability;
+
         // Reset all algorithms with the same data
         for algorithm in &mut self.algorithms {
             algorithm.reset(data.clone());
@@ -49,9 +123,18 @@ impl RaceController {
         self.is_running = true;
         self.is_paused = false;
         self.snapshot_service.clear();
+        self.lane_stats = vec![
+            LaneStats {
+                state: AlgorithmState::Running,
+                ..LaneStats::default()
+            };
+            self.algorithms.len()
+        ];
+        self.finish_order.clear();
+        self.record_lane_progress();
 
         // Take initial snapshot
-        self.snapshot_service.take_snapshot(&self.algorithms, 0);
+        self.take_snapshot();
 
         Ok(())
     }
@@ -69,18 +152,32 @@ impl RaceController {
 
         // Allocate budgets using fairness model
         let budgets = self.fairness_model.allocate_budget(&self.algorithms);
+        let by_operation = self.fairness_model.granularity() == Granularity::Operation;
 
         // Execute steps for each algorithm
-        for (algorithm, budget) in self.algorithms.iter_mut().zip(budgets.iter()) {
+        for ((algorithm, budget), stats) in self
+            .algorithms
+            .iter_mut()
+            .zip(budgets.iter())
+            .zip(self.lane_stats.iter_mut())
+        {
             if *budget > 0 && !algorithm.is_complete() {
-                algorithm.step(*budget);
+                let started = Instant::now();
+                if by_operation {
+                    algorithm.step_operations(*budget);
+                } else {
+                    algorithm.step(*budget);
+                }
+                stats.busy_time += started.elapsed();
+                stats.steps += 1;
             }
         }
 
         self.current_step += 1;
+        self.record_lane_progress();
 
         // Take snapshot
-        self.snapshot_service.take_snapshot(&self.algorithms, self.current_step);
+        self.take_snapshot();
 
         // Check if race is complete
         if self.is_race_complete() {
@@ -130,6 +227,8 @@ impl RaceController {
         self.is_running = false;
         self.is_paused = false;
         self.snapshot_service.clear();
+        self.lane_stats.clear();
+        self.finish_order.clear();
     }
 
     /// Get current step number
@@ -153,7 +252,7 @@ impl RaceController {
     }
 
     /// Get reference to algorithms
-    pub fn get_algorithms(&self) -> &[Box<dyn Sorter>] {
+    pub fn get_algorithms(&self) -> &[Box<dyn Sorter<T>>] {
         &self.algorithms
     }
 
@@ -171,4 +270,209 @@ impl RaceController {
     pub fn get_fairness_model_name(&self) -> &str {
         self.fairness_model.name()
     }
-}
\ No newline at end of file
+
+    /// Get per-lane statistics, in the same order as the algorithms
+    pub fn get_lane_stats(&self) -> &[LaneStats] {
+        &self.lane_stats
+    }
+
+    /// Get lane indices in the order they completed, excluding lanes that failed verification
+    pub fn get_finish_order(&self) -> &[usize] {
+        &self.finish_order
+    }
+
+    /// Snapshot the current step, flagging lanes that failed verification
+    fn take_snapshot(&mut self) {
+        let verification: Vec<LaneVerification> =
+            self.lane_stats.iter().map(LaneStats::verification).collect();
+        let step = self.current_step;
+        let (sorted_input, compare) = (&self.sorted_input, &self.compare);
+        self.snapshot_service.take_snapshot_with_verification(
+            &self.algorithms,
+            &verification,
+            step,
+            |array| bar_heights(array, sorted_input, compare),
+        );
+    }
+
+    /// Update peak memory and verify newly completed lanes
+    fn record_lane_progress(&mut self) {
+        let elapsed = self.get_elapsed_time().unwrap_or_default();
+
+        for (index, (algorithm, stats)) in self
+            .algorithms
+            .iter()
+            .zip(self.lane_stats.iter_mut())
+            .enumerate()
+        {
+            stats.peak_memory = stats.peak_memory.max(algorithm.get_memory_usage());
+
+            if algorithm.is_complete() && stats.finish_step.is_none() {
+                stats.finish_step = Some(self.current_step);
+                stats.finish_time = Some(elapsed);
+
+                // A lane only places if its output really is the sorted input
+                match verify_sorted_permutation(&self.sorted_input, algorithm.get_array(), &self.compare) {
+                    Ok(()) => {
+                        stats.state = AlgorithmState::Complete;
+                        if self.check_stability {
+                            stats.stability =
+                                Some(check_stability(algorithm.get_array(), algorithm.get_origins(), &self.compare));
+                        }
+                        self.finish_order.push(index);
+                    }
+                    Err(message) => stats.state = AlgorithmState::Error(message),
+                }
+            }
+        }
+    }
+}
+
+/// Bar heights for a lane's array, using element ranks where the type has no height of its own
+fn bar_heights<T: Element>(array: &[T], sorted_input: &[T], compare: &Comparator<T>) -> Vec<i32> {
+    array
+        .iter()
+        .map(|element| {
+            element.bar_height().unwrap_or_else(|| {
+                sorted_input.partition_point(|other| compare.less(other, element)) as i32 + 1
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::fairness::comparison::ComparisonFairness;
+    use crate::models::traits::{StepResult, Telemetry};
+    use crate::services::sorters::{bubble::BubbleSort, merge::MergeSort, selection::SelectionSort};
+    use std::any::Any;
+
+    /// Sorter that claims to finish immediately without sorting anything
+    #[derive(Debug, Default)]
+    struct LazySorter {
+        data: Vec<i32>,
+    }
+
+    impl Sorter for LazySorter {
+        fn step(&mut self, _budget: usize) -> StepResult {
+            StepResult { comparisons_used: 0, moves_made: 0, continued: false }
+        }
+        fn is_complete(&self) -> bool {
+            true
+        }
+        fn get_telemetry(&self) -> Telemetry {
+            Telemetry {
+                total_comparisons: 0,
+                total_moves: 0,
+                total_writes: 0,
+                parallel_depth: None,
+                memory_current: 0,
+                memory_peak: 0,
+                highlights: Vec::new(),
+                markers: Default::default(),
+                status_text: String::new(),
+                progress_hint: 1.0,
+            }
+        }
+        fn reset(&mut self, data: Vec<i32>) {
+            self.data = data;
+        }
+        fn name(&self) -> &str {
+            "Lazy Sort"
+        }
+        fn get_array(&self) -> &[i32] {
+            &self.data
+        }
+        fn get_memory_usage(&self) -> usize {
+            0
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_lane_stats_and_finish_order() {
+        let algorithms: Vec<Box<dyn Sorter>> = vec![
+            Box::new(BubbleSort::new()),
+            Box::new(MergeSort::new()),
+        ];
+        let mut controller = RaceController::new(algorithms, Box::new(ComparisonFairness::new(4)), 1);
+        let data: Vec<i32> = (1..=20).rev().collect();
+
+        controller.start_race(&RunConfiguration::default(), data).unwrap();
+        controller.run_to_completion(None);
+
+        assert!(controller.is_race_complete());
+        let stats = controller.get_lane_stats();
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|lane| lane.steps > 0 && lane.finish_step.is_some()));
+
+        // Merge Sort needs far fewer comparisons on reversed input, so it finishes first
+        assert_eq!(controller.get_finish_order(), &[1, 0]);
+        assert!(stats[1].finish_step < stats[0].finish_step);
+    }
+
+    #[test]
+    fn test_already_sorted_lane_finishes_at_step_zero() {
+        let algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(BubbleSort::new())];
+        let mut controller = RaceController::new(algorithms, Box::new(ComparisonFairness::new(4)), 1);
+
+        controller.start_race(&RunConfiguration::default(), vec![1]).unwrap();
+
+        assert_eq!(controller.get_lane_stats()[0].finish_step, Some(0));
+        assert_eq!(controller.get_finish_order(), &[0]);
+    }
+
+    #[test]
+    fn test_unsorted_output_is_flagged_and_does_not_place() {
+        let algorithms: Vec<Box<dyn Sorter>> = vec![
+            Box::new(LazySorter::default()),
+            Box::new(BubbleSort::new()),
+        ];
+        let mut controller = RaceController::new(algorithms, Box::new(ComparisonFairness::new(4)), 1);
+
+        controller.start_race(&RunConfiguration::default(), vec![3, 1, 2]).unwrap();
+        controller.run_to_completion(None);
+
+        let stats = controller.get_lane_stats();
+        assert!(matches!(stats[0].state, AlgorithmState::Error(_)));
+        assert!(stats[0].error().unwrap().contains("not sorted"));
+        assert_eq!(stats[1].state, AlgorithmState::Complete);
+        assert_eq!(controller.get_finish_order(), &[1]);
+
+        let snapshot = controller.get_snapshot_service().get_latest_snapshot().unwrap();
+        assert!(snapshot.algorithm_snapshots[0].error.is_some());
+        assert!(snapshot.algorithm_snapshots[1].error.is_none());
+    }
+
+    #[test]
+    fn test_stability_is_checked_only_when_enabled() {
+        let lanes = || -> Vec<Box<dyn Sorter>> {
+            vec![Box::new(MergeSort::new()), Box::new(SelectionSort::new())]
+        };
+        // Selection Sort swaps the first 2 past the second one
+        let data = vec![2, 2, 1];
+
+        let mut controller = RaceController::new(lanes(), Box::new(ComparisonFairness::new(4)), 1);
+        controller.start_race(&RunConfiguration::default(), data.clone()).unwrap();
+        controller.run_to_completion(None);
+        assert!(controller.get_lane_stats().iter().all(|lane| lane.stability.is_none()));
+
+        let mut controller = RaceController::new(lanes(), Box::new(ComparisonFairness::new(4)), 1);
+        let config = RunConfiguration::default().with_stability_check(true);
+        controller.start_race(&config, data).unwrap();
+        controller.run_to_completion(None);
+
+        let stats = controller.get_lane_stats();
+        assert_eq!(stats[0].stability, Some(Stability::Stable));
+        assert_eq!(stats[1].stability, Some(Stability::Unstable));
+
+        let snapshot = controller.get_snapshot_service().get_latest_snapshot().unwrap();
+        assert_eq!(snapshot.algorithm_snapshots[1].stability, Some(Stability::Unstable));
+    }
+}
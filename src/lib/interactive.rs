@@ -2,8 +2,9 @@
 
 use crate::models::{
     configuration::{ConfigurationState, DistributionType},
-    config::FairnessMode,
+    config::{FairnessMode, MAX_ARRAY_SIZE},
     interactive_mode::{ApplicationMode, ConfigurationField, InteractiveMode},
+    session::AlgorithmType,
 };
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -16,8 +17,6 @@ use ratatui::{
 };
 
 // Constants for configuration limits
-const TOTAL_ALGORITHMS: usize = 7;
-const MAX_ARRAY_SIZE: u32 = 10000;
 const MAX_BUDGET: u32 = 1000000;
 const MAX_FLOAT_PARAM: f32 = 100.0;
 const MAX_INPUT_LENGTH: usize = 10;
@@ -35,6 +34,8 @@ pub struct InteractiveConfigMenu {
     distribution_index: usize,
     /// Selected fairness mode index for navigation
     fairness_mode_index: usize,
+    /// Highlighted algorithm index in the algorithm selection menu
+    algorithm_index: usize,
     /// Current parameter value being edited (for numeric inputs)
     current_parameter_value: Option<String>,
 }
@@ -48,6 +49,7 @@ impl InteractiveConfigMenu {
             array_size_index: 3, // Default to 100 (index 3 in [10, 25, 50, 100, 200, 500, 1000])
             distribution_index: 0, // Default to Shuffled
             fairness_mode_index: 2, // Default to WallTime
+            algorithm_index: 0,
             current_parameter_value: None,
         }
     }
@@ -72,6 +74,8 @@ impl InteractiveConfigMenu {
                 seed: 12345, // Default seed
                 fairness_mode: self.config_state.fairness_mode.clone(),
                 target_fps: 30,
+                algorithms: self.config_state.lanes.clone(),
+                check_stability: self.config_state.check_stability,
             })
         } else {
             None
@@ -133,6 +137,18 @@ impl InteractiveConfigMenu {
                     Ok(false)
                 }
             }
+            KeyEvent {
+                code: KeyCode::Char('a'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => {
+                if self.interactive_mode.current_mode != ApplicationMode::Racing {
+                    self.interactive_mode.set_config_focus(ConfigurationField::Algorithms)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
             // Help toggle (works in any mode)
             KeyEvent {
                 code: KeyCode::Char('?'),
@@ -148,7 +164,16 @@ impl InteractiveConfigMenu {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => {
-                self.interactive_mode.cycle_array_view_algorithm(TOTAL_ALGORITHMS);
+                self.interactive_mode.cycle_array_view_algorithm(self.config_state.lanes.len());
+                Ok(true)
+            }
+            // Space toggles the highlighted algorithm in the algorithm menu
+            KeyEvent {
+                code: KeyCode::Char(' '),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if self.interactive_mode.config_focus == Some(ConfigurationField::Algorithms) => {
+                self.toggle_highlighted_algorithm();
                 Ok(true)
             }
             // Race control (Space key)
@@ -316,6 +341,29 @@ impl InteractiveConfigMenu {
                     _ => {}
                 }
             }
+            ConfigurationField::Algorithms => {
+                let algorithms = AlgorithmType::all();
+                match key_code {
+                    KeyCode::Up => {
+                        if self.algorithm_index > 0 {
+                            self.algorithm_index -= 1;
+                        } else {
+                            self.algorithm_index = algorithms.len() - 1; // Wrap to end
+                        }
+                    }
+                    KeyCode::Down => {
+                        if self.algorithm_index < algorithms.len() - 1 {
+                            self.algorithm_index += 1;
+                        } else {
+                            self.algorithm_index = 0; // Wrap to beginning
+                        }
+                    }
+                    KeyCode::Left | KeyCode::Right => {
+                        self.cycle_highlighted_variant(key_code == KeyCode::Right);
+                    }
+                    _ => {}
+                }
+            }
             _ => {
                 // Handle parameter fields if needed
             }
@@ -323,6 +371,35 @@ impl InteractiveConfigMenu {
         Ok(())
     }
 
+    /// Add or remove the highlighted algorithm in the lane list
+    fn toggle_highlighted_algorithm(&mut self) {
+        let Some(algorithm) = AlgorithmType::from_index(self.algorithm_index) else {
+            return;
+        };
+
+        match self.config_state.toggle_algorithm(algorithm) {
+            Ok(()) => {
+                self.interactive_mode.set_lanes_interactive(self.config_state.lanes.clone()); // Sync interactive_mode
+                if self.interactive_mode.array_view_algorithm >= self.config_state.lanes.len() {
+                    self.interactive_mode.set_array_view_algorithm(0);
+                }
+            }
+            Err(e) => self.interactive_mode.set_error_message(e.to_string()),
+        }
+    }
+
+    /// Switch the highlighted algorithm's lanes to another variant, e.g. a gap sequence
+    fn cycle_highlighted_variant(&mut self, forward: bool) {
+        let Some(algorithm) = AlgorithmType::from_index(self.algorithm_index) else {
+            return;
+        };
+
+        match self.config_state.cycle_variant(algorithm, forward) {
+            Ok(()) => self.interactive_mode.set_lanes_interactive(self.config_state.lanes.clone()), // Sync interactive_mode
+            Err(e) => self.interactive_mode.set_error_message(e.to_string()),
+        }
+    }
+
     /// Handle confirmation (Enter key) in configuration menus
     fn handle_confirmation(&mut self) -> Result<()> {
         if let Some(field) = self.interactive_mode.config_focus {
@@ -351,6 +428,10 @@ impl InteractiveConfigMenu {
                         self.interactive_mode.clear_config_focus();
                     }
                 }
+                ConfigurationField::Algorithms => {
+                    // Algorithms are toggled with Space; Enter closes the menu
+                    self.interactive_mode.clear_config_focus();
+                }
                 ConfigurationField::BudgetParam => {
                     if let Some(ref value_str) = self.current_parameter_value
                         && let Ok(budget) = value_str.parse::<u32>() {
@@ -442,6 +523,9 @@ impl InteractiveConfigMenu {
                 ConfigurationField::FairnessMode => {
                     self.render_fairness_mode_menu(area, buf);
                 }
+                ConfigurationField::Algorithms => {
+                    self.render_algorithm_menu(area, buf);
+                }
                 _ => {
                     // Render parameter input menus
                 }
@@ -501,7 +585,7 @@ impl InteractiveConfigMenu {
             Line::from(vec![
                 Span::styled("Distribution:   ", Style::default().fg(Color::Yellow)),
                 Span::styled(
-                    format!("{:?}", config.distribution),
+                    config.to_legacy_distribution().to_string(),
                     Style::default().fg(Color::White),
                 ),
                 Span::styled("  [Press 'b' to change]", Style::default().fg(Color::Gray)),
@@ -514,6 +598,19 @@ impl InteractiveConfigMenu {
                 ),
                 Span::styled("  [Press 'f' to change]", Style::default().fg(Color::Gray)),
             ]),
+            Line::from(vec![
+                Span::styled("Algorithms:     ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    config
+                        .lanes
+                        .iter()
+                        .map(|lane| lane.label.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled("  [Press 'a' to change]", Style::default().fg(Color::Gray)),
+            ]),
             Line::from(""),
             Line::from(vec![
                 Span::styled(
@@ -540,10 +637,10 @@ impl InteractiveConfigMenu {
 
         // Instructions
         let instruction_text = match self.interactive_mode.current_mode {
-            ApplicationMode::Configuration => "Press SPACE to start race | k/b/f to configure | v to switch array view | ? for help | q to quit",
+            ApplicationMode::Configuration => "Press SPACE to start race | k/b/f/a to configure | v to switch array view | ? for help | q to quit",
             ApplicationMode::Racing => "Press SPACE to pause | v to switch array view | ? for help | q to quit",
-            ApplicationMode::Paused => "Press SPACE to resume | k/b/f to configure | v to switch array view | ? for help | q to quit",
-            ApplicationMode::Complete => "Press SPACE or k/b/f to configure next race | v to switch array view | ? for help | q to quit",
+            ApplicationMode::Paused => "Press SPACE to resume | k/b/f/a to configure | v to switch array view | ? for help | q to quit",
+            ApplicationMode::Complete => "Press SPACE or k/b/f/a to configure next race | v to switch array view | ? for help | q to quit",
         };
 
         let instructions = Paragraph::new(instruction_text)
@@ -622,7 +719,7 @@ impl InteractiveConfigMenu {
         let distributions = ConfigurationState::get_available_distributions();
         
         // Create popup area
-        let popup_area = self.centered_rect(40, 50, area);
+        let popup_area = self.centered_rect(40, 70, area);
         
         // Clear background
         Clear.render(popup_area, buf);
@@ -643,6 +740,16 @@ impl InteractiveConfigMenu {
                     DistributionType::Reversed => "Reverse sorted",
                     DistributionType::NearlySorted => "Mostly sorted",
                     DistributionType::FewUnique => "Few unique values",
+                    DistributionType::Sorted => "Already sorted",
+                    DistributionType::WithDuplicates => "Half the values repeat",
+                    DistributionType::MedianOfThreeKiller => "Worst case for median-of-3 pivots",
+                    DistributionType::OrganPipe => "Up to the middle, then down",
+                    DistributionType::Sawtooth => "Repeated ascending runs",
+                    DistributionType::Antiqsort => "Worst case for the adversary lane",
+                    DistributionType::Gaussian => "Bell curve around the middle",
+                    DistributionType::Zipf => "Power law, small values common",
+                    DistributionType::Uniform => "Random values in a range",
+                    DistributionType::Runs => "A few sorted runs",
                 };
                 
                 ListItem::new(format!("{:?} - {}", dist, description)).style(style)
@@ -698,7 +805,7 @@ impl InteractiveConfigMenu {
                     FairnessMode::Weighted { .. } => "Weighted by comparisons and moves",
                     FairnessMode::WallTime { .. } => "Equal time slices for each algorithm",
                     FairnessMode::Adaptive { .. } => "Adaptive allocation based on performance",
-                    _ => "Equal steps",
+                    FairnessMode::EqualSteps => "One operation per algorithm each step",
                 };
                 
                 ListItem::new(vec![
@@ -731,6 +838,77 @@ impl InteractiveConfigMenu {
         }
     }
 
+    /// Render algorithm selection menu
+    fn render_algorithm_menu(&self, area: Rect, buf: &mut Buffer) {
+        let config = self.interactive_mode.get_current_config();
+
+        // Create popup area
+        let popup_area = self.centered_rect(50, 80, area);
+
+        // Clear background
+        Clear.render(popup_area, buf);
+
+        // Create menu items, showing how many lanes race each algorithm
+        let items: Vec<ListItem> = AlgorithmType::all()
+            .iter()
+            .enumerate()
+            .map(|(i, &algorithm)| {
+                let style = if i == self.algorithm_index {
+                    Style::default().bg(Color::Blue).fg(Color::White)
+                } else {
+                    Style::default()
+                };
+
+                let lanes: Vec<_> = config.lanes.iter().filter(|lane| lane.algorithm == algorithm).collect();
+                let variant = match lanes.first() {
+                    Some(lane) if !lane.options.is_empty() => format!(" <{}>", lane.options.join("+")),
+                    _ => String::new(),
+                };
+                let label = match lanes.len() {
+                    0 => format!("[ ] {}", algorithm),
+                    1 => format!("[x] {}{}", algorithm, variant),
+                    n => format!("[x] {}{} (x{})", algorithm, variant, n),
+                };
+
+                let info = algorithm.info();
+                let details = format!(
+                    "    avg {}, memory {}{}{}",
+                    info.complexity.average,
+                    info.memory,
+                    if info.stable { ", stable" } else { "" },
+                    if info.in_place { ", in-place" } else { "" },
+                );
+
+                ListItem::new(vec![
+                    Line::from(label),
+                    Line::from(Span::styled(details, Style::default().fg(Color::Gray))),
+                ]).style(style)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Select Algorithms"))
+            .highlight_style(Style::default().bg(Color::Blue));
+
+        list.render(popup_area, buf);
+
+        // Instructions
+        let instruction_area = Rect {
+            x: popup_area.x,
+            y: popup_area.y + popup_area.height,
+            width: popup_area.width,
+            height: 1,
+        };
+
+        if instruction_area.y < area.height {
+            let instructions = Paragraph::new("↑↓ Navigate | ←→ Variant | Space to toggle | Enter to close")
+                .style(Style::default().fg(Color::Gray));
+            instructions.render(instruction_area, buf);
+        }
+    }
+
     /// Render help overlay
     fn render_help_overlay(&self, area: Rect, buf: &mut Buffer) {
         let popup_area = self.centered_rect(80, 80, area);
@@ -806,7 +984,7 @@ impl InteractiveConfigMenu {
         let is_valid = match self.interactive_mode.config_focus {
             Some(ConfigurationField::ArraySize) => {
                 test_value.parse::<u32>()
-                    .map(|v| v <= MAX_ARRAY_SIZE)
+                    .map(|v| v as usize <= MAX_ARRAY_SIZE)
                     .unwrap_or(true)  // Allow incomplete numbers
             }
             Some(ConfigurationField::BudgetParam) => {
@@ -987,4 +1165,55 @@ mod tests {
         assert!(handled);
         assert_eq!(menu.interactive_mode.current_mode, ApplicationMode::Paused);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_algorithm_menu_toggles_lanes() {
+        let mut menu = InteractiveConfigMenu::new();
+        let key = |code| KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::empty(),
+        };
+
+        assert!(menu.handle_key_event(key(KeyCode::Char('a'))).unwrap());
+        assert_eq!(menu.interactive_mode.config_focus, Some(ConfigurationField::Algorithms));
+
+        // Space removes the highlighted algorithm instead of starting the race
+        menu.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        assert_eq!(menu.interactive_mode.current_mode, ApplicationMode::Configuration);
+        assert!(!menu.config_state.has_algorithm(AlgorithmType::BubbleSort));
+        assert_eq!(menu.interactive_mode.get_current_config().lanes, menu.config_state.lanes);
+
+        menu.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        assert!(menu.config_state.has_algorithm(AlgorithmType::BubbleSort));
+
+        menu.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(menu.interactive_mode.config_focus, None);
+
+        menu.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        let run_config = menu.get_run_config().unwrap();
+        assert_eq!(run_config.algorithms, menu.config_state.lanes);
+    }
+
+    #[test]
+    fn test_algorithm_menu_cycles_variants() {
+        let mut menu = InteractiveConfigMenu::new();
+        let key = |code| KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::empty(),
+        };
+
+        menu.handle_key_event(key(KeyCode::Char('a'))).unwrap();
+        menu.algorithm_index = AlgorithmType::ShellSort.to_index();
+        menu.handle_key_event(key(KeyCode::Left)).unwrap();
+
+        let lanes = &menu.interactive_mode.get_current_config().lanes;
+        let shell = lanes.iter().find(|lane| lane.algorithm == AlgorithmType::ShellSort).unwrap();
+        assert_eq!(shell.options, vec!["pratt"]);
+        assert_eq!(shell.label, "Shell Sort (Pratt)");
+        assert_eq!(*lanes, menu.config_state.lanes);
+    }
+}
@@ -1,10 +1,14 @@
 //! Library module
 
+pub mod app;
 pub mod bar_chart;
 pub mod controller;
 pub mod input;
 pub mod interactive;
 pub mod memory_graph;
 pub mod progress;
+pub mod replay;
+pub mod report;
 pub mod sparkline;
+pub mod tournament;
 pub mod visualization;
\ No newline at end of file
@@ -0,0 +1,350 @@
+//! Playback of recorded races and of a live race's history
+
+use crate::services::snapshot::RaceSnapshot;
+
+/// Playback speeds in frames per tick
+pub const REPLAY_SPEEDS: [f32; 8] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0];
+
+/// Index of the 1x speed in `REPLAY_SPEEDS`
+const DEFAULT_SPEED_INDEX: usize = 2;
+
+/// Playback state over a recorded snapshot stream
+///
+/// Playback advances a fixed number of frames per tick, so a recording
+/// plays back identically regardless of how fast the original race ran.
+#[derive(Debug, Clone)]
+pub struct ReplayPlayer {
+    frames: Vec<RaceSnapshot>,
+    position: usize,
+    playing: bool,
+    speed_index: usize,
+    /// Fractional frames carried over between ticks at speeds below 1x
+    pending_frames: f32,
+}
+
+impl ReplayPlayer {
+    /// Create a paused player positioned at the first frame
+    pub fn new(frames: Vec<RaceSnapshot>) -> Self {
+        Self {
+            frames,
+            position: 0,
+            playing: false,
+            speed_index: DEFAULT_SPEED_INDEX,
+            pending_frames: 0.0,
+        }
+    }
+
+    /// Get all recorded frames
+    pub fn frames(&self) -> &[RaceSnapshot] {
+        &self.frames
+    }
+
+    /// Get the number of recorded frames
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Check if the recording has no frames
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Get the current frame index
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the frame at the current position
+    pub fn current_frame(&self) -> Option<&RaceSnapshot> {
+        self.frames.get(self.position)
+    }
+
+    /// Check if playback is running
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Check if the current frame is the last one
+    pub fn is_at_end(&self) -> bool {
+        self.position + 1 >= self.frames.len()
+    }
+
+    /// Start playback, restarting from the beginning when at the end
+    pub fn play(&mut self) {
+        if self.is_at_end() {
+            self.position = 0;
+        }
+        self.playing = true;
+    }
+
+    /// Pause playback
+    pub fn pause(&mut self) {
+        self.playing = false;
+        self.pending_frames = 0.0;
+    }
+
+    /// Toggle between playing and paused
+    pub fn toggle_playback(&mut self) {
+        if self.playing {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Get the playback speed in frames per tick
+    pub fn speed(&self) -> f32 {
+        REPLAY_SPEEDS[self.speed_index]
+    }
+
+    /// Switch to the next faster speed
+    pub fn speed_up(&mut self) {
+        self.speed_index = (self.speed_index + 1).min(REPLAY_SPEEDS.len() - 1);
+    }
+
+    /// Switch to the next slower speed
+    pub fn slow_down(&mut self) {
+        self.speed_index = self.speed_index.saturating_sub(1);
+    }
+
+    /// Jump to a frame, clamped to the recording
+    pub fn seek(&mut self, position: usize) {
+        self.position = position.min(self.frames.len().saturating_sub(1));
+        self.pending_frames = 0.0;
+    }
+
+    /// Move by a number of frames relative to the current position
+    pub fn seek_relative(&mut self, offset: isize) {
+        self.seek(self.position.saturating_add_signed(offset));
+    }
+
+    /// Advance playback by one tick, returning whether the position changed
+    pub fn tick(&mut self) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        self.pending_frames += self.speed();
+        let advance = self.pending_frames.floor() as usize;
+        self.pending_frames -= advance as f32;
+
+        let previous = self.position;
+        self.position = (self.position + advance).min(self.frames.len().saturating_sub(1));
+
+        if self.is_at_end() {
+            self.pause();
+        }
+
+        self.position != previous
+    }
+}
+
+/// Position in a live race's snapshot history, scrubbed while paused
+///
+/// Follows the latest state until stepped back. Indices are into a history
+/// whose last snapshot is the latest state, so stepping forward onto it
+/// follows the race again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryCursor {
+    index: Option<usize>,
+}
+
+impl HistoryCursor {
+    /// Create a cursor following the latest state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// History index being viewed in a history of `len` snapshots, or
+    /// `None` for the latest state
+    ///
+    /// An index the history no longer reaches, such as after a restart,
+    /// reads as the latest state.
+    pub fn viewed(&self, len: usize) -> Option<usize> {
+        self.index.filter(|&index| index + 1 < len)
+    }
+
+    /// Step one snapshot back, stopping at the first
+    pub fn step_back(&mut self, len: usize) {
+        if let Some(latest) = len.checked_sub(1) {
+            let current = self.viewed(len).unwrap_or(latest);
+            self.index = Some(current.saturating_sub(1));
+        }
+    }
+
+    /// Step one snapshot forward, following the latest state once reached
+    pub fn step_forward(&mut self, len: usize) {
+        self.index = self.viewed(len).map(|index| index + 1).filter(|&index| index + 1 < len);
+    }
+
+    /// Jump to the first snapshot
+    pub fn jump_to_start(&mut self) {
+        self.index = Some(0);
+    }
+
+    /// Follow the latest state again, as when resuming or restarting
+    pub fn follow_latest(&mut self) {
+        self.index = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn frames(count: usize) -> Vec<RaceSnapshot> {
+        (0..count)
+            .map(|step| RaceSnapshot {
+                timestamp: SystemTime::UNIX_EPOCH,
+                algorithm_snapshots: Vec::new(),
+                step,
+                race_complete: step + 1 == count,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_new_player_is_paused_at_start() {
+        let player = ReplayPlayer::new(frames(5));
+        assert!(!player.is_playing());
+        assert_eq!(player.position(), 0);
+        assert_eq!(player.speed(), 1.0);
+        assert_eq!(player.current_frame().unwrap().step, 0);
+    }
+
+    #[test]
+    fn test_tick_advances_only_while_playing() {
+        let mut player = ReplayPlayer::new(frames(5));
+        assert!(!player.tick());
+
+        player.play();
+        assert!(player.tick());
+        assert_eq!(player.position(), 1);
+    }
+
+    #[test]
+    fn test_playback_stops_at_end() {
+        let mut player = ReplayPlayer::new(frames(3));
+        player.play();
+        while player.tick() {}
+
+        assert_eq!(player.position(), 2);
+        assert!(player.is_at_end());
+        assert!(!player.is_playing());
+
+        // Playing again restarts from the beginning
+        player.play();
+        assert_eq!(player.position(), 0);
+    }
+
+    #[test]
+    fn test_variable_speed() {
+        let mut player = ReplayPlayer::new(frames(100));
+        player.speed_up();
+        player.speed_up();
+        assert_eq!(player.speed(), 4.0);
+
+        player.play();
+        player.tick();
+        assert_eq!(player.position(), 4);
+
+        for _ in 0..10 {
+            player.slow_down();
+        }
+        assert_eq!(player.speed(), 0.25);
+
+        // Four ticks at quarter speed advance exactly one frame
+        let start = player.position();
+        for _ in 0..4 {
+            player.tick();
+        }
+        assert_eq!(player.position(), start + 1);
+    }
+
+    #[test]
+    fn test_seek_is_clamped() {
+        let mut player = ReplayPlayer::new(frames(10));
+        player.seek(100);
+        assert_eq!(player.position(), 9);
+
+        player.seek_relative(-3);
+        assert_eq!(player.position(), 6);
+
+        player.seek_relative(-100);
+        assert_eq!(player.position(), 0);
+    }
+
+    #[test]
+    fn test_empty_recording() {
+        let mut player = ReplayPlayer::new(Vec::new());
+        assert!(player.is_empty());
+        assert!(player.current_frame().is_none());
+
+        player.seek(5);
+        player.play();
+        assert!(!player.tick());
+    }
+
+    #[test]
+    fn test_history_cursor_stops_at_either_end() {
+        let mut cursor = HistoryCursor::new();
+        cursor.step_forward(5);
+        assert_eq!(cursor.viewed(5), None);
+
+        for _ in 0..10 {
+            cursor.step_back(5);
+        }
+        assert_eq!(cursor.viewed(5), Some(0));
+
+        cursor.step_forward(5);
+        assert_eq!(cursor.viewed(5), Some(1));
+        for _ in 0..10 {
+            cursor.step_forward(5);
+        }
+        assert_eq!(cursor.viewed(5), None);
+
+        // Stepping back from the latest state shows the one before it
+        cursor.step_back(5);
+        assert_eq!(cursor.viewed(5), Some(3));
+
+        // An empty or single-snapshot history has only the latest state
+        cursor.follow_latest();
+        cursor.step_back(0);
+        assert_eq!(cursor.viewed(0), None);
+        cursor.step_back(1);
+        assert_eq!(cursor.viewed(1), None);
+    }
+
+    #[test]
+    fn test_history_cursor_resumes_from_latest_after_rewind() {
+        let mut cursor = HistoryCursor::new();
+        cursor.jump_to_start();
+        cursor.step_forward(10);
+        assert_eq!(cursor.viewed(10), Some(1));
+
+        // The race moves on after resuming; stepping back starts from its new end
+        cursor.follow_latest();
+        assert_eq!(cursor.viewed(15), None);
+        cursor.step_back(15);
+        assert_eq!(cursor.viewed(15), Some(13));
+    }
+
+    #[test]
+    fn test_history_cursor_restart_while_scrubbed() {
+        let mut cursor = HistoryCursor::new();
+        cursor.step_back(50);
+        cursor.step_back(50);
+        assert_eq!(cursor.viewed(50), Some(47));
+
+        // A restarted race has a single snapshot, which is the latest state
+        assert_eq!(cursor.viewed(1), None);
+        cursor.step_forward(1);
+        assert_eq!(cursor.viewed(1), None);
+
+        cursor.step_back(50);
+        cursor.follow_latest();
+        cursor.step_back(3);
+        assert_eq!(cursor.viewed(3), Some(1));
+    }
+}
@@ -0,0 +1,324 @@
+//! Machine-readable race results for headless runs
+
+use crate::lib::controller::RaceController;
+use crate::models::config::RunConfiguration;
+use crate::models::element::Element;
+use crate::services::source::InputSource;
+use crate::services::verify::Stability;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// Output format for race reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// Single JSON document
+    #[default]
+    Json,
+    /// One CSV row per algorithm
+    Csv,
+}
+
+/// Metric a report ranks its lanes by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum RankBy {
+    /// Order the lanes finished in
+    #[default]
+    Finish,
+    /// Fewest comparisons
+    Comparisons,
+    /// Fewest moves
+    Moves,
+    /// Fewest element writes, the cost that matters on flash storage
+    Writes,
+}
+
+impl RankBy {
+    /// Value of the metric for a lane; lower ranks higher
+    pub fn score(self, result: &AlgorithmReport) -> u64 {
+        match self {
+            RankBy::Finish => result.finish_step.map_or(u64::MAX, |step| step as u64),
+            RankBy::Comparisons => result.comparisons,
+            RankBy::Moves => result.moves,
+            RankBy::Writes => result.writes,
+        }
+    }
+}
+
+/// Final results for a single algorithm lane
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlgorithmReport {
+    pub name: String,
+    /// 1-based finishing position, `None` if the lane did not complete
+    pub finish_position: Option<usize>,
+    /// 1-based position in the report's ranking, `None` if the lane did not
+    /// complete with a verified output
+    #[serde(default)]
+    pub rank: Option<usize>,
+    pub completed: bool,
+    pub comparisons: u64,
+    /// Comparator layers run, for sorting networks: the time the
+    /// comparisons would take on parallel hardware
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel_depth: Option<u64>,
+    pub moves: u64,
+    /// Element writes, counting a swap as two
+    #[serde(default)]
+    pub writes: u64,
+    pub steps: usize,
+    pub peak_memory_bytes: usize,
+    /// Time spent inside this lane's `step` calls
+    pub wall_time_us: u64,
+    /// Race step on which the lane completed
+    pub finish_step: Option<usize>,
+    /// Why the lane's output failed verification, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Whether equal keys kept their input order, in stability-checked races
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability: Option<Stability>,
+}
+
+/// Results of a complete race
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RaceReport {
+    pub array_size: usize,
+    pub distribution: String,
+    pub seed: u64,
+    pub fairness: String,
+    pub total_steps: usize,
+    pub wall_time_us: u64,
+    /// Algorithm names in the order they finished
+    pub finish_order: Vec<String>,
+    /// Metric the ranking is ordered by
+    #[serde(default)]
+    pub ranked_by: RankBy,
+    /// Names of the lanes that completed, best first by `ranked_by`
+    #[serde(default)]
+    pub ranking: Vec<String>,
+    pub results: Vec<AlgorithmReport>,
+}
+
+impl RaceReport {
+    /// Build a report from the current state of a race controller
+    pub fn from_controller<T: Element>(controller: &RaceController<T>, config: &RunConfiguration) -> Self {
+        let algorithms = controller.get_algorithms();
+        let lane_stats = controller.get_lane_stats();
+        let finish_order = controller.get_finish_order();
+
+        let results = algorithms
+            .iter()
+            .enumerate()
+            .map(|(index, algorithm)| {
+                let telemetry = algorithm.get_telemetry();
+                let stats = lane_stats.get(index).cloned().unwrap_or_default();
+                let finish_position = finish_order
+                    .iter()
+                    .position(|&lane| lane == index)
+                    .map(|position| position + 1);
+
+                AlgorithmReport {
+                    name: algorithm.name().to_string(),
+                    finish_position,
+                    rank: None,
+                    completed: algorithm.is_complete() && stats.error().is_none(),
+                    comparisons: telemetry.total_comparisons,
+                    parallel_depth: telemetry.parallel_depth,
+                    moves: telemetry.total_moves,
+                    writes: telemetry.total_writes,
+                    steps: stats.steps,
+                    peak_memory_bytes: stats.peak_memory.max(telemetry.memory_peak),
+                    wall_time_us: stats.busy_time.as_micros() as u64,
+                    finish_step: stats.finish_step,
+                    error: stats.error().map(str::to_string),
+                    stability: stats.stability,
+                }
+            })
+            .collect();
+
+        let report = Self {
+            array_size: config.array_size,
+            distribution: config.distribution.to_string(),
+            seed: config.seed,
+            fairness: controller.get_fairness_model_name().to_string(),
+            total_steps: controller.get_current_step(),
+            wall_time_us: controller
+                .get_elapsed_time()
+                .map(|elapsed| elapsed.as_micros() as u64)
+                .unwrap_or(0),
+            finish_order: finish_order
+                .iter()
+                .filter_map(|&lane| algorithms.get(lane))
+                .map(|algorithm| algorithm.name().to_string())
+                .collect(),
+            ranked_by: RankBy::default(),
+            ranking: Vec::new(),
+            results,
+        };
+        report.with_rank_by(RankBy::default())
+    }
+
+    /// Rank the completed lanes by `rank_by`, breaking ties by finishing order
+    pub fn with_rank_by(mut self, rank_by: RankBy) -> Self {
+        let mut ranked: Vec<usize> = (0..self.results.len()).filter(|&lane| self.results[lane].completed).collect();
+        ranked.sort_by_key(|&lane| {
+            let result = &self.results[lane];
+            (rank_by.score(result), result.finish_position.unwrap_or(usize::MAX))
+        });
+
+        for result in &mut self.results {
+            result.rank = None;
+        }
+        for (position, &lane) in ranked.iter().enumerate() {
+            self.results[lane].rank = Some(position + 1);
+        }
+        self.ranking = ranked.iter().map(|&lane| self.results[lane].name.clone()).collect();
+        self.ranked_by = rank_by;
+        self
+    }
+
+    /// Name `source` instead of the configured distribution as where the
+    /// input came from
+    pub fn with_input(mut self, source: &InputSource) -> Self {
+        self.distribution = format!("Input({})", source);
+        self
+    }
+
+    /// Serialize the report as pretty-printed JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize the report as CSV with a header row
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "name,finish_position,rank,completed,comparisons,parallel_depth,moves,writes,steps,peak_memory_bytes,wall_time_us,finish_step,error,stability\n",
+        );
+
+        for result in &self.results {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                escape_csv_field(&result.name),
+                optional_field(result.finish_position),
+                optional_field(result.rank),
+                result.completed,
+                result.comparisons,
+                optional_field(result.parallel_depth),
+                result.moves,
+                result.writes,
+                result.steps,
+                result.peak_memory_bytes,
+                result.wall_time_us,
+                optional_field(result.finish_step),
+                escape_csv_field(result.error.as_deref().unwrap_or_default()),
+                optional_field(result.stability),
+            ));
+        }
+
+        csv
+    }
+
+    /// Write the report in the requested format
+    pub fn write_to<W: Write>(&self, writer: &mut W, format: ReportFormat) -> anyhow::Result<()> {
+        match format {
+            ReportFormat::Json => writeln!(writer, "{}", self.to_json()?)?,
+            ReportFormat::Csv => write!(writer, "{}", self.to_csv())?,
+        }
+        Ok(())
+    }
+}
+
+fn optional_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines
+pub(crate) fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::traits::Sorter;
+    use crate::services::fairness::comparison::ComparisonFairness;
+    use crate::services::sorters::{bubble::BubbleSort, insertion::InsertionSort};
+
+    fn finished_report() -> RaceReport {
+        let algorithms: Vec<Box<dyn Sorter>> = vec![
+            Box::new(BubbleSort::new()),
+            Box::new(InsertionSort::new()),
+        ];
+        let mut controller = RaceController::new(algorithms, Box::new(ComparisonFairness::new(8)), 1);
+        let config = RunConfiguration::new().with_array_size(10);
+
+        controller.start_race(&config, vec![5, 3, 8, 1, 9, 2, 7, 4, 6, 0]).unwrap();
+        controller.run_to_completion(None);
+
+        RaceReport::from_controller(&controller, &config)
+    }
+
+    #[test]
+    fn test_report_covers_every_lane() {
+        let report = finished_report();
+
+        assert_eq!(report.results.len(), 2);
+        assert_eq!(report.finish_order.len(), 2);
+        assert!(report.results.iter().all(|r| r.completed && r.comparisons > 0));
+
+        let mut positions: Vec<_> = report.results.iter().filter_map(|r| r.finish_position).collect();
+        positions.sort();
+        assert_eq!(positions, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = finished_report();
+        let json = report.to_json().unwrap();
+        let parsed: RaceReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn test_csv_has_header_and_row_per_lane() {
+        let report = finished_report();
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("name,finish_position"));
+        assert!(lines[1].starts_with("Bubble Sort,"));
+    }
+
+    #[test]
+    fn test_ranking_by_writes() {
+        let report = finished_report();
+        assert_eq!(report.ranked_by, RankBy::Finish);
+        assert_eq!(report.ranking, report.finish_order);
+
+        // Both swap adjacent pairs once per inversion, so the write counts
+        // tie and finishing order decides
+        let report = report.with_rank_by(RankBy::Writes);
+        assert!(report.results.iter().all(|r| r.writes == 2 * r.moves));
+        assert_eq!(report.ranking, report.finish_order);
+
+        let mut report = report;
+        report.results[0].writes = 0;
+        let report = report.with_rank_by(RankBy::Writes);
+        assert_eq!(report.ranking[0], "Bubble Sort");
+        assert_eq!(report.results[0].rank, Some(1));
+        assert_eq!(report.results[1].rank, Some(2));
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,585 @@
+//! Batch tournaments across array sizes, distributions, seeds and fairness modes
+
+use crate::lib::controller::RaceController;
+use crate::lib::report::{escape_csv_field, AlgorithmReport, RaceReport, RankBy, ReportFormat};
+use crate::models::config::{check_array_size, Distribution, FairnessMode, RunConfiguration};
+use crate::models::traits::Sorter;
+use crate::services::fairness::factory::create_fairness_model;
+use crate::services::generator::ArrayGenerator;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+
+/// Snapshot history kept per race; tournaments only need final results
+const TOURNAMENT_MAX_SNAPSHOTS: usize = 1;
+
+/// Factory producing a fresh set of algorithm lanes for each race
+pub type AlgorithmFactory = Box<dyn Fn() -> Vec<Box<dyn Sorter>>>;
+
+/// The matrix of races a tournament runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentConfig {
+    pub sizes: Vec<usize>,
+    pub distributions: Vec<Distribution>,
+    pub seeds: Vec<u64>,
+    pub fairness_modes: Vec<FairnessMode>,
+    /// Step limit for a single race, `None` to run until every lane completes
+    pub max_steps: Option<usize>,
+    /// Metric that decides who wins each race
+    pub rank_by: RankBy,
+}
+
+impl Default for TournamentConfig {
+    fn default() -> Self {
+        Self {
+            sizes: vec![50],
+            distributions: Distribution::all(),
+            seeds: (0..5).collect(),
+            fairness_modes: vec![FairnessMode::default()],
+            max_steps: None,
+            rank_by: RankBy::default(),
+        }
+    }
+}
+
+impl TournamentConfig {
+    /// Create a new tournament configuration with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set array sizes
+    pub fn with_sizes(mut self, sizes: Vec<usize>) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Set distributions
+    pub fn with_distributions(mut self, distributions: Vec<Distribution>) -> Self {
+        self.distributions = distributions;
+        self
+    }
+
+    /// Set seeds explicitly
+    pub fn with_seeds(mut self, seeds: Vec<u64>) -> Self {
+        self.seeds = seeds;
+        self
+    }
+
+    /// Use `count` consecutive seeds starting at `base_seed`
+    pub fn with_seed_count(mut self, base_seed: u64, count: usize) -> Self {
+        self.seeds = (0..count as u64).map(|offset| base_seed.wrapping_add(offset)).collect();
+        self
+    }
+
+    /// Set fairness modes
+    pub fn with_fairness_modes(mut self, fairness_modes: Vec<FairnessMode>) -> Self {
+        self.fairness_modes = fairness_modes;
+        self
+    }
+
+    /// Set the per-race step limit
+    pub fn with_max_steps(mut self, max_steps: Option<usize>) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Set the metric that decides who wins each race
+    pub fn with_rank_by(mut self, rank_by: RankBy) -> Self {
+        self.rank_by = rank_by;
+        self
+    }
+
+    /// Number of races the tournament will run
+    pub fn race_count(&self) -> usize {
+        self.sizes.len() * self.distributions.len() * self.seeds.len() * self.fairness_modes.len()
+    }
+
+    /// Validate the tournament matrix
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sizes.is_empty() {
+            return Err("At least one array size is required".to_string());
+        }
+        if self.distributions.is_empty() {
+            return Err("At least one distribution is required".to_string());
+        }
+        if self.seeds.is_empty() {
+            return Err("At least one seed is required".to_string());
+        }
+        if self.fairness_modes.is_empty() {
+            return Err("At least one fairness mode is required".to_string());
+        }
+        for &size in &self.sizes {
+            check_array_size(size)?;
+        }
+        for distribution in &self.distributions {
+            distribution.validate()?;
+        }
+
+        for &size in &self.sizes {
+            for fairness_mode in &self.fairness_modes {
+                RunConfiguration::new()
+                    .with_array_size(size)
+                    .with_fairness_mode(fairness_mode.clone())
+                    .validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Mean, median and standard deviation of a set of samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SummaryStats {
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SummaryStats {
+    /// Summarize samples; empty input yields all zeros
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / count;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2.0
+        } else {
+            sorted[middle]
+        };
+        let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Self {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Aggregated results for one algorithm within one cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlgorithmAggregate {
+    pub name: String,
+    /// Races won, counting shared first place as a win for every lane involved
+    pub wins: u32,
+    pub completed_races: usize,
+    pub comparisons: SummaryStats,
+    pub moves: SummaryStats,
+    #[serde(default)]
+    pub writes: SummaryStats,
+    /// Race step on which the lane finished
+    pub finish_step: SummaryStats,
+    pub peak_memory_bytes: SummaryStats,
+    pub wall_time_us: SummaryStats,
+}
+
+/// Aggregated results for one size × distribution × fairness mode cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CellResult {
+    pub array_size: usize,
+    pub distribution: String,
+    /// Fairness model name, as in [`RaceReport::fairness`]
+    pub fairness: String,
+    /// Parameters of the fairness mode, see [`FairnessMode::params`]
+    pub fairness_params: String,
+    pub races: usize,
+    pub algorithms: Vec<AlgorithmAggregate>,
+}
+
+/// Results of a full tournament
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentResult {
+    pub seeds: Vec<u64>,
+    pub total_races: usize,
+    pub cells: Vec<CellResult>,
+    /// Wins per algorithm summed across all cells
+    pub total_wins: BTreeMap<String, u32>,
+}
+
+impl TournamentResult {
+    /// Serialize the results as pretty-printed JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize the results as CSV, one row per cell and algorithm
+    pub fn to_csv(&self) -> String {
+        let metrics = ["comparisons", "moves", "writes", "finish_step", "peak_memory_bytes", "wall_time_us"];
+        let mut header = vec![
+            "array_size".to_string(),
+            "distribution".to_string(),
+            "fairness".to_string(),
+            "fairness_params".to_string(),
+            "name".to_string(),
+            "races".to_string(),
+            "completed".to_string(),
+            "wins".to_string(),
+        ];
+        for metric in metrics {
+            for stat in ["mean", "median", "stddev"] {
+                header.push(format!("{}_{}", metric, stat));
+            }
+        }
+
+        let mut csv = header.join(",");
+        csv.push('\n');
+
+        for cell in &self.cells {
+            for algorithm in &cell.algorithms {
+                let mut row = vec![
+                    cell.array_size.to_string(),
+                    escape_csv_field(&cell.distribution),
+                    escape_csv_field(&cell.fairness),
+                    escape_csv_field(&cell.fairness_params),
+                    escape_csv_field(&algorithm.name),
+                    cell.races.to_string(),
+                    algorithm.completed_races.to_string(),
+                    algorithm.wins.to_string(),
+                ];
+                for stats in [
+                    &algorithm.comparisons,
+                    &algorithm.moves,
+                    &algorithm.writes,
+                    &algorithm.finish_step,
+                    &algorithm.peak_memory_bytes,
+                    &algorithm.wall_time_us,
+                ] {
+                    row.push(format!("{:.3}", stats.mean));
+                    row.push(format!("{:.3}", stats.median));
+                    row.push(format!("{:.3}", stats.stddev));
+                }
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+        }
+
+        csv
+    }
+
+    /// Write the results in the requested format
+    pub fn write_to<W: Write>(&self, writer: &mut W, format: ReportFormat) -> anyhow::Result<()> {
+        match format {
+            ReportFormat::Json => writeln!(writer, "{}", self.to_json()?)?,
+            ReportFormat::Csv => write!(writer, "{}", self.to_csv())?,
+        }
+        Ok(())
+    }
+}
+
+/// Runs every race in a tournament matrix and aggregates the results
+pub struct Tournament {
+    config: TournamentConfig,
+    algorithm_factory: AlgorithmFactory,
+}
+
+impl fmt::Debug for Tournament {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tournament")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tournament {
+    /// Create a tournament that builds its lanes with `algorithm_factory`
+    pub fn new(config: TournamentConfig, algorithm_factory: AlgorithmFactory) -> Self {
+        Self {
+            config,
+            algorithm_factory,
+        }
+    }
+
+    /// Get the tournament configuration
+    pub fn config(&self) -> &TournamentConfig {
+        &self.config
+    }
+
+    /// Run a single race and return its report
+    pub fn run_race(&self, config: &RunConfiguration) -> Result<RaceReport, String> {
+        let array = ArrayGenerator::new(config.seed).generate(config.array_size, &config.distribution);
+        let mut controller = RaceController::new(
+            (self.algorithm_factory)(),
+            create_fairness_model(&config.fairness_mode),
+            TOURNAMENT_MAX_SNAPSHOTS,
+        );
+
+        controller.start_race(config, array)?;
+        controller.run_to_completion(self.config.max_steps);
+
+        Ok(RaceReport::from_controller(&controller, config).with_rank_by(self.config.rank_by))
+    }
+
+    /// Run the whole matrix
+    pub fn run(&self) -> Result<TournamentResult, String> {
+        self.config.validate()?;
+
+        let mut cells = Vec::new();
+        let mut total_wins: BTreeMap<String, u32> = BTreeMap::new();
+
+        for &size in &self.config.sizes {
+            for distribution in &self.config.distributions {
+                for fairness_mode in &self.config.fairness_modes {
+                    let mut reports = Vec::with_capacity(self.config.seeds.len());
+                    for &seed in &self.config.seeds {
+                        let run_config = RunConfiguration::new()
+                            .with_array_size(size)
+                            .with_distribution(distribution.clone())
+                            .with_seed(seed)
+                            .with_fairness_mode(fairness_mode.clone());
+                        reports.push(self.run_race(&run_config)?);
+                    }
+
+                    let cell = aggregate_cell(size, distribution, fairness_mode, &reports);
+                    for algorithm in &cell.algorithms {
+                        *total_wins.entry(algorithm.name.clone()).or_insert(0) += algorithm.wins;
+                    }
+                    cells.push(cell);
+                }
+            }
+        }
+
+        Ok(TournamentResult {
+            seeds: self.config.seeds.clone(),
+            total_races: self.config.race_count(),
+            cells,
+            total_wins,
+        })
+    }
+}
+
+/// Lanes that finished with a verified output and the best score on the
+/// report's ranking metric, by default the earliest finishing step
+fn race_winners(report: &RaceReport) -> Vec<usize> {
+    let score = |result: &AlgorithmReport| report.ranked_by.score(result);
+    let best = report.results.iter().filter(|r| r.completed).map(score).min();
+
+    match best {
+        Some(best) => report
+            .results
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.completed && score(r) == best)
+            .map(|(index, _)| index)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn aggregate_cell(
+    size: usize,
+    distribution: &Distribution,
+    fairness_mode: &FairnessMode,
+    reports: &[RaceReport],
+) -> CellResult {
+    let lane_count = reports.first().map(|r| r.results.len()).unwrap_or(0);
+    let mut wins = vec![0u32; lane_count];
+    for report in reports {
+        for lane in race_winners(report) {
+            wins[lane] += 1;
+        }
+    }
+
+    let algorithms = (0..lane_count)
+        .map(|lane| {
+            let lane_results: Vec<_> = reports.iter().filter_map(|r| r.results.get(lane)).collect();
+            let summarize = |metric: &dyn Fn(&AlgorithmReport) -> f64| {
+                let samples: Vec<f64> = lane_results.iter().map(|r| metric(r)).collect();
+                SummaryStats::from_samples(&samples)
+            };
+            let finish_steps: Vec<f64> = lane_results
+                .iter()
+                .filter_map(|r| r.finish_step)
+                .map(|step| step as f64)
+                .collect();
+
+            AlgorithmAggregate {
+                name: lane_results.first().map(|r| r.name.clone()).unwrap_or_default(),
+                wins: wins[lane],
+                completed_races: lane_results.iter().filter(|r| r.completed).count(),
+                comparisons: summarize(&|r| r.comparisons as f64),
+                moves: summarize(&|r| r.moves as f64),
+                writes: summarize(&|r| r.writes as f64),
+                finish_step: SummaryStats::from_samples(&finish_steps),
+                peak_memory_bytes: summarize(&|r| r.peak_memory_bytes as f64),
+                wall_time_us: summarize(&|r| r.wall_time_us as f64),
+            }
+        })
+        .collect();
+
+    CellResult {
+        array_size: size,
+        distribution: distribution.to_string(),
+        fairness: create_fairness_model::<i32>(fairness_mode).name().to_string(),
+        fairness_params: fairness_mode.params(),
+        races: reports.len(),
+        algorithms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::config::MAX_ARRAY_SIZE;
+    use crate::services::sorters::{bubble::BubbleSort, cycle::CycleSort, merge::MergeSort};
+
+    fn small_tournament() -> Tournament {
+        let config = TournamentConfig::new()
+            .with_sizes(vec![10, 20])
+            .with_distributions(vec![Distribution::Shuffled, Distribution::Reversed])
+            .with_seed_count(1, 3);
+        Tournament::new(
+            config,
+            Box::new(|| -> Vec<Box<dyn Sorter>> {
+                vec![Box::new(BubbleSort::new()), Box::new(MergeSort::new())]
+            }),
+        )
+    }
+
+    #[test]
+    fn test_summary_stats() {
+        let stats = SummaryStats::from_samples(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.median, 4.5);
+        assert_eq!(stats.stddev, 2.0);
+        assert_eq!(stats.min, 2.0);
+        assert_eq!(stats.max, 9.0);
+
+        assert_eq!(SummaryStats::from_samples(&[3.0]).median, 3.0);
+        assert_eq!(SummaryStats::from_samples(&[]), SummaryStats::default());
+    }
+
+    #[test]
+    fn test_tournament_covers_full_matrix() {
+        let tournament = small_tournament();
+        assert_eq!(tournament.config().race_count(), 12);
+
+        let result = tournament.run().unwrap();
+        assert_eq!(result.total_races, 12);
+        assert_eq!(result.cells.len(), 4);
+
+        for cell in &result.cells {
+            assert_eq!(cell.races, 3);
+            assert_eq!(cell.algorithms.len(), 2);
+            assert!(cell.algorithms.iter().all(|a| a.completed_races == 3));
+        }
+    }
+
+    #[test]
+    fn test_win_counts() {
+        let result = small_tournament().run().unwrap();
+
+        // Merge Sort beats Bubble Sort on reversed input every time
+        let reversed = result
+            .cells
+            .iter()
+            .find(|cell| cell.array_size == 20 && cell.distribution == "Reversed")
+            .unwrap();
+        assert_eq!(reversed.algorithms[1].wins, 3);
+        assert_eq!(reversed.algorithms[0].wins, 0);
+
+        let total: u32 = result.total_wins.values().sum();
+        assert!(total >= 12);
+    }
+
+    #[test]
+    fn test_cells_name_fairness_like_race_reports() {
+        let config = TournamentConfig::new()
+            .with_sizes(vec![10])
+            .with_distributions(vec![Distribution::Shuffled])
+            .with_seed_count(1, 1)
+            .with_fairness_modes(vec![
+                FairnessMode::ComparisonBudget { k: 16 },
+                FairnessMode::Weighted { alpha: 2.0, beta: 0.5 },
+            ]);
+        let tournament = Tournament::new(
+            config,
+            Box::new(|| -> Vec<Box<dyn Sorter>> { vec![Box::new(BubbleSort::new())] }),
+        );
+        let result = tournament.run().unwrap();
+
+        let race = RunConfiguration::new()
+            .with_array_size(10)
+            .with_fairness_mode(FairnessMode::ComparisonBudget { k: 16 });
+        let report = tournament.run_race(&race).unwrap();
+        assert_eq!(result.cells[0].fairness, report.fairness);
+        assert_eq!(result.cells[0].fairness, "Comparison Budget");
+        assert_eq!(result.cells[0].fairness_params, "k=16");
+        assert_eq!(result.cells[1].fairness_params, "alpha=2, beta=0.5");
+
+        let csv = result.to_csv();
+        assert!(csv.lines().nth(1).unwrap().starts_with("10,Shuffled,Comparison Budget,k=16,"));
+        assert!(!csv.contains("ComparisonBudget"));
+    }
+
+    #[test]
+    fn test_wins_by_writes() {
+        let config = TournamentConfig::new()
+            .with_sizes(vec![30])
+            .with_distributions(vec![Distribution::Shuffled])
+            .with_seed_count(1, 3)
+            .with_rank_by(RankBy::Writes);
+        let tournament = Tournament::new(
+            config,
+            Box::new(|| -> Vec<Box<dyn Sorter>> {
+                vec![Box::new(MergeSort::new()), Box::new(CycleSort::new())]
+            }),
+        );
+        let result = tournament.run().unwrap();
+
+        // Merge Sort finishes first but Cycle Sort writes far less
+        let cell = &result.cells[0];
+        assert_eq!(cell.algorithms[1].wins, 3);
+        assert_eq!(cell.algorithms[0].wins, 0);
+        assert!(cell.algorithms[1].writes.mean < cell.algorithms[0].writes.mean);
+    }
+
+    #[test]
+    fn test_tournament_is_deterministic() {
+        let first = small_tournament().run().unwrap();
+        let second = small_tournament().run().unwrap();
+
+        for (a, b) in first.cells.iter().zip(second.cells.iter()) {
+            for (x, y) in a.algorithms.iter().zip(b.algorithms.iter()) {
+                assert_eq!(x.comparisons, y.comparisons);
+                assert_eq!(x.wins, y.wins);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_matrix_rejected() {
+        let tournament = Tournament::new(
+            TournamentConfig::new().with_sizes(vec![]),
+            Box::new(Vec::new),
+        );
+        assert!(tournament.run().is_err());
+
+        for size in [0, MAX_ARRAY_SIZE + 1] {
+            assert!(TournamentConfig::new().with_sizes(vec![10, size]).validate().is_err(), "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let csv = small_tournament().run().unwrap().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 1 + 4 * 2);
+        assert!(lines[0].contains("comparisons_mean,comparisons_median,comparisons_stddev"));
+    }
+}
@@ -1,13 +1,29 @@
 //! Configuration types for sorting race simulation
 
+use crate::models::{element::Comparator, lane::LaneSpec, session::AlgorithmType};
+use crate::services::registry;
+
+/// Largest array a race accepts, whether generated, loaded from a file or
+/// stdin, or set in the interactive menu
+pub const MAX_ARRAY_SIZE: usize = 10_000;
+
+/// Check `size` is between 1 and [`MAX_ARRAY_SIZE`]
+pub fn check_array_size(size: usize) -> Result<(), String> {
+    if size == 0 || size > MAX_ARRAY_SIZE {
+        return Err(format!("Array size must be between 1 and {}, got {}", MAX_ARRAY_SIZE, size));
+    }
+    Ok(())
+}
+
 /// Data distribution patterns for generated arrays
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum Distribution {
     /// Random shuffled array
     #[default]
     Shuffled,
-    /// Nearly sorted array with few out-of-place elements
-    NearlySorted,
+    /// Sorted array with `swap_percent` percent of its size in random swaps,
+    /// at least one unless the percentage is 0
+    NearlySorted { swap_percent: f64 },
     /// Reverse sorted array
     Reversed,
     /// Array with few unique values
@@ -16,6 +32,182 @@ pub enum Distribution {
     Sorted,
     /// Array with duplicates
     WithDuplicates,
+    /// Musser's sequence that drives median-of-three quicksort quadratic
+    MedianOfThreeKiller,
+    /// Ascending to the middle, then descending
+    OrganPipe,
+    /// Repeated ascending runs, about the square root of the size long
+    Sawtooth,
+    /// Worst case for the target lane, found by McIlroy's antiquicksort
+    /// adversary while the lane sorts; create it with
+    /// [`Distribution::antiqsort`]
+    Antiqsort(LaneSpec),
+    /// Normally distributed values around half the size, clamped to
+    /// `1..=size`; `stddev` is a fraction of the size
+    Gaussian { stddev: f64 },
+    /// Power-law values in `1..=size`: value `k` is drawn with weight
+    /// `1 / k^exponent`, so larger exponents mean fewer distinct values
+    Zipf { exponent: f64 },
+    /// Uniformly random values in `min..=max`
+    Uniform { min: i32, max: i32 },
+    /// A shuffled array cut into `count` equal parts, each sorted
+    Runs { count: usize },
+}
+
+impl Distribution {
+    /// Get every distribution that needs no parameters, nearly sorted at its
+    /// default swap percentage
+    pub fn all() -> Vec<Distribution> {
+        vec![
+            Distribution::Shuffled,
+            DistributionParams::default().nearly_sorted(),
+            Distribution::Reversed,
+            Distribution::FewUnique,
+            Distribution::Sorted,
+            Distribution::WithDuplicates,
+            Distribution::MedianOfThreeKiller,
+            Distribution::OrganPipe,
+            Distribution::Sawtooth,
+        ]
+    }
+
+    /// Worst case input for `target`, built by watching it sort
+    ///
+    /// Fails if the lane is not a built-in comparison sort, since the
+    /// adversary works by answering the lane's comparisons.
+    pub fn antiqsort(target: LaneSpec) -> Result<Distribution, String> {
+        let distribution = Distribution::Antiqsort(target);
+        distribution.validate()?;
+        Ok(distribution)
+    }
+
+    /// Check the distribution's parameters
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Distribution::Antiqsort(target) => {
+                registry::create_builtin_variant::<usize>(target.algorithm.key(), &target.options, Comparator::natural())
+                    .map(|_| ())
+                    .map_err(|e| format!("Cannot build an antiqsort input for {}: {}", target.label, e))
+            }
+            &Distribution::Gaussian { stddev } if !(stddev.is_finite() && stddev > 0.0) => {
+                Err(format!("Standard deviation must be greater than 0, got {}", stddev))
+            }
+            &Distribution::Zipf { exponent } if !(exponent.is_finite() && exponent >= 0.0) => {
+                Err(format!("Zipf exponent must be 0 or more, got {}", exponent))
+            }
+            &Distribution::Uniform { min, max } if min > max => {
+                Err(format!("Value range minimum {} is above its maximum {}", min, max))
+            }
+            Distribution::Runs { count: 0 } => Err("Number of runs must be greater than 0".to_string()),
+            &Distribution::NearlySorted { swap_percent } if !(0.0..=100.0).contains(&swap_percent) => {
+                Err(format!("Swap percentage must be between 0 and 100, got {}", swap_percent))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Parameters of this distribution, to carry over into another
+    pub fn params(&self) -> DistributionParams {
+        let mut params = DistributionParams::default();
+        match *self {
+            Distribution::Antiqsort(ref target) => params.adversary = Some(target.clone()),
+            Distribution::Gaussian { stddev } => params.stddev = Some(stddev),
+            Distribution::Zipf { exponent } => params.zipf_exponent = Some(exponent),
+            Distribution::Uniform { min, max } => params.value_range = Some((min, max)),
+            Distribution::Runs { count } => params.runs = Some(count),
+            Distribution::NearlySorted { swap_percent } => params.swap_percent = Some(swap_percent),
+            _ => {}
+        }
+        params
+    }
+}
+
+impl std::fmt::Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distribution::Antiqsort(target) => write!(f, "Antiqsort({})", target.label),
+            Distribution::Gaussian { stddev } => write!(f, "Gaussian(stddev {})", stddev),
+            Distribution::Zipf { exponent } => write!(f, "Zipf(exponent {})", exponent),
+            Distribution::Uniform { min, max } => write!(f, "Uniform({}..={})", min, max),
+            Distribution::Runs { count } => write!(f, "Runs({})", count),
+            Distribution::NearlySorted { swap_percent } => write!(f, "NearlySorted({}% swapped)", swap_percent),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Knobs of the parameterized distributions, `None` meaning the default
+///
+/// The command line and the interactive configuration both build their
+/// [`Distribution`] from a kind and these parameters.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DistributionParams {
+    /// Standard deviation of [`Distribution::Gaussian`], as a fraction of the size
+    pub stddev: Option<f64>,
+    /// Exponent of [`Distribution::Zipf`]
+    pub zipf_exponent: Option<f64>,
+    /// Value range of [`Distribution::Uniform`], inclusive
+    pub value_range: Option<(i32, i32)>,
+    /// Number of sorted runs for [`Distribution::Runs`]
+    pub runs: Option<usize>,
+    /// Swap percentage of [`Distribution::NearlySorted`]
+    pub swap_percent: Option<f64>,
+    /// Lane [`Distribution::Antiqsort`] builds its worst case against
+    pub adversary: Option<LaneSpec>,
+}
+
+impl DistributionParams {
+    /// Default standard deviation, as a fraction of the size
+    pub const DEFAULT_STDDEV: f64 = 0.15;
+    /// Default Zipf exponent
+    pub const DEFAULT_ZIPF_EXPONENT: f64 = 1.0;
+    /// Default value range, inclusive
+    pub const DEFAULT_VALUE_RANGE: (i32, i32) = (1, 100);
+    /// Default number of sorted runs
+    pub const DEFAULT_RUNS: usize = 4;
+    /// Default swap percentage of nearly sorted input
+    pub const DEFAULT_SWAP_PERCENT: f64 = 10.0;
+
+    /// Gaussian distribution with these parameters
+    pub fn gaussian(&self) -> Distribution {
+        Distribution::Gaussian {
+            stddev: self.stddev.unwrap_or(Self::DEFAULT_STDDEV),
+        }
+    }
+
+    /// Zipf distribution with these parameters
+    pub fn zipf(&self) -> Distribution {
+        Distribution::Zipf {
+            exponent: self.zipf_exponent.unwrap_or(Self::DEFAULT_ZIPF_EXPONENT),
+        }
+    }
+
+    /// Uniform distribution with these parameters
+    pub fn uniform(&self) -> Distribution {
+        let (min, max) = self.value_range.unwrap_or(Self::DEFAULT_VALUE_RANGE);
+        Distribution::Uniform { min, max }
+    }
+
+    /// Sorted runs distribution with these parameters
+    pub fn runs(&self) -> Distribution {
+        Distribution::Runs {
+            count: self.runs.unwrap_or(Self::DEFAULT_RUNS),
+        }
+    }
+
+    /// Antiquicksort distribution against the adversary lane, Quick Sort by
+    /// default
+    pub fn antiqsort(&self) -> Distribution {
+        let target = self.adversary.clone().unwrap_or_else(|| LaneSpec::new(AlgorithmType::QuickSort));
+        Distribution::Antiqsort(target)
+    }
+
+    /// Nearly sorted distribution with these parameters
+    pub fn nearly_sorted(&self) -> Distribution {
+        Distribution::NearlySorted {
+            swap_percent: self.swap_percent.unwrap_or(Self::DEFAULT_SWAP_PERCENT),
+        }
+    }
 }
 
 /// Fairness model configuration
@@ -39,6 +231,19 @@ impl Default for FairnessMode {
     }
 }
 
+impl FairnessMode {
+    /// Parameters of this mode as `name=value` pairs, e.g. `alpha=2, beta=0.5`
+    pub fn params(&self) -> String {
+        match self {
+            FairnessMode::ComparisonBudget { k } => format!("k={}", k),
+            FairnessMode::Weighted { alpha, beta } => format!("alpha={}, beta={}", alpha, beta),
+            FairnessMode::WallTime { slice_ms } => format!("slice_ms={}", slice_ms),
+            FairnessMode::Adaptive { learning_rate } => format!("learning_rate={}", learning_rate),
+            FairnessMode::EqualSteps => String::new(),
+        }
+    }
+}
+
 /// Complete configuration for a sorting race run
 #[derive(Debug, Clone)]
 pub struct RunConfiguration {
@@ -52,6 +257,10 @@ pub struct RunConfiguration {
     pub fairness_mode: FairnessMode,
     /// Target frames per second for visualization
     pub target_fps: u32,
+    /// Lanes to race, in display order
+    pub algorithms: Vec<LaneSpec>,
+    /// Check whether each lane kept equal keys in their input order
+    pub check_stability: bool,
 }
 
 impl Default for RunConfiguration {
@@ -62,6 +271,8 @@ impl Default for RunConfiguration {
             seed: 42,
             fairness_mode: FairnessMode::default(),
             target_fps: 30,
+            algorithms: LaneSpec::defaults(),
+            check_stability: false,
         }
     }
 }
@@ -102,16 +313,32 @@ impl RunConfiguration {
         self
     }
 
+    /// Set the lanes to race
+    pub fn with_algorithms(mut self, algorithms: Vec<LaneSpec>) -> Self {
+        self.algorithms = algorithms;
+        self
+    }
+
+    /// Enable or disable stability checking
+    pub fn with_stability_check(mut self, check_stability: bool) -> Self {
+        self.check_stability = check_stability;
+        self
+    }
+
     /// Validate configuration parameters
     pub fn validate(&self) -> Result<(), String> {
-        if self.array_size == 0 {
-            return Err("Array size must be greater than 0".to_string());
-        }
+        check_array_size(self.array_size)?;
+
+        self.distribution.validate()?;
 
         if self.target_fps == 0 {
             return Err("Target FPS must be greater than 0".to_string());
         }
 
+        if self.algorithms.is_empty() {
+            return Err("At least one algorithm must be selected".to_string());
+        }
+
         match &self.fairness_mode {
             FairnessMode::ComparisonBudget { k } => {
                 if *k == 0 {
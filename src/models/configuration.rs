@@ -1,6 +1,10 @@
 //! Configuration state for interactive terminal interface
 
-use crate::models::config::{Distribution, FairnessMode};
+use crate::models::{
+    config::{self, Distribution, DistributionParams, FairnessMode},
+    lane::LaneSpec,
+    session::AlgorithmType,
+};
 use anyhow::{Result, anyhow};
 
 /// Represents current user selections for interactive configuration
@@ -10,6 +14,8 @@ pub struct ConfigurationState {
     pub array_size: u32,
     /// Data pattern selection
     pub distribution: DistributionType,
+    /// Parameters of the parameterized distributions
+    pub distribution_params: DistributionParams,
     /// Algorithm fairness strategy
     pub fairness_mode: FairnessMode,
     /// Budget parameter for comparison fairness
@@ -20,15 +26,67 @@ pub struct ConfigurationState {
     pub beta: Option<f32>,
     /// Learning rate for adaptive fairness
     pub learning_rate: Option<f32>,
+    /// Lanes to race, in display order
+    pub lanes: Vec<LaneSpec>,
+    /// Check whether each lane kept equal keys in their input order
+    pub check_stability: bool,
 }
 
-/// Distribution types for interactive configuration
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Distribution kinds, as chosen on the command line or in the interactive
+/// configuration; parameters come from [`DistributionParams`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum DistributionType {
+    /// Random order
     Shuffled,
+    /// Reverse sorted
     Reversed,
+    /// Sorted with a share of elements swapped out of place
     NearlySorted,
+    /// Few unique values
     FewUnique,
+    /// Already sorted
+    Sorted,
+    /// Half the values repeat
+    WithDuplicates,
+    /// Musser's sequence, quadratic for median-of-three quicksort
+    #[value(name = "median3-killer")]
+    MedianOfThreeKiller,
+    /// Ascending to the middle, then descending
+    OrganPipe,
+    /// Repeated ascending runs, about √n long
+    Sawtooth,
+    /// Worst case for the adversary lane, built while it sorts
+    Antiqsort,
+    /// Values clustered around the middle
+    Gaussian,
+    /// Power law; a few small values dominate
+    Zipf,
+    /// Uniformly random values in a range
+    Uniform,
+    /// A few sorted runs
+    Runs,
+}
+
+impl DistributionType {
+    /// Distribution of this kind with the given parameters
+    pub fn with_params(self, params: &DistributionParams) -> Distribution {
+        match self {
+            DistributionType::Shuffled => Distribution::Shuffled,
+            DistributionType::Reversed => Distribution::Reversed,
+            DistributionType::NearlySorted => params.nearly_sorted(),
+            DistributionType::FewUnique => Distribution::FewUnique,
+            DistributionType::Sorted => Distribution::Sorted,
+            DistributionType::WithDuplicates => Distribution::WithDuplicates,
+            DistributionType::MedianOfThreeKiller => Distribution::MedianOfThreeKiller,
+            DistributionType::OrganPipe => Distribution::OrganPipe,
+            DistributionType::Sawtooth => Distribution::Sawtooth,
+            DistributionType::Antiqsort => params.antiqsort(),
+            DistributionType::Gaussian => params.gaussian(),
+            DistributionType::Zipf => params.zipf(),
+            DistributionType::Uniform => params.uniform(),
+            DistributionType::Runs => params.runs(),
+        }
+    }
 }
 
 impl ConfigurationState {
@@ -37,11 +95,14 @@ impl ConfigurationState {
         Self {
             array_size: 100,
             distribution: DistributionType::Shuffled,
+            distribution_params: DistributionParams::default(),
             fairness_mode: FairnessMode::WallTime { slice_ms: 50 },
             budget: None,
             alpha: None,
             beta: None,
             learning_rate: None,
+            lanes: LaneSpec::defaults(),
+            check_stability: false,
         }
     }
 
@@ -55,11 +116,14 @@ impl ConfigurationState {
         Self {
             array_size: config.array_size as u32,
             distribution: config.distribution.clone().into(),
+            distribution_params: config.distribution.params(),
             fairness_mode: config.fairness_mode.clone(),
             budget: None,
             alpha: None,
             beta: None,
             learning_rate: None,
+            lanes: config.algorithms.clone(),
+            check_stability: config.check_stability,
         }
     }
 
@@ -70,12 +134,8 @@ impl ConfigurationState {
 
     /// Get available distribution types
     pub fn get_available_distributions() -> Vec<DistributionType> {
-        vec![
-            DistributionType::Shuffled,
-            DistributionType::Reversed,
-            DistributionType::NearlySorted,
-            DistributionType::FewUnique,
-        ]
+        // The same kinds the command line accepts, in the same order
+        <DistributionType as clap::ValueEnum>::value_variants().to_vec()
     }
 
     /// Get available fairness modes
@@ -85,6 +145,7 @@ impl ConfigurationState {
             FairnessMode::Weighted { alpha: 2.0, beta: 0.5 },
             FairnessMode::WallTime { slice_ms: 50 },
             FairnessMode::Adaptive { learning_rate: 0.3 },
+            FairnessMode::EqualSteps,
         ]
     }
 
@@ -106,10 +167,14 @@ impl ConfigurationState {
     /// Validate the current configuration
     pub fn validate(&self) -> Result<()> {
         // Validate array size
-        if self.array_size < 10 || self.array_size > 1000 {
-            return Err(anyhow!("Array size must be between 10 and 1000, got {}", self.array_size));
+        config::check_array_size(self.array_size as usize).map_err(|e| anyhow!(e))?;
+
+        if self.lanes.is_empty() {
+            return Err(anyhow!("At least one algorithm must be selected"));
         }
 
+        self.to_legacy_distribution().validate().map_err(|e| anyhow!(e))?;
+
         // Validate fairness mode parameters
         match &self.fairness_mode {
             FairnessMode::ComparisonBudget { k } => {
@@ -153,13 +218,18 @@ impl ConfigurationState {
 
     /// Set array size with validation
     pub fn set_array_size(&mut self, size: u32) -> Result<()> {
-        if !(10..=1000).contains(&size) {
-            return Err(anyhow!("Array size must be between 10 and 1000, got {}", size));
-        }
+        config::check_array_size(size as usize).map_err(|e| anyhow!(e))?;
         self.array_size = size;
         Ok(())
     }
 
+    /// Set distribution parameters, checking the ones the current distribution uses
+    pub fn set_distribution_params(&mut self, params: DistributionParams) -> Result<()> {
+        self.distribution.with_params(&params).validate().map_err(|e| anyhow!(e))?;
+        self.distribution_params = params;
+        Ok(())
+    }
+
     /// Set fairness mode and clear incompatible parameters
     pub fn set_fairness_mode(&mut self, mode: FairnessMode) {
         self.fairness_mode = mode.clone();
@@ -196,14 +266,65 @@ impl ConfigurationState {
         }
     }
 
+    /// Check if any lane races the given algorithm
+    pub fn has_algorithm(&self, algorithm: AlgorithmType) -> bool {
+        self.lanes.iter().any(|lane| lane.algorithm == algorithm)
+    }
+
+    /// Add a lane for the algorithm, or remove every lane racing it
+    ///
+    /// The last remaining algorithm cannot be removed.
+    pub fn toggle_algorithm(&mut self, algorithm: AlgorithmType) -> Result<()> {
+        if !self.has_algorithm(algorithm) {
+            self.lanes.push(LaneSpec::new(algorithm));
+            self.lanes = LaneSpec::with_unique_labels(std::mem::take(&mut self.lanes));
+            return Ok(());
+        }
+
+        if self.lanes.iter().all(|lane| lane.algorithm == algorithm) {
+            return Err(anyhow!("At least one algorithm must be selected"));
+        }
+        self.lanes.retain(|lane| lane.algorithm != algorithm);
+        Ok(())
+    }
+
+    /// Switch every lane racing the algorithm to its next or previous variant
+    ///
+    /// Variants are the plain algorithm followed by each of its lane options,
+    /// skipping options that only restate the default.
+    pub fn cycle_variant(&mut self, algorithm: AlgorithmType, forward: bool) -> Result<()> {
+        let Some(current) = self.lanes.iter().find(|lane| lane.algorithm == algorithm) else {
+            return Err(anyhow!("Select {} before choosing a variant", algorithm));
+        };
+
+        let mut variants = vec![Vec::new()];
+        for &option in algorithm.info().options {
+            let lane = LaneSpec::new(algorithm).with_options(vec![option.to_string()]).map_err(|e| anyhow!(e))?;
+            if lane.label != algorithm.to_string() {
+                variants.push(lane.options);
+            }
+        }
+        if variants.len() == 1 {
+            return Err(anyhow!("{} has no variants", algorithm));
+        }
+
+        let index = variants.iter().position(|options| *options == current.options).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % variants.len()
+        } else {
+            (index + variants.len() - 1) % variants.len()
+        };
+
+        for lane in self.lanes.iter_mut().filter(|lane| lane.algorithm == algorithm) {
+            *lane = LaneSpec::new(algorithm).with_options(variants[next].clone()).map_err(|e| anyhow!(e))?;
+        }
+        self.lanes = LaneSpec::with_unique_labels(std::mem::take(&mut self.lanes));
+        Ok(())
+    }
+
     /// Convert to the legacy Distribution enum for compatibility
     pub fn to_legacy_distribution(&self) -> Distribution {
-        match self.distribution {
-            DistributionType::Shuffled => Distribution::Shuffled,
-            DistributionType::Reversed => Distribution::Reversed,
-            DistributionType::NearlySorted => Distribution::NearlySorted,
-            DistributionType::FewUnique => Distribution::FewUnique,
-        }
+        self.distribution.with_params(&self.distribution_params)
     }
 }
 
@@ -215,12 +336,7 @@ impl Default for ConfigurationState {
 
 impl From<DistributionType> for Distribution {
     fn from(dist_type: DistributionType) -> Self {
-        match dist_type {
-            DistributionType::Shuffled => Distribution::Shuffled,
-            DistributionType::Reversed => Distribution::Reversed,
-            DistributionType::NearlySorted => Distribution::NearlySorted,
-            DistributionType::FewUnique => Distribution::FewUnique,
-        }
+        dist_type.with_params(&DistributionParams::default())
     }
 }
 
@@ -229,10 +345,18 @@ impl From<Distribution> for DistributionType {
         match distribution {
             Distribution::Shuffled => DistributionType::Shuffled,
             Distribution::Reversed => DistributionType::Reversed,
-            Distribution::NearlySorted => DistributionType::NearlySorted,
+            Distribution::NearlySorted { .. } => DistributionType::NearlySorted,
             Distribution::FewUnique => DistributionType::FewUnique,
-            Distribution::Sorted => DistributionType::Shuffled,     // Map to closest equivalent
-            Distribution::WithDuplicates => DistributionType::FewUnique,  // Map to closest equivalent
+            Distribution::Sorted => DistributionType::Sorted,
+            Distribution::WithDuplicates => DistributionType::WithDuplicates,
+            Distribution::MedianOfThreeKiller => DistributionType::MedianOfThreeKiller,
+            Distribution::OrganPipe => DistributionType::OrganPipe,
+            Distribution::Sawtooth => DistributionType::Sawtooth,
+            Distribution::Antiqsort(_) => DistributionType::Antiqsort,
+            Distribution::Gaussian { .. } => DistributionType::Gaussian,
+            Distribution::Zipf { .. } => DistributionType::Zipf,
+            Distribution::Uniform { .. } => DistributionType::Uniform,
+            Distribution::Runs { .. } => DistributionType::Runs,
         }
     }
 }
@@ -240,6 +364,7 @@ impl From<Distribution> for DistributionType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::config::MAX_ARRAY_SIZE;
 
     #[test]
     fn test_configuration_state_creation() {
@@ -261,12 +386,12 @@ mod tests {
         assert!(config.set_array_size(50).is_ok());
         assert_eq!(config.array_size, 50);
         
-        assert!(config.set_array_size(10).is_ok());
-        assert!(config.set_array_size(1000).is_ok());
+        assert!(config.set_array_size(1).is_ok());
+        assert!(config.set_array_size(MAX_ARRAY_SIZE as u32).is_ok());
         
         // Invalid sizes
-        assert!(config.set_array_size(5).is_err());
-        assert!(config.set_array_size(1001).is_err());
+        assert!(config.set_array_size(0).is_err());
+        assert!(config.set_array_size(MAX_ARRAY_SIZE as u32 + 1).is_err());
     }
 
     #[test]
@@ -295,7 +420,7 @@ mod tests {
         assert!(config.is_valid());
         
         // Invalid array size
-        config.array_size = 5;
+        config.array_size = 0;
         assert!(config.is_invalid());
         
         config.array_size = 100; // Reset to valid
@@ -306,17 +431,92 @@ mod tests {
         assert!(config.is_invalid());
     }
 
+    #[test]
+    fn test_distribution_params() {
+        let mut config = ConfigurationState::new();
+        config.distribution = DistributionType::Uniform;
+        assert_eq!(config.to_legacy_distribution(), Distribution::Uniform { min: 1, max: 100 });
+
+        let params = DistributionParams { value_range: Some((10, 20)), ..Default::default() };
+        assert!(config.set_distribution_params(params.clone()).is_ok());
+        assert_eq!(config.to_legacy_distribution(), Distribution::Uniform { min: 10, max: 20 });
+
+        let inverted = DistributionParams { value_range: Some((20, 10)), ..Default::default() };
+        assert!(config.set_distribution_params(inverted).is_err());
+        assert_eq!(config.distribution_params, params);
+
+        // Parameters given on the command line carry over
+        let run_config = crate::models::config::RunConfiguration {
+            distribution: Distribution::NearlySorted { swap_percent: 25.0 },
+            ..Default::default()
+        };
+        let config = ConfigurationState::from_run_config(&run_config);
+        assert_eq!(config.distribution, DistributionType::NearlySorted);
+        assert_eq!(config.to_legacy_distribution(), run_config.distribution);
+    }
+
+    #[test]
+    fn test_distribution_types_round_trip() {
+        for kind in ConfigurationState::get_available_distributions() {
+            let distribution = kind.with_params(&DistributionParams::default());
+            assert!(distribution.validate().is_ok(), "{:?}", kind);
+            assert_eq!(DistributionType::from(distribution), kind);
+        }
+    }
+
     #[test]
     fn test_available_options() {
         let sizes = ConfigurationState::get_available_array_sizes();
         assert_eq!(sizes, vec![10, 25, 50, 100, 200, 500, 1000]);
         
         let distributions = ConfigurationState::get_available_distributions();
-        assert_eq!(distributions.len(), 4);
+        assert_eq!(distributions.len(), 14);
         assert!(distributions.contains(&DistributionType::Shuffled));
+        assert!(distributions.contains(&DistributionType::Sorted));
+        assert!(distributions.contains(&DistributionType::Antiqsort));
         
         let fairness_modes = ConfigurationState::get_available_fairness_modes();
-        assert_eq!(fairness_modes.len(), 4);
+        assert_eq!(fairness_modes.len(), 5);
+    }
+
+    #[test]
+    fn test_toggle_algorithm() {
+        let mut config = ConfigurationState::new();
+        config.lanes = vec![LaneSpec::new(AlgorithmType::QuickSort), LaneSpec::new(AlgorithmType::QuickSort).with_label("Quick B")];
+
+        config.toggle_algorithm(AlgorithmType::HeapSort).unwrap();
+        assert_eq!(config.lanes.len(), 3);
+        assert!(config.has_algorithm(AlgorithmType::HeapSort));
+
+        // Removing an algorithm drops every lane that races it
+        config.toggle_algorithm(AlgorithmType::QuickSort).unwrap();
+        assert_eq!(config.lanes, vec![LaneSpec::new(AlgorithmType::HeapSort)]);
+
+        // The last algorithm stays selected
+        assert!(config.toggle_algorithm(AlgorithmType::HeapSort).is_err());
+        assert!(config.is_valid());
+
+        config.lanes.clear();
+        assert!(config.is_invalid());
+    }
+
+    #[test]
+    fn test_cycle_variant() {
+        let mut config = ConfigurationState::new();
+        config.lanes = vec![LaneSpec::new(AlgorithmType::ShellSort), LaneSpec::new(AlgorithmType::BubbleSort)];
+
+        // Knuth's sequence is the default, so the first variant is Shell's
+        config.cycle_variant(AlgorithmType::ShellSort, true).unwrap();
+        assert_eq!(config.lanes[0].options, vec!["shell"]);
+        assert_eq!(config.lanes[0].label, "Shell Sort (Shell)");
+
+        config.cycle_variant(AlgorithmType::ShellSort, false).unwrap();
+        assert_eq!(config.lanes[0], LaneSpec::new(AlgorithmType::ShellSort));
+        config.cycle_variant(AlgorithmType::ShellSort, false).unwrap();
+        assert_eq!(config.lanes[0].label, "Shell Sort (Pratt)");
+
+        assert!(config.cycle_variant(AlgorithmType::TimSort, true).is_err());
+        assert!(config.cycle_variant(AlgorithmType::BubbleSort, true).is_err());
     }
 
     #[test]
@@ -1,6 +1,7 @@
 //! Display mode for controlling array visualization
 
 use crate::models::{
+    lane::{DEFAULT_ALGORITHMS, LaneSpec},
     session::AlgorithmType,
     traits::Sorter,
 };
@@ -20,9 +21,9 @@ pub struct DisplayMode {
 }
 
 impl DisplayMode {
-    /// Create a new display mode
+    /// Create a new display mode over the default lanes
     pub fn new() -> Self {
-        let available_algorithms = AlgorithmType::all();
+        let available_algorithms = DEFAULT_ALGORITHMS.to_vec();
         Self {
             viewed_algorithm: available_algorithms[0], // Default to first algorithm (BubbleSort)
             available_algorithms,
@@ -96,14 +97,8 @@ impl DisplayMode {
 
     /// Get current array data from algorithm instances
     pub fn get_current_array_data<'a>(&self, algorithms: &'a [Box<dyn Sorter>]) -> Option<&'a [i32]> {
-        // Find the algorithm that matches our viewed algorithm
-        for (i, algorithm) in algorithms.iter().enumerate() {
-            if let Some(expected_type) = AlgorithmType::from_index(i)
-                && expected_type == self.viewed_algorithm {
-                    return Some(algorithm.get_array());
-                }
-        }
-        None
+        // Lanes are listed in race order, so the cycle index is the lane index
+        algorithms.get(self.cycle_index).map(|algorithm| algorithm.get_array())
     }
 
     /// Get array source algorithm name
@@ -198,6 +193,11 @@ impl DisplayMode {
         }
     }
 
+    /// Create display mode for a race's lane list
+    pub fn from_lanes(lanes: &[LaneSpec]) -> Result<Self> {
+        Self::with_algorithms(lanes.iter().map(|lane| lane.algorithm).collect())
+    }
+
     /// Create display mode from algorithm instances
     pub fn from_algorithms(algorithms: &[Box<dyn Sorter>]) -> Self {
         let available_algorithms = (0..algorithms.len())
@@ -0,0 +1,181 @@
+//! Element types that can be raced
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+/// A value that sorters can race on
+///
+/// Implement this for your own types to race them; the default bar height
+/// draws each element by its rank in the sorted input.
+pub trait Element: Clone + Debug + Send + Sync + 'static {
+    /// Height of this element's bar in the visualization
+    ///
+    /// Returns `None` to draw the element's rank instead.
+    fn bar_height(&self) -> Option<i32> {
+        None
+    }
+}
+
+impl Element for i32 {
+    fn bar_height(&self) -> Option<i32> {
+        Some(*self)
+    }
+}
+
+impl Element for i64 {}
+impl Element for u32 {}
+impl Element for u64 {}
+impl Element for usize {}
+impl Element for f32 {}
+impl Element for f64 {}
+impl Element for char {}
+impl Element for String {}
+impl Element for &'static str {}
+
+/// An element that can be sorted by its digits instead of by comparisons
+///
+/// Radix, counting and bucket sorts order elements by `radix_key`, so the
+/// key must sort the same way as the element's natural ordering.
+pub trait RadixKey: Element + Ord {
+    /// Unsigned key with the same ordering as the element
+    fn radix_key(&self) -> u64;
+}
+
+impl RadixKey for i32 {
+    fn radix_key(&self) -> u64 {
+        (*self as u32 ^ 0x8000_0000) as u64
+    }
+}
+
+impl RadixKey for i64 {
+    fn radix_key(&self) -> u64 {
+        *self as u64 ^ 0x8000_0000_0000_0000
+    }
+}
+
+impl RadixKey for u32 {
+    fn radix_key(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl RadixKey for u64 {
+    fn radix_key(&self) -> u64 {
+        *self
+    }
+}
+
+impl RadixKey for usize {
+    fn radix_key(&self) -> u64 {
+        *self as u64
+    }
+}
+
+type CompareFn<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// Ordering used by a sorter to compare elements
+pub struct Comparator<T> {
+    compare: CompareFn<T>,
+}
+
+impl<T> Comparator<T> {
+    /// Create a comparator from a comparison function
+    pub fn new(compare: impl Fn(&T, &T) -> Ordering + Send + Sync + 'static) -> Self {
+        Self {
+            compare: Arc::new(compare),
+        }
+    }
+
+    /// Compare elements by a key extracted from each
+    pub fn by_key<K: Ord>(key: impl Fn(&T) -> K + Send + Sync + 'static) -> Self {
+        Self::new(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Compare two elements
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.compare)(a, b)
+    }
+
+    /// Check if `a` sorts strictly after `b`
+    pub fn greater(&self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Greater
+    }
+
+    /// Check if `a` sorts strictly before `b`
+    pub fn less(&self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Less
+    }
+}
+
+impl<T: Ord + 'static> Comparator<T> {
+    /// Compare elements by their `Ord` implementation
+    pub fn natural() -> Self {
+        Self::new(T::cmp)
+    }
+}
+
+impl Comparator<f64> {
+    /// Compare floats with IEEE 754 total ordering
+    pub fn total() -> Self {
+        Self::new(f64::total_cmp)
+    }
+}
+
+impl Comparator<f32> {
+    /// Compare floats with IEEE 754 total ordering
+    pub fn total() -> Self {
+        Self::new(f32::total_cmp)
+    }
+}
+
+impl<T: Ord + 'static> Default for Comparator<T> {
+    fn default() -> Self {
+        Self::natural()
+    }
+}
+
+impl<T> Clone for Comparator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            compare: Arc::clone(&self.compare),
+        }
+    }
+}
+
+impl<T> Debug for Comparator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Comparator")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_and_key_comparators() {
+        let natural = Comparator::<i32>::natural();
+        assert!(natural.less(&1, &2));
+        assert!(natural.greater(&3, &2));
+
+        let by_len = Comparator::by_key(|s: &String| s.len());
+        assert_eq!(by_len.compare(&"ab".to_string(), &"cd".to_string()), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_radix_keys_preserve_ordering() {
+        let values = [i32::MIN, -5, -1, 0, 1, 7, i32::MAX];
+        assert!(values.windows(2).all(|w| w[0].radix_key() < w[1].radix_key()));
+
+        let values = [i64::MIN, -1, 0, i64::MAX];
+        assert!(values.windows(2).all(|w| w[0].radix_key() < w[1].radix_key()));
+    }
+
+    #[test]
+    fn test_total_float_ordering() {
+        let total = Comparator::<f64>::total();
+        assert!(total.less(&-0.0, &0.0));
+        assert!(total.greater(&f64::NAN, &f64::INFINITY));
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::models::{
     configuration::{ConfigurationState, DistributionType},
+    lane::LaneSpec,
 };
 use anyhow::{Result, anyhow};
 use std::time::Instant;
@@ -25,6 +26,7 @@ pub enum ConfigurationField {
     ArraySize,
     Distribution,
     FairnessMode,
+    Algorithms,
     BudgetParam,
     AlphaParam,
     BetaParam,
@@ -318,6 +320,13 @@ impl InteractiveMode {
         self.needs_update = true;
     }
 
+    /// Set the lanes to race interactively
+    pub fn set_lanes_interactive(&mut self, lanes: Vec<LaneSpec>) {
+        self.configuration.lanes = lanes;
+        self.clear_error_message();
+        self.needs_update = true;
+    }
+
     /// Set budget parameter
     pub fn set_budget_parameter(&mut self, budget: u32) -> Result<()> {
         if budget == 0 {
@@ -336,6 +345,7 @@ impl InteractiveMode {
         content.push_str("k - Array size configuration\n");
         content.push_str("b - Distribution configuration\n");
         content.push_str("f - Fairness mode configuration\n");
+        content.push_str("a - Algorithm selection\n");
         content.push_str("v - Switch array visualization\n");
         content.push_str("Space - Start/Pause race\n");
         content.push_str("? - Toggle help\n");
@@ -462,7 +472,7 @@ mod tests {
         let mut mode = InteractiveMode::new();
         
         assert!(mode.set_array_size_interactive(100).is_ok());
-        assert!(!mode.attempt_set_array_size(5)); // Too small
+        assert!(!mode.attempt_set_array_size(0)); // Too small
         assert!(mode.is_error_message_visible());
         
         mode.clear_error_message();
@@ -0,0 +1,183 @@
+//! Lane selection for a sorting race
+
+use crate::models::session::AlgorithmType;
+use crate::services::registry;
+
+/// Algorithms raced when no lanes are chosen
+///
+/// Curated rather than taken from the registry, so registering another
+/// sorter does not add it to every default race.
+pub const DEFAULT_ALGORITHMS: [AlgorithmType; 7] = [
+    AlgorithmType::BubbleSort,
+    AlgorithmType::SelectionSort,
+    AlgorithmType::InsertionSort,
+    AlgorithmType::MergeSort,
+    AlgorithmType::QuickSort,
+    AlgorithmType::HeapSort,
+    AlgorithmType::ShellSort,
+];
+
+/// A single racing lane: the algorithm to run and the label shown for it
+///
+/// The same algorithm may appear in several lanes as long as every lane
+/// has its own label, since lanes are told apart by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneSpec {
+    /// Algorithm raced in this lane
+    pub algorithm: AlgorithmType,
+    /// Display name of the lane
+    pub label: String,
+    /// Options selecting a variant of the algorithm, e.g. `median3`
+    pub options: Vec<String>,
+}
+
+impl LaneSpec {
+    /// Create a lane labelled with the algorithm's display name
+    pub fn new(algorithm: AlgorithmType) -> Self {
+        Self {
+            algorithm,
+            label: algorithm.to_string(),
+            options: Vec::new(),
+        }
+    }
+
+    /// Set the lane label
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Race a variant of the algorithm, labelling the lane with the variant's name
+    ///
+    /// Fails if the algorithm does not understand one of the options.
+    pub fn with_options(mut self, options: Vec<String>) -> Result<Self, String> {
+        self.label = if options.is_empty() {
+            self.algorithm.to_string()
+        } else {
+            registry::create_variant(self.algorithm.key(), &options)?.name().to_string()
+        };
+        self.options = options;
+        Ok(self)
+    }
+
+    /// Get the default lane list, one lane per [`DEFAULT_ALGORITHMS`] entry
+    pub fn defaults() -> Vec<LaneSpec> {
+        DEFAULT_ALGORITHMS.into_iter().map(LaneSpec::new).collect()
+    }
+
+    /// Parse a single lane from `key[+option...][:label]`, e.g. `quick:Quick (B)`
+    /// or `quick+median3+hoare`
+    ///
+    /// Lanes with options are labelled with the variant's name, e.g.
+    /// `Quick Sort (median-of-3, Hoare)`.
+    pub fn parse(spec: &str) -> Result<LaneSpec, String> {
+        let (variant, label) = match spec.split_once(':') {
+            Some((variant, label)) => (variant, Some(label.trim())),
+            None => (spec, None),
+        };
+        let mut parts = variant.split('+').map(str::trim);
+        let key = parts.next().unwrap_or_default();
+        let options: Vec<String> = parts.map(str::to_string).collect();
+
+        let algorithm = AlgorithmType::from_key(key).ok_or_else(|| {
+            let keys: Vec<&str> = AlgorithmType::all().iter().map(|a| a.key()).collect();
+            format!("Unknown algorithm '{}', expected one of: {}", key, keys.join(", "))
+        })?;
+
+        let lane = LaneSpec::new(algorithm).with_options(options)?;
+        match label {
+            Some("") => Err(format!("Lane label for '{}' must not be empty", key)),
+            Some(label) => Ok(lane.with_label(label)),
+            None => Ok(lane),
+        }
+    }
+
+    /// Parse a comma separated lane list, e.g. `quick,merge,quick:Quick (B)`
+    pub fn parse_list(list: &str) -> Result<Vec<LaneSpec>, String> {
+        let lanes = list
+            .split(',')
+            .map(LaneSpec::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::with_unique_labels(lanes))
+    }
+
+    /// Suffix repeated labels with ` #2`, ` #3`, ... so every lane has its own name
+    pub fn with_unique_labels(mut lanes: Vec<LaneSpec>) -> Vec<LaneSpec> {
+        for index in 1..lanes.len() {
+            let (earlier, rest) = lanes.split_at_mut(index);
+            let lane = &mut rest[0];
+            if !earlier.iter().any(|other| other.label == lane.label) {
+                continue;
+            }
+
+            let base = lane.label.clone();
+            let mut suffix = 2;
+            while earlier.iter().any(|other| other.label == format!("{} #{}", base, suffix)) {
+                suffix += 1;
+            }
+            lane.label = format!("{} #{}", base, suffix);
+        }
+        lanes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_and_label() {
+        assert_eq!(LaneSpec::parse("quick").unwrap(), LaneSpec::new(AlgorithmType::QuickSort));
+
+        let lane = LaneSpec::parse("merge:Merge (B)").unwrap();
+        assert_eq!(lane.algorithm, AlgorithmType::MergeSort);
+        assert_eq!(lane.label, "Merge (B)");
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_key_and_empty_label() {
+        let error = LaneSpec::parse("bogo").unwrap_err();
+        assert!(error.contains("bogo"));
+        assert!(error.contains("quick"));
+
+        assert!(LaneSpec::parse("quick:").is_err());
+    }
+
+    #[test]
+    fn test_parse_variant_options() {
+        let lane = LaneSpec::parse("quick+median3+hoare").unwrap();
+        assert_eq!(lane.algorithm, AlgorithmType::QuickSort);
+        assert_eq!(lane.options, vec!["median3", "hoare"]);
+        assert_eq!(lane.label, "Quick Sort (median-of-3, Hoare)");
+
+        assert_eq!(LaneSpec::parse("quick+first:First").unwrap().label, "First");
+        assert!(LaneSpec::parse("quick+middle").unwrap_err().contains("median3"));
+        assert!(LaneSpec::parse("quick+").is_err());
+        assert!(LaneSpec::parse("merge+hoare").is_err());
+    }
+
+    #[test]
+    fn test_duplicate_lanes_get_unique_labels() {
+        let lanes = LaneSpec::parse_list("quick,heap,quick,quick").unwrap();
+        let labels: Vec<&str> = lanes.iter().map(|lane| lane.label.as_str()).collect();
+
+        assert_eq!(labels, vec!["Quick Sort", "Heap Sort", "Quick Sort #2", "Quick Sort #3"]);
+    }
+
+    #[test]
+    fn test_defaults_are_the_classic_sorts() {
+        let labels: Vec<String> = LaneSpec::defaults().into_iter().map(|lane| lane.label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Bubble Sort",
+                "Selection Sort",
+                "Insertion Sort",
+                "Merge Sort",
+                "Quick Sort",
+                "Heap Sort",
+                "Shell Sort",
+            ]
+        );
+    }
+}
@@ -19,6 +19,16 @@ impl Markers {
         self.merge_runs.clear();
         self.cursors.clear();
         self.gap = None;
+        self.runs.clear();
+        self.run_stack.clear();
+        self.galloping = false;
+        self.heapsort_range = None;
+        self.insertion_range = None;
+        self.pattern = None;
+        self.digit_pass = None;
+        self.buckets.clear();
+        self.network_layer = None;
+        self.comparators.clear();
     }
 
     /// Set pivot marker for Quick Sort
@@ -76,6 +86,31 @@ impl Markers {
         self.gap = None;
     }
 
+    /// Record a natural run detected by Tim Sort
+    pub fn add_run(&mut self, start: usize, end: usize) {
+        self.runs.push((start, end));
+    }
+
+    /// Set the pending run stack for Tim Sort
+    pub fn set_run_stack(&mut self, runs: Vec<(usize, usize)>) {
+        self.run_stack = runs;
+    }
+
+    /// Set the current digit pass for radix sorts, both 1-based
+    pub fn set_digit_pass(&mut self, pass: usize, passes: usize) {
+        self.digit_pass = Some((pass, passes));
+    }
+
+    /// Set the bucket boundaries being filled or sorted
+    pub fn set_buckets(&mut self, buckets: Vec<(usize, usize)>) {
+        self.buckets = buckets;
+    }
+
+    /// Set the current comparator layer of a sorting network, both 1-based
+    pub fn set_network_layer(&mut self, layer: usize, layers: usize) {
+        self.network_layer = Some((layer, layers));
+    }
+
     /// Check if any markers are active
     pub fn has_active_markers(&self) -> bool {
         self.pivot.is_some() 
@@ -83,6 +118,31 @@ impl Markers {
             || !self.merge_runs.is_empty() 
             || !self.cursors.is_empty() 
             || self.gap.is_some()
+            || !self.runs.is_empty()
+            || !self.run_stack.is_empty()
+            || self.galloping
+            || self.heapsort_range.is_some()
+            || self.insertion_range.is_some()
+            || self.pattern.is_some()
+            || self.digit_pass.is_some()
+            || !self.buckets.is_empty()
+            || self.network_layer.is_some()
+            || !self.comparators.is_empty()
+    }
+
+    /// Short description of a special mode the algorithm is in, if any
+    pub fn mode_label(&self) -> Option<String> {
+        if self.galloping {
+            Some("galloping".to_string())
+        } else if self.heapsort_range.is_some() {
+            Some("heapsort fallback".to_string())
+        } else if let Some((pass, passes)) = self.digit_pass {
+            Some(format!("digit pass {}/{}", pass, passes))
+        } else if let Some((layer, layers)) = self.network_layer {
+            Some(format!("layer {}/{}", layer, layers))
+        } else {
+            self.pattern.clone()
+        }
     }
 
     /// Get all active marker positions as a flat vector
@@ -103,6 +163,21 @@ impl Markers {
             positions.push(*start);
             positions.push(*end);
         }
+
+        for (start, end) in self.heapsort_range.iter().chain(&self.insertion_range) {
+            positions.push(*start);
+            positions.push(*end);
+        }
+
+        for (start, end) in &self.run_stack {
+            positions.push(*start);
+            positions.push(*end);
+        }
+
+        for (low, high) in &self.comparators {
+            positions.push(*low);
+            positions.push(*high);
+        }
         
         positions.sort_unstable();
         positions.dedup();
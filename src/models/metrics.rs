@@ -1,12 +1,20 @@
 //! Performance metrics tracking for sorting algorithms
 
+use serde::{Deserialize, Serialize};
+
 /// Comprehensive metrics for algorithm performance
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Metrics {
     /// Total number of comparisons performed
     pub comparisons: u64,
     /// Total number of element moves/swaps performed
     pub moves: u64,
+    /// Total number of element writes, counting a swap as two
+    #[serde(default)]
+    pub writes: u64,
+    /// Comparator layers run so far, for sorting networks
+    #[serde(default)]
+    pub parallel_depth: Option<u64>,
     /// Total execution time in microseconds
     pub execution_time_us: u64,
     /// Peak memory usage in bytes
@@ -42,6 +50,11 @@ impl Metrics {
         self.moves += count;
     }
 
+    /// Add write count
+    pub fn add_writes(&mut self, count: u64) {
+        self.writes += count;
+    }
+
     /// Add execution time
     pub fn add_time(&mut self, microseconds: u64) {
         self.execution_time_us += microseconds;
@@ -98,7 +111,7 @@ impl Metrics {
 }
 
 /// Snapshot of metrics at a point in time
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricsSnapshot {
     /// The metrics data
     pub metrics: Metrics,
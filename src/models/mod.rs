@@ -1,6 +1,7 @@
 //! Models module
 
 pub mod traits;
+pub mod element;
 pub mod algorithm;
 pub mod metrics;
 pub mod config;
@@ -8,5 +9,6 @@ pub mod markers;
 pub mod configuration;
 pub mod interactive_mode;
 pub mod session;
+pub mod lane;
 pub mod display_mode;
 pub mod memory_metrics;
\ No newline at end of file
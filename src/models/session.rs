@@ -3,34 +3,52 @@
 use crate::models::{
     configuration::{ConfigurationState, DistributionType},
     config::FairnessMode,
+    lane::LaneSpec,
     traits::Sorter,
 };
+use crate::services::registry::{self, SorterInfo};
 use std::time::Instant;
 
 /// Algorithm type identifier
+///
+/// Identifies an algorithm in the sorter registry by its key. The built-in
+/// algorithms are available as associated constants; algorithms registered
+/// by downstream crates are looked up with [`AlgorithmType::from_key`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum AlgorithmType {
-    BubbleSort,
-    SelectionSort,
-    InsertionSort,
-    MergeSort,
-    QuickSort,
-    HeapSort,
-    ShellSort,
-}
+pub struct AlgorithmType(&'static str);
 
+#[allow(non_upper_case_globals)]
 impl AlgorithmType {
-    /// Get all available algorithm types
+    pub const BubbleSort: AlgorithmType = AlgorithmType("bubble");
+    pub const SelectionSort: AlgorithmType = AlgorithmType("selection");
+    pub const InsertionSort: AlgorithmType = AlgorithmType("insertion");
+    pub const MergeSort: AlgorithmType = AlgorithmType("merge");
+    pub const QuickSort: AlgorithmType = AlgorithmType("quick");
+    pub const HeapSort: AlgorithmType = AlgorithmType("heap");
+    pub const ShellSort: AlgorithmType = AlgorithmType("shell");
+    pub const TimSort: AlgorithmType = AlgorithmType("tim");
+    pub const IntroSort: AlgorithmType = AlgorithmType("intro");
+    pub const PdqSort: AlgorithmType = AlgorithmType("pdq");
+    pub const LsdRadixSort: AlgorithmType = AlgorithmType("lsd-radix");
+    pub const MsdRadixSort: AlgorithmType = AlgorithmType("msd-radix");
+    pub const CountingSort: AlgorithmType = AlgorithmType("counting");
+    pub const BucketSort: AlgorithmType = AlgorithmType("bucket");
+    pub const CocktailSort: AlgorithmType = AlgorithmType("cocktail");
+    pub const CombSort: AlgorithmType = AlgorithmType("comb");
+    pub const GnomeSort: AlgorithmType = AlgorithmType("gnome");
+    pub const OddEvenSort: AlgorithmType = AlgorithmType("odd-even");
+    pub const CycleSort: AlgorithmType = AlgorithmType("cycle");
+    pub const BitonicSort: AlgorithmType = AlgorithmType("bitonic");
+    pub const OddEvenMergeSort: AlgorithmType = AlgorithmType("odd-even-merge");
+
+    /// Create an algorithm type for a registered key
+    pub(crate) const fn new(key: &'static str) -> Self {
+        AlgorithmType(key)
+    }
+
+    /// Get all registered algorithm types in registration order
     pub fn all() -> Vec<AlgorithmType> {
-        vec![
-            AlgorithmType::BubbleSort,
-            AlgorithmType::SelectionSort,
-            AlgorithmType::InsertionSort,
-            AlgorithmType::MergeSort,
-            AlgorithmType::QuickSort,
-            AlgorithmType::HeapSort,
-            AlgorithmType::ShellSort,
-        ]
+        registry::with_registry(|registry| registry.keys().into_iter().map(AlgorithmType).collect())
     }
 
     /// Get algorithm type from index
@@ -41,28 +59,31 @@ impl AlgorithmType {
 
     /// Get index of algorithm type
     pub fn to_index(self) -> usize {
-        match self {
-            AlgorithmType::BubbleSort => 0,
-            AlgorithmType::SelectionSort => 1,
-            AlgorithmType::InsertionSort => 2,
-            AlgorithmType::MergeSort => 3,
-            AlgorithmType::QuickSort => 4,
-            AlgorithmType::HeapSort => 5,
-            AlgorithmType::ShellSort => 6,
-        }
+        registry::with_registry(|registry| registry.position(self.0))
+            .expect("algorithm types always refer to registered algorithms")
+    }
+
+    /// Get the short key used to select the algorithm on the command line
+    pub fn key(self) -> &'static str {
+        self.0
+    }
+
+    /// Get algorithm type from its command line key
+    pub fn from_key(key: &str) -> Option<AlgorithmType> {
+        registry::sorter_info(key).map(|info| AlgorithmType(info.key))
+    }
+
+    /// Get the registry metadata for this algorithm
+    pub fn info(self) -> SorterInfo {
+        registry::sorter_info(self.0).expect("algorithm types always refer to registered algorithms")
     }
 }
 
 impl std::fmt::Display for AlgorithmType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AlgorithmType::BubbleSort => write!(f, "Bubble Sort"),
-            AlgorithmType::SelectionSort => write!(f, "Selection Sort"),
-            AlgorithmType::InsertionSort => write!(f, "Insertion Sort"),
-            AlgorithmType::MergeSort => write!(f, "Merge Sort"),
-            AlgorithmType::QuickSort => write!(f, "Quick Sort"),
-            AlgorithmType::HeapSort => write!(f, "Heap Sort"),
-            AlgorithmType::ShellSort => write!(f, "Shell Sort"),
+        match registry::sorter_info(self.0) {
+            Some(info) => write!(f, "{}", info.name),
+            None => write!(f, "{}", self.0),
         }
     }
 }
@@ -80,10 +101,14 @@ pub struct RaceResult {
     pub completion_times: Vec<Option<std::time::Duration>>,
     /// Memory usage for each algorithm
     pub memory_usage: Vec<usize>,
-    /// Algorithm type names for reference
+    /// Lane labels, in lane order
     pub algorithm_names: Vec<String>,
+    /// Algorithm raced in each lane
+    pub lane_algorithms: Vec<AlgorithmType>,
     /// Winner algorithm (first to complete)
     pub winner: Option<AlgorithmType>,
+    /// Index of the winning lane
+    pub winner_lane: Option<usize>,
     /// Race start timestamp
     pub race_start: Instant,
     /// Race end timestamp
@@ -93,21 +118,23 @@ pub struct RaceResult {
 }
 
 impl RaceResult {
-    /// Create a new race result
+    /// Create a new race result for the given lanes
     pub fn new(
         array_size: u32,
         distribution: DistributionType,
         fairness_mode: FairnessMode,
-        algorithm_count: usize,
+        lanes: &[LaneSpec],
     ) -> Self {
         Self {
             array_size,
             distribution,
             fairness_mode,
-            completion_times: vec![None; algorithm_count],
-            memory_usage: vec![0; algorithm_count],
-            algorithm_names: Vec::new(),
+            completion_times: vec![None; lanes.len()],
+            memory_usage: vec![0; lanes.len()],
+            algorithm_names: lanes.iter().map(|lane| lane.label.clone()).collect(),
+            lane_algorithms: lanes.iter().map(|lane| lane.algorithm).collect(),
             winner: None,
+            winner_lane: None,
             race_start: Instant::now(),
             race_end: None,
             total_duration: None,
@@ -126,12 +153,20 @@ impl RaceResult {
             self.completion_times[algorithm_index] = Some(duration);
             
             // Set winner if this is the first completion
-            if self.winner.is_none() {
-                self.winner = AlgorithmType::from_index(algorithm_index);
+            if self.winner_lane.is_none() {
+                self.winner_lane = Some(algorithm_index);
+                self.winner = self.lane_algorithms.get(algorithm_index).copied();
             }
         }
     }
 
+    /// Get the label of the winning lane
+    pub fn winner_name(&self) -> Option<&str> {
+        self.winner_lane
+            .and_then(|lane| self.algorithm_names.get(lane))
+            .map(String::as_str)
+    }
+
     /// Set memory usage for an algorithm
     pub fn set_memory_usage(&mut self, algorithm_index: usize, memory: usize) {
         if algorithm_index < self.memory_usage.len() {
@@ -177,21 +212,14 @@ impl SessionState {
         // Validate configuration
         self.current_config.validate()?;
 
-        // Create new race result
-        let algorithm_count = AlgorithmType::all().len();
-        let mut race_result = RaceResult::new(
+        // Create new race result for the configured lanes
+        let race_result = RaceResult::new(
             self.current_config.array_size,
             self.current_config.distribution,
             self.current_config.fairness_mode.clone(),
-            algorithm_count,
+            &self.current_config.lanes,
         );
 
-        // Set algorithm names
-        race_result.algorithm_names = AlgorithmType::all()
-            .iter()
-            .map(|alg| alg.to_string())
-            .collect();
-
         self.current_race = Some(race_result);
         Ok(())
     }
@@ -214,7 +242,7 @@ impl SessionState {
                 race.set_memory_usage(i, memory);
 
                 // Check if algorithm completed and set completion time
-                if algorithm.is_complete() && race.completion_times[i].is_none() {
+                if algorithm.is_complete() && race.completion_times.get(i) == Some(&None) {
                     let completion_time = race.race_start.elapsed();
                     race.set_completion_time(i, completion_time);
                 }
@@ -355,22 +383,23 @@ impl SessionStatistics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::lane::DEFAULT_ALGORITHMS;
 
     #[test]
     fn test_algorithm_type_conversions() {
         assert_eq!(AlgorithmType::from_index(0), Some(AlgorithmType::BubbleSort));
         assert_eq!(AlgorithmType::from_index(6), Some(AlgorithmType::ShellSort));
-        assert_eq!(AlgorithmType::from_index(7), None);
+        assert_eq!(AlgorithmType::from_index(AlgorithmType::all().len()), None);
 
         assert_eq!(AlgorithmType::BubbleSort.to_index(), 0);
         assert_eq!(AlgorithmType::ShellSort.to_index(), 6);
 
-        assert_eq!(AlgorithmType::all().len(), 7);
+        assert!(AlgorithmType::all().starts_with(&DEFAULT_ALGORITHMS));
     }
 
     #[test]
     fn test_race_result_creation() {
-        let result = RaceResult::new(100, DistributionType::Shuffled, FairnessMode::WallTime { slice_ms: 50 }, 7);
+        let result = RaceResult::new(100, DistributionType::Shuffled, FairnessMode::WallTime { slice_ms: 50 }, &LaneSpec::defaults());
         
         assert_eq!(result.array_size, 100);
         assert_eq!(result.distribution, DistributionType::Shuffled);
@@ -382,10 +411,14 @@ mod tests {
 
     #[test]
     fn test_race_result_completion() {
-        let mut result = RaceResult::new(50, DistributionType::Reversed, FairnessMode::WallTime { slice_ms: 50 }, 3);
+        let lanes = LaneSpec::parse_list("bubble,quick,quick").unwrap();
+        let mut result = RaceResult::new(50, DistributionType::Reversed, FairnessMode::WallTime { slice_ms: 50 }, &lanes);
         
-        result.set_completion_time(1, std::time::Duration::from_millis(100));
-        assert_eq!(result.winner, Some(AlgorithmType::SelectionSort));
+        result.set_completion_time(2, std::time::Duration::from_millis(100));
+        result.set_completion_time(0, std::time::Duration::from_millis(200));
+        assert_eq!(result.winner, Some(AlgorithmType::QuickSort));
+        assert_eq!(result.winner_lane, Some(2));
+        assert_eq!(result.winner_name(), Some("Quick Sort #2"));
         
         result.complete();
         assert!(result.is_complete());
@@ -409,6 +442,7 @@ mod tests {
         // Start new race
         assert!(session.start_new_race().is_ok());
         assert!(session.has_current_race());
+        assert_eq!(session.get_current_race().unwrap().completion_times.len(), LaneSpec::defaults().len());
         
         // Complete race
         session.complete_current_race();
@@ -417,6 +451,18 @@ mod tests {
         assert_eq!(session.run_history.len(), 1);
     }
 
+    #[test]
+    fn test_race_follows_configured_lanes() {
+        let mut session = SessionState::new();
+        session.current_config.lanes = LaneSpec::parse_list("heap,merge").unwrap();
+        session.start_new_race().unwrap();
+
+        let race = session.get_current_race().unwrap();
+        assert_eq!(race.algorithm_names, vec!["Heap Sort", "Merge Sort"]);
+        assert_eq!(race.lane_algorithms, vec![AlgorithmType::HeapSort, AlgorithmType::MergeSort]);
+        assert_eq!(race.completion_times.len(), 2);
+    }
+
     #[test]
     fn test_session_statistics() {
         let mut session = SessionState::new();
@@ -1,5 +1,6 @@
 //! Core traits for the sorting race visualization
 
+use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::fmt::Debug;
 
@@ -14,8 +15,42 @@ pub struct StepResult {
     pub continued: bool,
 }
 
+/// Unit a sorter's step budget is measured in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BudgetUnit {
+    /// One unit per comparison
+    #[default]
+    Comparisons,
+    /// One unit per element operation: every element counted, moved or
+    /// compared, for sorters that do not work by comparisons
+    Operations,
+}
+
+impl BudgetUnit {
+    /// Work a sorter has spent so far, in this unit
+    pub fn spent(self, telemetry: &Telemetry) -> u64 {
+        match self {
+            BudgetUnit::Comparisons => telemetry.total_comparisons,
+            BudgetUnit::Operations => telemetry.total_comparisons + telemetry.total_moves,
+        }
+    }
+}
+
+/// How finely a sorter's `step_operations` divides its work
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    /// A budget of one covers one comparison together with the moves and
+    /// bookkeeping that follow from it before the next comparison
+    #[default]
+    Comparison,
+    /// A budget of one covers exactly one operation: a comparison or an
+    /// element move, along with any bookkeeping before the next one
+    Operation,
+}
+
 /// Visual markers for algorithm-specific operations
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Markers {
     /// Current pivot index (Quick Sort)
     pub pivot: Option<usize>,
@@ -25,17 +60,46 @@ pub struct Markers {
     pub merge_runs: Vec<(usize, usize)>,
     /// Current comparison positions
     pub cursors: Vec<usize>,
-    /// Current gap size (Shell Sort)
+    /// Current gap size (Shell Sort, Comb Sort)
     pub gap: Option<usize>,
+    /// Natural runs detected in the input (Tim Sort, natural Merge Sort)
+    pub runs: Vec<(usize, usize)>,
+    /// Sorted runs waiting to be merged, bottom of the stack first (Tim Sort)
+    pub run_stack: Vec<(usize, usize)>,
+    /// Whether the active merge is galloping (Tim Sort)
+    pub galloping: bool,
+    /// Range being heap sorted as a fallback (Intro Sort, Pdq Sort)
+    pub heapsort_range: Option<(usize, usize)>,
+    /// Range being insertion sorted (Intro Sort, Pdq Sort)
+    pub insertion_range: Option<(usize, usize)>,
+    /// Input pattern the algorithm just reacted to (Pdq Sort)
+    pub pattern: Option<String>,
+    /// Current digit pass and total passes, 1-based (Radix Sorts)
+    pub digit_pass: Option<(usize, usize)>,
+    /// Bucket boundaries as inclusive ranges (Radix, Counting and Bucket Sorts)
+    pub buckets: Vec<(usize, usize)>,
+    /// Current comparator layer and total layers, 1-based (Sorting Networks)
+    pub network_layer: Option<(usize, usize)>,
+    /// Comparators of the current layer, which run simultaneously (Sorting
+    /// Networks)
+    pub comparators: Vec<(usize, usize)>,
 }
 
 /// Telemetry data returned after each step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Telemetry {
     /// Total comparisons so far
     pub total_comparisons: u64,
     /// Total moves so far
     pub total_moves: u64,
+    /// Total element writes so far, to the array or to a buffer; a swap
+    /// writes two elements
+    #[serde(default)]
+    pub total_writes: u64,
+    /// Comparator layers run so far, for sorters that run a sorting network;
+    /// each layer's comparisons could all happen at once
+    #[serde(default)]
+    pub parallel_depth: Option<u64>,
     /// Current auxiliary memory usage in bytes
     pub memory_current: usize,
     /// Peak auxiliary memory usage in bytes
@@ -51,15 +115,47 @@ pub struct Telemetry {
 }
 
 /// Core trait that all sorting algorithms must implement
-pub trait Sorter: Debug + Send + Any {
+///
+/// Sorters are generic over the element type `T`, which defaults to `i32`
+/// as used by the terminal UI. See [`crate::models::element`] for racing
+/// other types.
+pub trait Sorter<T = i32>: Debug + Send + Any {
     /// Execute one step of the sorting algorithm
     /// 
     /// # Arguments
-    /// * `budget` - Maximum number of comparisons allowed in this step
+    /// * `budget` - Maximum work allowed in this step, in the sorter's
+    ///   [`Sorter::budget_unit`]
     /// 
     /// # Returns
     /// * `StepResult` - Information about operations performed
     fn step(&mut self, budget: usize) -> StepResult;
+
+    /// Unit the `step` budget is measured in
+    ///
+    /// Comparison sorts spend one unit per comparison. Sorters that never
+    /// compare elements, such as radix sort, report
+    /// [`BudgetUnit::Operations`] and spend their budget on element moves.
+    fn budget_unit(&self) -> BudgetUnit {
+        BudgetUnit::Comparisons
+    }
+
+    /// Execute one step, spending at most `budget` operations
+    ///
+    /// Every comparison and every element move costs one operation. Sorters
+    /// that already count [`BudgetUnit::Operations`] step the same way as
+    /// with [`Sorter::step`], which is the default.
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step(budget)
+    }
+
+    /// How much work a budget of one buys in [`Sorter::step_operations`]
+    ///
+    /// Equal-steps fairness gives every lane a budget of one operation per
+    /// step. [`Granularity::Operation`] sorters then do exactly one; coarser
+    /// sorters do a whole comparison with the moves that follow from it.
+    fn granularity(&self) -> Granularity {
+        Granularity::Comparison
+    }
     
     /// Check if the algorithm has completed sorting
     fn is_complete(&self) -> bool;
@@ -71,13 +167,22 @@ pub trait Sorter: Debug + Send + Any {
     /// 
     /// # Arguments
     /// * `data` - New array to sort
-    fn reset(&mut self, data: Vec<i32>);
+    fn reset(&mut self, data: Vec<T>);
     
     /// Get the algorithm's display name
     fn name(&self) -> &str;
     
     /// Get current array state (for visualization)
-    fn get_array(&self) -> &[i32];
+    fn get_array(&self) -> &[T];
+
+    /// Get the original input index of each element, in `get_array` order
+    ///
+    /// Sorters that move this alongside their data let the race check
+    /// whether equal keys kept their input order. Returns `None` if the
+    /// sorter does not track element identity.
+    fn get_origins(&self) -> Option<&[usize]> {
+        None
+    }
     
     /// Get auxiliary memory usage in bytes
     fn get_memory_usage(&self) -> usize;
@@ -90,15 +195,25 @@ pub trait Sorter: Debug + Send + Any {
 }
 
 /// Fairness model trait for allocating step budgets
-pub trait FairnessModel: Debug {
+pub trait FairnessModel<T = i32>: Debug {
     /// Allocate step budgets to algorithms
     /// 
     /// # Arguments
     /// * `algorithms` - Current state of all algorithms
     /// 
     /// # Returns
-    /// * Vector of budgets (comparisons) for each algorithm
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize>;
+    /// * Vector of budgets for each algorithm, in each algorithm's
+    ///   [`Sorter::budget_unit`] or in operations, per [`FairnessModel::granularity`]
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize>;
+
+    /// How the allocated budgets are spent
+    ///
+    /// With [`Granularity::Operation`] every budget counts operations and
+    /// lanes step with [`Sorter::step_operations`]; otherwise they step with
+    /// [`Sorter::step`] in their own [`Sorter::budget_unit`].
+    fn granularity(&self) -> Granularity {
+        Granularity::Comparison
+    }
     
     /// Get the model's display name
     fn name(&self) -> &str;
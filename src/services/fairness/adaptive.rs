@@ -31,7 +31,7 @@ impl AdaptiveFairness {
     }
 
     /// Update progress rate for an algorithm using exponential moving average
-    fn update_progress_rate(&self, algorithm: &dyn Sorter) {
+    fn update_progress_rate<T: 'static>(&self, algorithm: &dyn Sorter<T>) {
         let name = algorithm.name();
         let current_progress = algorithm.get_telemetry().progress_hint;
         
@@ -88,8 +88,8 @@ impl Default for AdaptiveFairness {
     }
 }
 
-impl FairnessModel for AdaptiveFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for AdaptiveFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         // Update progress rates for all algorithms
         for algorithm in algorithms.iter() {
             if !algorithm.is_complete() {
@@ -194,6 +194,8 @@ mod tests {
             Telemetry {
                 total_comparisons: 0,
                 total_moves: 0,
+                total_writes: 0,
+                parallel_depth: None,
                 memory_current: 0,
                 memory_peak: 0,
                 highlights: vec![],
@@ -230,7 +232,7 @@ mod tests {
     fn test_adaptive_fairness_creation() {
         let model = AdaptiveFairness::new(0.2);
         assert_eq!(model.learning_rate, 0.2);
-        assert_eq!(model.name(), "Adaptive Fairness");
+        assert_eq!(FairnessModel::<i32>::name(&model), "Adaptive Fairness");
     }
     
     #[test]
@@ -3,6 +3,8 @@
 use crate::models::traits::{FairnessModel, Sorter};
 
 /// Fairness model that allocates equal comparison budgets to all algorithms
+///
+/// Comparison-free sorters get the same budget in element operations.
 #[derive(Debug)]
 pub struct ComparisonFairness {
     budget_per_step: usize,
@@ -33,8 +35,8 @@ impl Default for ComparisonFairness {
     }
 }
 
-impl FairnessModel for ComparisonFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for ComparisonFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         // Allocate equal budget to all algorithms
         algorithms
             .iter()
@@ -100,6 +102,6 @@ mod tests {
     #[test]
     fn test_comparison_fairness_name() {
         let fairness = ComparisonFairness::new(5);
-        assert_eq!(fairness.name(), "Comparison Budget");
+        assert_eq!(FairnessModel::<i32>::name(&fairness), "Comparison Budget");
     }
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+//! Equal-steps fairness model implementation
+
+use crate::models::traits::{FairnessModel, Granularity, Sorter};
+
+/// Fairness model that lets every algorithm perform one operation per step
+///
+/// Operations are comparisons and element moves alike. Every running
+/// algorithm gets a budget of one operation each step; algorithms with
+/// [`Granularity::Operation`] then take exactly one, so operation counts
+/// stay level.
+#[derive(Debug, Default)]
+pub struct EqualStepsFairness;
+
+impl EqualStepsFairness {
+    /// Create a new equal-steps fairness model
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: 'static> FairnessModel<T> for EqualStepsFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
+        algorithms
+            .iter()
+            .map(|algorithm| if algorithm.is_complete() { 0 } else { 1 })
+            .collect()
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn name(&self) -> &str {
+        "Equal Steps"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::sorters::{bubble::BubbleSort, radix::LsdRadixSort};
+
+    #[test]
+    fn test_equal_steps_gives_one_operation() {
+        let fairness = EqualStepsFairness::new();
+        let mut algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(BubbleSort::new()), Box::new(LsdRadixSort::new())];
+        for algorithm in &mut algorithms {
+            algorithm.reset(vec![3, 2, 1]);
+        }
+
+        assert_eq!(fairness.allocate_budget(&algorithms), vec![1, 1]);
+    }
+
+    #[test]
+    fn test_equal_steps_splits_a_comparison_from_its_swap() {
+        let fairness = EqualStepsFairness::new();
+        let mut algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(BubbleSort::new()), Box::new(BubbleSort::new())];
+        algorithms[0].reset(vec![2, 1, 3, 4]);
+        algorithms[1].reset(vec![1, 2, 3, 4]);
+        assert_eq!(FairnessModel::<i32>::granularity(&fairness), Granularity::Operation);
+
+        // The first comparison calls for a swap in one lane only, which
+        // waits for the next step
+        let budgets = fairness.allocate_budget(&algorithms);
+        let results: Vec<_> = algorithms
+            .iter_mut()
+            .zip(budgets)
+            .map(|(algorithm, budget)| algorithm.step_operations(budget))
+            .collect();
+        assert!(results.iter().all(|result| result.comparisons_used == 1 && result.moves_made == 0));
+        assert_eq!(algorithms[0].get_array(), &[2, 1, 3, 4]);
+
+        assert_eq!(fairness.allocate_budget(&algorithms), vec![1, 1]);
+        let result = algorithms[0].step_operations(1);
+        assert_eq!((result.comparisons_used, result.moves_made), (0, 1));
+        assert_eq!(algorithms[0].get_array(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_equal_steps_completed_algorithm() {
+        let fairness = EqualStepsFairness::new();
+        let mut done = BubbleSort::new();
+        done.reset(vec![1]);
+        let mut running = BubbleSort::new();
+        running.reset(vec![2, 1]);
+
+        let algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(done), Box::new(running)];
+        assert_eq!(fairness.allocate_budget(&algorithms), vec![0, 1]);
+    }
+}
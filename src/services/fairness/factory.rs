@@ -0,0 +1,21 @@
+//! Construction of fairness models from configuration
+
+use crate::models::config::FairnessMode;
+use crate::models::traits::FairnessModel;
+use crate::services::fairness::{
+    adaptive::AdaptiveFairness, comparison::ComparisonFairness, equal_steps::EqualStepsFairness,
+    walltime::WallTimeFairness, weighted::WeightedFairness,
+};
+
+/// Create the fairness model described by a fairness mode
+///
+/// Fairness models only look at telemetry, so they work for any element type.
+pub fn create_fairness_model<T: 'static>(fairness_mode: &FairnessMode) -> Box<dyn FairnessModel<T>> {
+    match fairness_mode {
+        FairnessMode::ComparisonBudget { k } => Box::new(ComparisonFairness::new(*k)),
+        FairnessMode::Weighted { alpha, beta } => Box::new(WeightedFairness::new(*alpha, *beta)),
+        FairnessMode::WallTime { slice_ms } => Box::new(WallTimeFairness::new(*slice_ms)),
+        FairnessMode::Adaptive { learning_rate } => Box::new(AdaptiveFairness::new(*learning_rate)),
+        FairnessMode::EqualSteps => Box::new(EqualStepsFairness::new()),
+    }
+}
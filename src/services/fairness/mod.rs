@@ -4,8 +4,12 @@ pub mod comparison;
 pub mod weighted;
 pub mod walltime;
 pub mod adaptive;
+pub mod equal_steps;
+pub mod factory;
 
 pub use comparison::ComparisonFairness;
 pub use weighted::{WeightedFairness, PerformanceWeightedFairness};
 pub use walltime::{WallTimeFairness, AdaptiveWallTimeFairness};
-pub use adaptive::AdaptiveFairness;
\ No newline at end of file
+pub use adaptive::AdaptiveFairness;
+pub use equal_steps::EqualStepsFairness;
+pub use factory::create_fairness_model;
\ No newline at end of file
@@ -61,8 +61,8 @@ impl Default for WallTimeFairness {
     }
 }
 
-impl FairnessModel for WallTimeFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for WallTimeFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         algorithms
             .iter()
             .map(|algorithm| {
@@ -134,8 +134,8 @@ impl Default for AdaptiveWallTimeFairness {
     }
 }
 
-impl FairnessModel for AdaptiveWallTimeFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for AdaptiveWallTimeFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         let base_budgets = self.base_fairness.allocate_budget(algorithms);
         
         base_budgets
@@ -223,9 +223,9 @@ mod tests {
     #[test]
     fn test_walltime_fairness_name() {
         let fairness = WallTimeFairness::default();
-        assert_eq!(fairness.name(), "Wall Time Fairness");
+        assert_eq!(FairnessModel::<i32>::name(&fairness), "Wall Time Fairness");
         
         let adaptive_fairness = AdaptiveWallTimeFairness::default();
-        assert_eq!(adaptive_fairness.name(), "Adaptive Wall Time");
+        assert_eq!(FairnessModel::<i32>::name(&adaptive_fairness), "Adaptive Wall Time");
     }
 }
\ No newline at end of file
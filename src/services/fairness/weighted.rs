@@ -3,8 +3,12 @@
 use crate::models::traits::{FairnessModel, Sorter};
 use std::collections::HashMap;
 
-/// Fairness model that allocates budgets based on α*comparisons + β*moves scoring
+/// Fairness model that allocates budgets based on α*work + β*moves scoring
 /// Algorithms with lower weighted scores get more budget (inverse fairness)
+///
+/// Work is measured in each algorithm's budget unit: comparisons for
+/// comparison sorts, every element operation for comparison-free sorts, so
+/// a radix sort cannot score zero and starve everyone else.
 #[derive(Debug)]
 pub struct WeightedFairness {
     alpha: f32,  // Weight for comparisons
@@ -16,7 +20,7 @@ impl WeightedFairness {
     /// Create a new weighted fairness model
     /// 
     /// # Arguments
-    /// * `alpha` - Weight for comparisons in the scoring formula
+    /// * `alpha` - Weight for budgeted work (usually comparisons) in the scoring formula
     /// * `beta` - Weight for moves in the scoring formula
     pub fn new(alpha: f32, beta: f32) -> Self {
         Self {
@@ -26,10 +30,11 @@ impl WeightedFairness {
         }
     }
 
-    /// Calculate weighted score for an algorithm: α*comparisons + β*moves
-    fn calculate_weighted_score(&self, algorithm: &dyn Sorter) -> f32 {
+    /// Calculate weighted score for an algorithm: α*work + β*moves
+    fn calculate_weighted_score<T: 'static>(&self, algorithm: &dyn Sorter<T>) -> f32 {
         let telemetry = algorithm.get_telemetry();
-        self.alpha * telemetry.total_comparisons as f32 + self.beta * telemetry.total_moves as f32
+        let work = algorithm.budget_unit().spent(&telemetry);
+        self.alpha * work as f32 + self.beta * telemetry.total_moves as f32
     }
 
     /// Set the base budget for allocation
@@ -49,8 +54,8 @@ impl Default for WeightedFairness {
     }
 }
 
-impl FairnessModel for WeightedFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for WeightedFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         // Filter active algorithms
         let active_algorithms: Vec<(usize, f32)> = algorithms
             .iter()
@@ -178,8 +183,8 @@ impl Default for PerformanceWeightedFairness {
     }
 }
 
-impl FairnessModel for PerformanceWeightedFairness {
-    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter>]) -> Vec<usize> {
+impl<T: 'static> FairnessModel<T> for PerformanceWeightedFairness {
+    fn allocate_budget(&self, algorithms: &[Box<dyn Sorter<T>>]) -> Vec<usize> {
         // Calculate total efficiency to normalize
         let total_efficiency: f32 = algorithms
             .iter()
@@ -218,7 +223,7 @@ impl FairnessModel for PerformanceWeightedFairness {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::services::sorters::{bubble::BubbleSort, quick::QuickSort};
+    use crate::services::sorters::{bubble::BubbleSort, quick::QuickSort, radix::LsdRadixSort};
 
     #[test]
     fn test_weighted_fairness_different_algorithms() {
@@ -287,4 +292,22 @@ mod tests {
         let total: usize = budgets.iter().sum();
         assert!(total > 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_weighted_fairness_charges_comparison_free_work() {
+        // Only comparisons weigh in, yet the radix sort's moves still count
+        let fairness = WeightedFairness::new(1.0, 0.0);
+
+        let mut radix = LsdRadixSort::new();
+        let mut quick = QuickSort::new();
+        radix.reset((0..100).rev().collect());
+        quick.reset((0..100).rev().collect());
+        radix.step(200);
+        quick.step(10);
+
+        let algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(radix), Box::new(quick)];
+        let budgets = fairness.allocate_budget(&algorithms);
+
+        assert!(budgets[0] < budgets[1], "radix sort got {:?}", budgets);
+    }
+}
@@ -1,8 +1,11 @@
 //! Deterministic array generation for sorting race
 
-use crate::models::config::Distribution;
+use crate::models::{config::Distribution, element::Comparator, lane::LaneSpec};
+use crate::services::registry;
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
 
 /// Deterministic array generator using seeded RNG
 #[derive(Debug)]
@@ -17,6 +20,10 @@ impl ArrayGenerator {
     }
 
     /// Generate an array with the specified distribution
+    ///
+    /// # Panics
+    /// If an [`Distribution::Antiqsort`] target is not a built-in comparison
+    /// sort; [`Distribution::antiqsort`] checks this.
     pub fn generate(&self, size: usize, distribution: &Distribution) -> Vec<i32> {
         if size == 0 {
             return Vec::new();
@@ -24,11 +31,19 @@ impl ArrayGenerator {
 
         match distribution {
             Distribution::Shuffled => self.generate_shuffled(size),
-            Distribution::NearlySorted => self.generate_nearly_sorted(size),
+            Distribution::NearlySorted { swap_percent } => self.generate_nearly_sorted(size, *swap_percent),
             Distribution::Reversed => self.generate_reversed(size),
             Distribution::FewUnique => self.generate_few_unique(size),
             Distribution::Sorted => self.generate_sorted(size),
             Distribution::WithDuplicates => self.generate_with_duplicates(size),
+            Distribution::MedianOfThreeKiller => self.generate_median_of_three_killer(size),
+            Distribution::OrganPipe => self.generate_organ_pipe(size),
+            Distribution::Sawtooth => self.generate_sawtooth(size),
+            Distribution::Antiqsort(target) => self.generate_antiqsort(size, target),
+            Distribution::Gaussian { stddev } => self.generate_gaussian(size, *stddev),
+            Distribution::Zipf { exponent } => self.generate_zipf(size, *exponent),
+            Distribution::Uniform { min, max } => self.generate_uniform(size, *min, *max),
+            Distribution::Runs { count } => self.generate_runs(size, *count),
         }
     }
 
@@ -46,13 +61,20 @@ impl ArrayGenerator {
         array
     }
 
-    /// Generate a nearly sorted array (90% sorted, 10% out of place)
-    fn generate_nearly_sorted(&self, size: usize) -> Vec<i32> {
+    /// Generate a sorted array with `swap_percent` percent of its size in
+    /// random swaps
+    fn generate_nearly_sorted(&self, size: usize, swap_percent: f64) -> Vec<i32> {
+        // Small arrays still get one swap unless none were asked for
+        let swaps = (size as f64 * swap_percent / 100.0).round() as usize;
+        let swaps = if swap_percent > 0.0 { swaps.max(1) } else { 0 };
+        self.generate_swapped(size, swaps)
+    }
+
+    /// Generate a sorted array with `swaps` random pairs swapped
+    fn generate_swapped(&self, size: usize, swaps: usize) -> Vec<i32> {
         let mut rng = StdRng::seed_from_u64(self.seed);
         let mut array: Vec<i32> = (1..=size as i32).collect();
-        
-        // Randomly swap ~10% of elements
-        let swaps = (size / 10).max(1);
+
         for _ in 0..swaps {
             let i = rng.random_range(0..size);
             let j = rng.random_range(0..size);
@@ -69,16 +91,64 @@ impl ArrayGenerator {
 
     /// Generate an array with few unique values
     fn generate_few_unique(&self, size: usize) -> Vec<i32> {
-        let mut rng = StdRng::seed_from_u64(self.seed);
         let unique_count = (size / 10).max(3).min(size); // ~10% unique values
-        let values: Vec<i32> = (1..=unique_count as i32).collect();
-        
-        let mut array = Vec::with_capacity(size);
-        for _ in 0..size {
-            let idx = rng.random_range(0..values.len());
-            array.push(values[idx]);
+        self.generate_uniform(size, 1, unique_count as i32)
+    }
+
+    /// Generate uniformly random values in `min..=max`
+    fn generate_uniform(&self, size: usize, min: i32, max: i32) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let span = (max as i64 - min as i64 + 1) as usize;
+        (0..size)
+            .map(|_| (min as i64 + rng.random_range(0..span) as i64) as i32)
+            .collect()
+    }
+
+    /// Generate normally distributed values around `size / 2`, with a
+    /// standard deviation of `stddev * size`, clamped to `1..=size`
+    fn generate_gaussian(&self, size: usize, stddev: f64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let center = size as f64 / 2.0;
+        let sigma = stddev * size as f64;
+
+        (0..size)
+            .map(|_| {
+                // Box-Muller transform; 1 - u keeps the logarithm finite
+                let u: f64 = 1.0 - rng.random::<f64>();
+                let v: f64 = rng.random();
+                let z = (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos();
+                (center + z * sigma).round().clamp(1.0, size as f64) as i32
+            })
+            .collect()
+    }
+
+    /// Generate values in `1..=size`, drawing `k` with weight `1 / k^exponent`
+    fn generate_zipf(&self, size: usize, exponent: f64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let cumulative: Vec<f64> = (1..=size)
+            .scan(0.0, |total, k| {
+                *total += (k as f64).powf(-exponent);
+                Some(*total)
+            })
+            .collect();
+        let total = cumulative[size - 1];
+
+        (0..size)
+            .map(|_| {
+                let target = rng.random::<f64>() * total;
+                let rank = cumulative.partition_point(|&weight| weight <= target).min(size - 1);
+                rank as i32 + 1
+            })
+            .collect()
+    }
+
+    /// Generate `count` sorted runs of about equal length, from a shuffled array
+    fn generate_runs(&self, size: usize, count: usize) -> Vec<i32> {
+        let mut array = self.generate_shuffled(size);
+        let count = count.clamp(1, size);
+        for run in 0..count {
+            array[run * size / count..(run + 1) * size / count].sort_unstable();
         }
-        
         array
     }
 
@@ -113,6 +183,77 @@ impl ArrayGenerator {
         array
     }
 
+    /// Generate Musser's median-of-three killer sequence
+    ///
+    /// For `n = 2k` the first half pairs each odd number `i` with `k + i`,
+    /// and the second half holds the even numbers in order, so the median of
+    /// the first, middle and last elements is always one of the two smallest.
+    /// Sizes that are not a multiple of four end with their extra values in
+    /// order.
+    fn generate_median_of_three_killer(&self, size: usize) -> Vec<i32> {
+        let pairs = size / 4 * 2;
+        let half = pairs as i32;
+        let mut array = Vec::with_capacity(size);
+        for i in (1..=half).step_by(2) {
+            array.push(i);
+            array.push(half + i);
+        }
+        array.extend((1..=half).map(|i| 2 * i));
+        array.extend(2 * half + 1..=size as i32);
+        array
+    }
+
+    /// Generate an organ pipe: ascending to the middle, then descending
+    fn generate_organ_pipe(&self, size: usize) -> Vec<i32> {
+        (0..size).map(|i| i.min(size - 1 - i) as i32 + 1).collect()
+    }
+
+    /// Generate ascending runs of about `sqrt(size)` values each
+    ///
+    /// The k-th element of every run is smaller than the (k+1)-th element of
+    /// any run, so no run can simply be appended to another.
+    fn generate_sawtooth(&self, size: usize) -> Vec<i32> {
+        let tooth = size.isqrt().max(2);
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by_key(|&i| (i % tooth, i / tooth));
+
+        let mut array = vec![0; size];
+        for (rank, &index) in order.iter().enumerate() {
+            array[index] = rank as i32 + 1;
+        }
+        array
+    }
+
+    /// Generate a worst case for `target` with McIlroy's antiquicksort
+    /// adversary
+    ///
+    /// The target sorts the indices `0..size` while the adversary decides
+    /// their values as it goes. Every element starts as "gas", larger than
+    /// any decided value. When two gas elements are compared, one of them is
+    /// frozen to the next smallest value, preferring the element that was
+    /// last compared against a frozen one, since that is most likely the
+    /// pivot. The decided values are consistent with every answer given, so
+    /// the target makes the same comparisons when it sorts the result.
+    fn generate_antiqsort(&self, size: usize, target: &LaneSpec) -> Vec<i32> {
+        let adversary = Arc::new(Mutex::new(Antiqsort::new(size)));
+        let compare = {
+            let adversary = Arc::clone(&adversary);
+            Comparator::new(move |a: &usize, b: &usize| {
+                adversary.lock().expect("adversary is never poisoned").compare(*a, *b)
+            })
+        };
+
+        let mut sorter = registry::create_builtin_variant(target.algorithm.key(), &target.options, compare)
+            .expect("antiqsort targets are checked when the distribution is created");
+        sorter.reset((0..size).collect());
+        while !sorter.is_complete() {
+            sorter.step(size.max(16));
+        }
+
+        let mut adversary = adversary.lock().expect("adversary is never poisoned");
+        adversary.finish()
+    }
+
     /// Validate that the generated array is correct
     pub fn validate_array(array: &[i32], size: usize, distribution: &Distribution) -> bool {
         if array.len() != size {
@@ -140,9 +281,65 @@ impl ArrayGenerator {
     }
 }
 
+/// State of McIlroy's antiquicksort adversary
+#[derive(Debug)]
+struct Antiqsort {
+    /// Value decided for each index, or `gas` while undecided
+    values: Vec<usize>,
+    /// Next value to freeze an element to
+    solid: usize,
+    /// Gas element most recently compared against a frozen one
+    candidate: usize,
+    gas: usize,
+}
+
+impl Antiqsort {
+    fn new(size: usize) -> Self {
+        Self {
+            values: vec![size; size],
+            solid: 0,
+            candidate: 0,
+            gas: size,
+        }
+    }
+
+    fn freeze(&mut self, index: usize) {
+        self.values[index] = self.solid;
+        self.solid += 1;
+    }
+
+    fn compare(&mut self, a: usize, b: usize) -> Ordering {
+        if self.values[a] == self.gas && self.values[b] == self.gas {
+            if a == self.candidate {
+                self.freeze(a);
+            } else {
+                self.freeze(b);
+            }
+        }
+        if self.values[a] == self.gas {
+            self.candidate = a;
+        } else if self.values[b] == self.gas {
+            self.candidate = b;
+        }
+        self.values[a].cmp(&self.values[b])
+    }
+
+    /// Freeze what is still gas, in index order, and return the values
+    /// 1-based
+    fn finish(&mut self) -> Vec<i32> {
+        for index in 0..self.values.len() {
+            if self.values[index] == self.gas {
+                self.freeze(index);
+            }
+        }
+        self.values.iter().map(|&value| value as i32 + 1).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::config::DistributionParams;
 
     #[test]
     fn test_deterministic_generation() {
@@ -190,6 +387,87 @@ mod tests {
         assert!(array.is_empty());
     }
 
+    #[test]
+    fn test_adversarial_shapes() {
+        let generator = ArrayGenerator::new(42);
+
+        assert_eq!(generator.generate(8, &Distribution::MedianOfThreeKiller), vec![1, 5, 3, 7, 2, 4, 6, 8]);
+        assert_eq!(generator.generate(7, &Distribution::OrganPipe), vec![1, 2, 3, 4, 3, 2, 1]);
+        assert_eq!(generator.generate(9, &Distribution::Sawtooth), vec![1, 4, 7, 2, 5, 8, 3, 6, 9]);
+
+        for size in [1, 2, 5, 10, 31, 100] {
+            for distribution in [Distribution::MedianOfThreeKiller, Distribution::Sawtooth] {
+                let mut array = generator.generate(size, &distribution);
+                array.sort();
+                assert_eq!(array, (1..=size as i32).collect::<Vec<_>>(), "{:?} size {}", distribution, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_antiqsort_is_a_permutation() {
+        let target = LaneSpec::parse("quick").unwrap();
+        let distribution = Distribution::antiqsort(target).unwrap();
+        let mut array = ArrayGenerator::new(42).generate(50, &distribution);
+        array.sort();
+        assert_eq!(array, (1..=50).collect::<Vec<_>>());
+
+        assert!(Distribution::antiqsort(LaneSpec::parse("counting").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parameterized_distributions() {
+        let generator = ArrayGenerator::new(42);
+
+        let gaussian = generator.generate(1000, &Distribution::Gaussian { stddev: 0.05 });
+        assert!(gaussian.iter().all(|&v| (1..=1000).contains(&v)));
+        let near_center = gaussian.iter().filter(|&&v| (400..=600).contains(&v)).count();
+        assert!(near_center > 900, "only {} values within two deviations", near_center);
+
+        let uniform = generator.generate(500, &Distribution::Uniform { min: -5, max: 5 });
+        assert!(uniform.iter().all(|&v| (-5..=5).contains(&v)));
+
+        let runs = generator.generate(100, &Distribution::Runs { count: 4 });
+        let descents = runs.windows(2).filter(|pair| pair[0] > pair[1]).count();
+        assert!(descents <= 3, "{} descents in 4 runs", descents);
+
+        let unswapped = generator.generate(100, &Distribution::NearlySorted { swap_percent: 0.0 });
+        assert_eq!(unswapped, (1..=100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_zipf_exponent_controls_cardinality() {
+        let generator = ArrayGenerator::new(42);
+        let distinct = |exponent: f64| {
+            let mut array = generator.generate(1000, &Distribution::Zipf { exponent });
+            array.sort();
+            array.dedup();
+            array.len()
+        };
+
+        assert!(distinct(0.0) > distinct(1.0));
+        assert!(distinct(1.0) > distinct(2.0));
+    }
+
+    #[test]
+    fn test_presets_match_parameterized_forms() {
+        let generator = ArrayGenerator::new(42);
+
+        // Nearly sorted defaults to 10% swaps, at every size, and few unique
+        // is a small uniform range
+        let nearly_sorted = DistributionParams::default().nearly_sorted();
+        for size in [4, 15, 100] {
+            assert_eq!(
+                generator.generate(size, &nearly_sorted),
+                generator.generate(size, &Distribution::NearlySorted { swap_percent: 10.0 })
+            );
+        }
+        assert_eq!(
+            generator.generate(100, &Distribution::FewUnique),
+            generator.generate(100, &Distribution::Uniform { min: 1, max: 10 })
+        );
+    }
+
     #[test]
     fn test_array_validation() {
         let generator = ArrayGenerator::new(42);
@@ -0,0 +1,188 @@
+//! Construction of racing lanes from lane specs
+
+use crate::models::{
+    element::{Comparator, Element},
+    lane::LaneSpec,
+    session::AlgorithmType,
+    traits::{BudgetUnit, Granularity, Sorter, StepResult, Telemetry},
+};
+use crate::services::registry;
+use std::any::Any;
+
+/// Create a fresh sorter for an algorithm from the registry
+pub fn create_sorter(algorithm: AlgorithmType) -> Box<dyn Sorter> {
+    registry::create_sorter(algorithm.key()).expect("algorithm types always refer to registered algorithms")
+}
+
+/// Create one sorter per lane, in lane order
+///
+/// Lane options must be valid, as checked by [`LaneSpec::parse`].
+pub fn create_lanes(lanes: &[LaneSpec]) -> Vec<Box<dyn Sorter>> {
+    lanes
+        .iter()
+        .map(|lane| {
+            let sorter = registry::create_variant(lane.algorithm.key(), &lane.options)
+                .expect("lane options are validated when lanes are parsed");
+            if sorter.name() == lane.label {
+                sorter
+            } else {
+                Box::new(LabeledSorter::new(sorter, lane.label.clone())) as Box<dyn Sorter>
+            }
+        })
+        .collect()
+}
+
+/// Create one built-in sorter per lane for any element type
+///
+/// Every lane orders elements with `compare`. Sorters registered at runtime
+/// only race `i32`, so lanes using them are rejected.
+pub fn create_lanes_with<T: Element>(
+    lanes: &[LaneSpec],
+    compare: Comparator<T>,
+) -> Result<Vec<Box<dyn Sorter<T>>>, String> {
+    lanes
+        .iter()
+        .map(|lane| {
+            let sorter = registry::create_builtin_variant(lane.algorithm.key(), &lane.options, compare.clone())?;
+            Ok(if sorter.name() == lane.label {
+                sorter
+            } else {
+                Box::new(LabeledSorter::new(sorter, lane.label.clone())) as Box<dyn Sorter<T>>
+            })
+        })
+        .collect()
+}
+
+/// Sorter wrapper that reports a custom lane label as its name
+///
+/// Everything else, including downcasting, is forwarded to the wrapped sorter.
+#[derive(Debug)]
+pub struct LabeledSorter<T = i32> {
+    inner: Box<dyn Sorter<T>>,
+    label: String,
+}
+
+impl<T> LabeledSorter<T> {
+    /// Wrap a sorter under a new label
+    pub fn new(inner: Box<dyn Sorter<T>>, label: String) -> Self {
+        Self { inner, label }
+    }
+
+    /// Get the wrapped sorter
+    pub fn inner(&self) -> &dyn Sorter<T> {
+        self.inner.as_ref()
+    }
+}
+
+impl<T: Element> Sorter<T> for LabeledSorter<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.inner.step(budget)
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.inner.step_operations(budget)
+    }
+
+    fn budget_unit(&self) -> BudgetUnit {
+        self.inner.budget_unit()
+    }
+
+    fn granularity(&self) -> Granularity {
+        self.inner.granularity()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.inner.is_complete()
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        self.inner.get_telemetry()
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.inner.reset(data)
+    }
+
+    fn name(&self) -> &str {
+        &self.label
+    }
+
+    fn get_array(&self) -> &[T] {
+        self.inner.get_array()
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        self.inner.get_origins()
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        self.inner.get_memory_usage()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.inner.as_any_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::sorters::insertion::InsertionSort;
+
+    #[test]
+    fn test_create_lanes_follows_specs() {
+        let specs = LaneSpec::parse_list("quick,merge,quick").unwrap();
+        let sorters = create_lanes(&specs);
+        let names: Vec<&str> = sorters.iter().map(|sorter| sorter.name()).collect();
+
+        assert_eq!(names, vec!["Quick Sort", "Merge Sort", "Quick Sort #2"]);
+    }
+
+    #[test]
+    fn test_create_lanes_applies_options() {
+        let specs = LaneSpec::parse_list("quick,quick+ninther+3way,quick+hoare:Hoare").unwrap();
+        let sorters = create_lanes(&specs);
+        let names: Vec<&str> = sorters.iter().map(|sorter| sorter.name()).collect();
+        assert_eq!(names, vec!["Quick Sort", "Quick Sort (ninther, 3-way)", "Hoare"]);
+
+        let generic = create_lanes_with(&specs, Comparator::<u64>::natural()).unwrap();
+        assert_eq!(generic[1].name(), "Quick Sort (ninther, 3-way)");
+    }
+
+    #[test]
+    fn test_labeled_sorter_forwards_to_inner() {
+        let mut sorter = LabeledSorter::new(create_sorter(AlgorithmType::InsertionSort), "Lane A".to_string());
+        sorter.reset(vec![3, 1, 2]);
+        while !sorter.is_complete() {
+            sorter.step(10);
+        }
+
+        assert_eq!(sorter.name(), "Lane A");
+        assert_eq!(sorter.get_array(), &[1, 2, 3]);
+        assert!(sorter.as_any().downcast_ref::<InsertionSort>().is_some());
+    }
+
+    #[test]
+    fn test_labeled_lanes_keep_their_granularity() {
+        let specs = LaneSpec::parse_list("lsd-radix,lsd-radix,counting:Count,bubble:Bubbles").unwrap();
+        let mut sorters = create_lanes(&specs);
+        let names: Vec<&str> = sorters.iter().map(|sorter| sorter.name()).collect();
+        assert_eq!(names, vec!["LSD Radix Sort", "LSD Radix Sort #2", "Count", "Bubbles"]);
+        for sorter in &sorters {
+            assert_eq!(sorter.granularity(), Granularity::Operation, "{}", sorter.name());
+        }
+
+        // The first comparison swaps, but the swap waits for the next step
+        let bubbles = &mut sorters[3];
+        bubbles.reset(vec![2, 1]);
+        let result = bubbles.step_operations(1);
+        assert_eq!((result.comparisons_used, result.moves_made), (1, 0));
+        let result = bubbles.step_operations(1);
+        assert_eq!((result.comparisons_used, result.moves_made), (0, 1));
+        assert_eq!(bubbles.get_array(), &[1, 2]);
+    }
+}
@@ -3,5 +3,9 @@
 pub mod sorters;
 pub mod fairness;
 pub mod generator;
+pub mod source;
+pub mod lanes;
+pub mod registry;
 pub mod memory;
-pub mod snapshot;
\ No newline at end of file
+pub mod snapshot;
+pub mod verify;
\ No newline at end of file
@@ -0,0 +1,617 @@
+//! Registry of sorting algorithms available for racing
+//!
+//! Every algorithm the CLI, the interactive menu and the lane list know about
+//! comes from this registry. Downstream crates add their own sorters by
+//! registering a factory before starting the application:
+//!
+//! ```no_run
+//! use sorting_race::services::registry::{register_sorter, SorterInfo};
+//! use sorting_race::services::sorters::insertion::InsertionSort;
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     register_sorter(
+//!         SorterInfo::new("binary-insertion", "Binary Insertion Sort")
+//!             .with_complexity("O(n)", "O(n²)", "O(n²)")
+//!             .with_stability(true),
+//!         || Box::new(InsertionSort::new()),
+//!     )?;
+//!     sorting_race::lib::app::run()
+//! }
+//! ```
+
+use crate::models::{
+    element::{Comparator, Element},
+    session::AlgorithmType,
+    traits::Sorter,
+};
+use crate::services::sorters::{
+    bubble::BubbleSort, heap::{self, HeapSort}, insertion::InsertionSort, merge::{self, MergeSort},
+    quick::{self, QuickSort}, selection::SelectionSort, shell::{self, ShellSort}, tim::TimSort,
+    intro::IntroSort, pdq::PdqSort, radix::{LsdRadixSort, MsdRadixSort}, counting::CountingSort,
+    bucket::BucketSort, cocktail::CocktailSort, comb::CombSort, gnome::GnomeSort, odd_even::OddEvenSort,
+    cycle::CycleSort, network::{Network, NetworkSort},
+};
+use anyhow::{Result, anyhow};
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// Factory creating a fresh sorter instance for a lane
+pub type SorterFactory = Arc<dyn Fn() -> Box<dyn Sorter> + Send + Sync>;
+
+type BuiltinFactory = fn() -> Box<dyn Sorter>;
+
+/// Time complexity of an algorithm in big-O notation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Complexity {
+    pub best: &'static str,
+    pub average: &'static str,
+    pub worst: &'static str,
+}
+
+/// Descriptive metadata for a registered algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SorterInfo {
+    /// Short key used on the command line, e.g. `quick`
+    pub key: &'static str,
+    /// Display name, e.g. `Quick Sort`
+    pub name: &'static str,
+    /// Time complexity
+    pub complexity: Complexity,
+    /// Auxiliary memory in big-O notation
+    pub memory: &'static str,
+    /// Whether equal elements keep their relative order
+    pub stable: bool,
+    /// Whether the algorithm sorts without a full auxiliary buffer
+    pub in_place: bool,
+    /// Whether the algorithm orders elements by comparing them, so it can
+    /// race any element type
+    pub comparison_based: bool,
+    /// Lane options selecting a variant, e.g. `quick+median3`
+    pub options: &'static [&'static str],
+}
+
+impl SorterInfo {
+    /// Create metadata with unknown complexity
+    pub fn new(key: &'static str, name: &'static str) -> Self {
+        Self {
+            key,
+            name,
+            complexity: Complexity {
+                best: "?",
+                average: "?",
+                worst: "?",
+            },
+            memory: "?",
+            stable: false,
+            in_place: true,
+            comparison_based: true,
+            options: &[],
+        }
+    }
+
+    /// Set best, average and worst case time complexity
+    pub fn with_complexity(mut self, best: &'static str, average: &'static str, worst: &'static str) -> Self {
+        self.complexity = Complexity { best, average, worst };
+        self
+    }
+
+    /// Set auxiliary memory complexity
+    pub fn with_memory(mut self, memory: &'static str) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Set whether the algorithm is stable
+    pub fn with_stability(mut self, stable: bool) -> Self {
+        self.stable = stable;
+        self
+    }
+
+    /// Set whether the algorithm sorts in place
+    pub fn with_in_place(mut self, in_place: bool) -> Self {
+        self.in_place = in_place;
+        self
+    }
+
+    /// Set whether the algorithm orders elements by comparing them
+    pub fn with_comparison_based(mut self, comparison_based: bool) -> Self {
+        self.comparison_based = comparison_based;
+        self
+    }
+
+    /// Set the lane options understood by the built-in sorter
+    pub fn with_options(mut self, options: &'static [&'static str]) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+#[derive(Clone)]
+struct RegistryEntry {
+    info: SorterInfo,
+    factory: SorterFactory,
+}
+
+/// Ordered collection of sorter factories keyed by algorithm key
+#[derive(Clone, Default)]
+pub struct SorterRegistry {
+    entries: Vec<RegistryEntry>,
+}
+
+impl std::fmt::Debug for SorterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SorterRegistry")
+            .field("algorithms", &self.keys())
+            .finish()
+    }
+}
+
+impl SorterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry holding the built-in algorithms
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        let builtins: [(SorterInfo, BuiltinFactory); 21] = [
+            (
+                SorterInfo::new("bubble", "Bubble Sort")
+                    .with_complexity("O(n)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(true),
+                || Box::new(BubbleSort::new()),
+            ),
+            (
+                SorterInfo::new("selection", "Selection Sort")
+                    .with_complexity("O(n²)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)"),
+                || Box::new(SelectionSort::new()),
+            ),
+            (
+                SorterInfo::new("insertion", "Insertion Sort")
+                    .with_complexity("O(n)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(true),
+                || Box::new(InsertionSort::new()),
+            ),
+            (
+                SorterInfo::new("merge", "Merge Sort")
+                    .with_complexity("O(n log n)", "O(n log n)", "O(n log n)")
+                    .with_memory("O(n)")
+                    .with_stability(true)
+                    .with_in_place(false)
+                    .with_options(&merge::OPTIONS),
+                || Box::new(MergeSort::new()),
+            ),
+            (
+                SorterInfo::new("quick", "Quick Sort")
+                    .with_complexity("O(n log n)", "O(n log n)", "O(n²)")
+                    .with_memory("O(log n)")
+                    .with_options(&quick::OPTIONS),
+                || Box::new(QuickSort::new()),
+            ),
+            (
+                SorterInfo::new("heap", "Heap Sort")
+                    .with_complexity("O(n log n)", "O(n log n)", "O(n log n)")
+                    .with_memory("O(1)")
+                    .with_options(&heap::OPTIONS),
+                || Box::new(HeapSort::new()),
+            ),
+            (
+                SorterInfo::new("shell", "Shell Sort")
+                    .with_complexity("O(n log n)", "O(n^1.3)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_options(&shell::OPTIONS),
+                || Box::new(ShellSort::new()),
+            ),
+            (
+                SorterInfo::new("tim", "Tim Sort")
+                    .with_complexity("O(n)", "O(n log n)", "O(n log n)")
+                    .with_memory("O(n)")
+                    .with_stability(true)
+                    .with_in_place(false),
+                || Box::new(TimSort::new()),
+            ),
+            (
+                SorterInfo::new("intro", "Intro Sort")
+                    .with_complexity("O(n log n)", "O(n log n)", "O(n log n)")
+                    .with_memory("O(log n)"),
+                || Box::new(IntroSort::new()),
+            ),
+            (
+                SorterInfo::new("pdq", "Pdq Sort")
+                    .with_complexity("O(n)", "O(n log n)", "O(n log n)")
+                    .with_memory("O(log n)"),
+                || Box::new(PdqSort::new()),
+            ),
+            (
+                SorterInfo::new("lsd-radix", "LSD Radix Sort")
+                    .with_complexity("O(nk)", "O(nk)", "O(nk)")
+                    .with_memory("O(n + b)")
+                    .with_stability(true)
+                    .with_in_place(false)
+                    .with_comparison_based(false),
+                || Box::new(LsdRadixSort::new()),
+            ),
+            (
+                SorterInfo::new("msd-radix", "MSD Radix Sort")
+                    .with_complexity("O(n)", "O(nk)", "O(nk)")
+                    .with_memory("O(n + kb)")
+                    .with_stability(true)
+                    .with_in_place(false)
+                    .with_comparison_based(false),
+                || Box::new(MsdRadixSort::new()),
+            ),
+            (
+                SorterInfo::new("counting", "Counting Sort")
+                    .with_complexity("O(n + k)", "O(n + k)", "O(n + k)")
+                    .with_memory("O(n + k)")
+                    .with_stability(true)
+                    .with_in_place(false)
+                    .with_comparison_based(false),
+                || Box::new(CountingSort::new()),
+            ),
+            (
+                SorterInfo::new("bucket", "Bucket Sort")
+                    .with_complexity("O(n)", "O(n)", "O(n²)")
+                    .with_memory("O(n)")
+                    .with_stability(true)
+                    .with_in_place(false)
+                    .with_comparison_based(false),
+                || Box::new(BucketSort::new()),
+            ),
+            (
+                SorterInfo::new("cocktail", "Cocktail Shaker Sort")
+                    .with_complexity("O(n)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(true),
+                || Box::new(CocktailSort::new()),
+            ),
+            (
+                SorterInfo::new("comb", "Comb Sort")
+                    .with_complexity("O(n log n)", "O(n²/2ᵖ)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(false),
+                || Box::new(CombSort::new()),
+            ),
+            (
+                SorterInfo::new("gnome", "Gnome Sort")
+                    .with_complexity("O(n)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(true),
+                || Box::new(GnomeSort::new()),
+            ),
+            (
+                SorterInfo::new("odd-even", "Odd-Even Sort")
+                    .with_complexity("O(n)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(true),
+                || Box::new(OddEvenSort::new()),
+            ),
+            (
+                SorterInfo::new("cycle", "Cycle Sort")
+                    .with_complexity("O(n²)", "O(n²)", "O(n²)")
+                    .with_memory("O(1)")
+                    .with_stability(false),
+                || Box::new(CycleSort::new()),
+            ),
+            (
+                SorterInfo::new("bitonic", "Bitonic Sort")
+                    .with_complexity("O(n log² n)", "O(n log² n)", "O(n log² n)")
+                    .with_memory("O(n)")
+                    .with_stability(false),
+                || Box::new(NetworkSort::bitonic()),
+            ),
+            (
+                SorterInfo::new("odd-even-merge", "Odd-Even Merge Sort")
+                    .with_complexity("O(n log² n)", "O(n log² n)", "O(n log² n)")
+                    .with_memory("O(n)")
+                    .with_stability(false),
+                || Box::new(NetworkSort::odd_even_merge()),
+            ),
+        ];
+
+        for (info, factory) in builtins {
+            registry
+                .register(info, factory)
+                .expect("built-in algorithm keys are unique");
+        }
+        registry
+    }
+
+    /// Register a sorter factory, returning the new algorithm's type
+    ///
+    /// Keys must be unique and may only contain lowercase letters, digits,
+    /// `-` and `_`, so they can be used in `--algos` lists.
+    pub fn register<F>(&mut self, info: SorterInfo, factory: F) -> Result<AlgorithmType>
+    where
+        F: Fn() -> Box<dyn Sorter> + Send + Sync + 'static,
+    {
+        let valid_key = !info.key.is_empty()
+            && info
+                .key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_key {
+            return Err(anyhow!(
+                "Invalid algorithm key '{}', use lowercase letters, digits, '-' or '_'",
+                info.key
+            ));
+        }
+        if info.name.is_empty() {
+            return Err(anyhow!("Algorithm '{}' needs a display name", info.key));
+        }
+        if self.get(info.key).is_some() {
+            return Err(anyhow!("Algorithm '{}' is already registered", info.key));
+        }
+
+        self.entries.push(RegistryEntry {
+            info,
+            factory: Arc::new(factory),
+        });
+        Ok(AlgorithmType::new(info.key))
+    }
+
+    /// Get metadata for an algorithm key
+    pub fn get(&self, key: &str) -> Option<&SorterInfo> {
+        self.entries.iter().map(|entry| &entry.info).find(|info| info.key == key)
+    }
+
+    /// Get the registration position of an algorithm key
+    pub fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.info.key == key)
+    }
+
+    /// Create a sorter for an algorithm key
+    pub fn create(&self, key: &str) -> Option<Box<dyn Sorter>> {
+        self.entries
+            .iter()
+            .find(|entry| entry.info.key == key)
+            .map(|entry| (entry.factory)())
+    }
+
+    /// Create a sorter for an algorithm key configured with lane options
+    ///
+    /// Without options this is [`create`](Self::create); options are only
+    /// understood by built-in sorters.
+    pub fn create_variant(&self, key: &str, options: &[String]) -> Result<Box<dyn Sorter>, String> {
+        if self.get(key).is_none() {
+            return Err(format!("Unknown algorithm '{}'", key));
+        }
+        if options.is_empty() {
+            return self.create(key).ok_or_else(|| format!("Unknown algorithm '{}'", key));
+        }
+        create_builtin_variant(key, options, Comparator::natural())
+    }
+
+    /// Get metadata for every algorithm in registration order
+    pub fn infos(&self) -> Vec<SorterInfo> {
+        self.entries.iter().map(|entry| entry.info).collect()
+    }
+
+    /// Get every algorithm key in registration order
+    pub fn keys(&self) -> Vec<&'static str> {
+        self.entries.iter().map(|entry| entry.info.key).collect()
+    }
+
+    /// Get the number of registered algorithms
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no algorithms are registered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Create a built-in sorter for any element type
+///
+/// Returns `None` for keys that are not built in, since registered
+/// factories only produce `i32` sorters, and for the comparison-free sorters,
+/// which order elements by a [`RadixKey`](crate::models::element::RadixKey)
+/// rather than a comparator.
+pub fn create_builtin_sorter<T: Element>(key: &str, compare: Comparator<T>) -> Option<Box<dyn Sorter<T>>> {
+    let sorter: Box<dyn Sorter<T>> = match key {
+        "bubble" => Box::new(BubbleSort::with_comparator(compare)),
+        "selection" => Box::new(SelectionSort::with_comparator(compare)),
+        "insertion" => Box::new(InsertionSort::with_comparator(compare)),
+        "merge" => Box::new(MergeSort::with_comparator(compare)),
+        "quick" => Box::new(QuickSort::with_comparator(compare)),
+        "heap" => Box::new(HeapSort::with_comparator(compare)),
+        "shell" => Box::new(ShellSort::with_comparator(compare)),
+        "tim" => Box::new(TimSort::with_comparator(compare)),
+        "intro" => Box::new(IntroSort::with_comparator(compare)),
+        "pdq" => Box::new(PdqSort::with_comparator(compare)),
+        "cocktail" => Box::new(CocktailSort::with_comparator(compare)),
+        "comb" => Box::new(CombSort::with_comparator(compare)),
+        "gnome" => Box::new(GnomeSort::with_comparator(compare)),
+        "odd-even" => Box::new(OddEvenSort::with_comparator(compare)),
+        "cycle" => Box::new(CycleSort::with_comparator(compare)),
+        "bitonic" => Box::new(NetworkSort::with_comparator(compare)),
+        "odd-even-merge" => Box::new(NetworkSort::with_comparator(compare).with_network(Network::OddEvenMerge)),
+        _ => return None,
+    };
+    Some(sorter)
+}
+
+/// Create a built-in sorter for any element type configured with lane options
+///
+/// Fails for unknown options, listing the ones the algorithm understands,
+/// and for algorithms [`create_builtin_sorter`] cannot create.
+pub fn create_builtin_variant<T: Element>(
+    key: &str,
+    options: &[String],
+    compare: Comparator<T>,
+) -> Result<Box<dyn Sorter<T>>, String> {
+    match key {
+        "merge" => configure(MergeSort::with_comparator(compare), key, options, &merge::OPTIONS, MergeSort::with_option)
+            .map(|sorter| Box::new(sorter) as Box<dyn Sorter<T>>),
+        "quick" => configure(QuickSort::with_comparator(compare), key, options, &quick::OPTIONS, QuickSort::with_option)
+            .map(|sorter| Box::new(sorter) as Box<dyn Sorter<T>>),
+        "heap" => configure(HeapSort::with_comparator(compare), key, options, &heap::OPTIONS, HeapSort::with_option)
+            .map(|sorter| Box::new(sorter) as Box<dyn Sorter<T>>),
+        "shell" => configure(ShellSort::with_comparator(compare), key, options, &shell::OPTIONS, ShellSort::with_option)
+            .map(|sorter| Box::new(sorter) as Box<dyn Sorter<T>>),
+        _ => {
+            if let Some(option) = options.first() {
+                return Err(format!("Algorithm '{}' takes no options, got '{}'", key, option));
+            }
+            create_builtin_sorter(key, compare).ok_or_else(|| format!("Algorithm '{}' can only race i32 elements", key))
+        }
+    }
+}
+
+/// Apply lane options to a sorter in order, later options overriding earlier ones
+fn configure<S>(
+    mut sorter: S,
+    key: &str,
+    options: &[String],
+    valid: &[&str],
+    apply: fn(S, &str) -> Option<S>,
+) -> Result<S, String> {
+    for option in options {
+        if !valid.contains(&option.as_str()) {
+            return Err(format!(
+                "Unknown option '{}' for '{}', expected one of: {}",
+                option,
+                key,
+                valid.join(", ")
+            ));
+        }
+        sorter = apply(sorter, option).expect("listed options are always understood");
+    }
+    Ok(sorter)
+}
+
+static GLOBAL_REGISTRY: LazyLock<RwLock<SorterRegistry>> =
+    LazyLock::new(|| RwLock::new(SorterRegistry::with_builtins()));
+
+/// Run a closure against the global registry
+pub fn with_registry<T>(f: impl FnOnce(&SorterRegistry) -> T) -> T {
+    let registry = GLOBAL_REGISTRY.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&registry)
+}
+
+/// Register a sorter in the global registry used by the application
+pub fn register_sorter<F>(info: SorterInfo, factory: F) -> Result<AlgorithmType>
+where
+    F: Fn() -> Box<dyn Sorter> + Send + Sync + 'static,
+{
+    let mut registry = GLOBAL_REGISTRY.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    registry.register(info, factory)
+}
+
+/// Get metadata for every globally registered algorithm
+pub fn registered_sorters() -> Vec<SorterInfo> {
+    with_registry(|registry| registry.infos())
+}
+
+/// Get metadata for a globally registered algorithm
+pub fn sorter_info(key: &str) -> Option<SorterInfo> {
+    with_registry(|registry| registry.get(key).copied())
+}
+
+/// Create a sorter from the global registry
+pub fn create_sorter(key: &str) -> Option<Box<dyn Sorter>> {
+    with_registry(|registry| registry.create(key))
+}
+
+/// Create a sorter variant from the global registry
+pub fn create_variant(key: &str, options: &[String]) -> Result<Box<dyn Sorter>, String> {
+    with_registry(|registry| registry.create_variant(key, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins_have_metadata() {
+        let registry = SorterRegistry::with_builtins();
+        assert_eq!(registry.len(), 21);
+
+        let merge = registry.get("merge").unwrap();
+        assert_eq!(merge.name, "Merge Sort");
+        assert!(merge.stable);
+        assert!(!merge.in_place);
+        assert_eq!(merge.complexity.worst, "O(n log n)");
+
+        let quick = registry.get("quick").unwrap();
+        assert!(!quick.stable);
+        assert!(quick.in_place);
+    }
+
+    #[test]
+    fn test_factories_match_names() {
+        let registry = SorterRegistry::with_builtins();
+        for info in registry.infos() {
+            let sorter = registry.create(info.key).unwrap();
+            assert_eq!(sorter.name(), info.name);
+        }
+    }
+
+    #[test]
+    fn test_every_builtin_supports_other_element_types() {
+        let registry = SorterRegistry::with_builtins();
+        for info in registry.infos() {
+            if !info.comparison_based {
+                assert!(create_builtin_sorter(info.key, Comparator::<String>::natural()).is_none());
+                continue;
+            }
+            let mut sorter = create_builtin_sorter(info.key, Comparator::<String>::natural()).unwrap();
+            sorter.reset(vec!["pear".to_string(), "apple".to_string(), "fig".to_string()]);
+            while !sorter.is_complete() {
+                sorter.step(4);
+            }
+            assert_eq!(sorter.get_array(), &["apple", "fig", "pear"], "{}", info.name);
+        }
+        assert!(create_builtin_sorter("my-sort", Comparator::<String>::natural()).is_none());
+    }
+
+    #[test]
+    fn test_variants_apply_options_in_order() {
+        let registry = SorterRegistry::with_builtins();
+        let options = |list: &[&str]| list.iter().map(|option| option.to_string()).collect::<Vec<_>>();
+
+        let sorter = registry.create_variant("quick", &options(&["first", "median3", "hoare"])).unwrap();
+        assert_eq!(sorter.name(), "Quick Sort (median-of-3, Hoare)");
+        assert_eq!(registry.create_variant("quick", &[]).unwrap().name(), "Quick Sort");
+
+        let error = registry.create_variant("quick", &options(&["middle"])).unwrap_err();
+        assert!(error.contains("middle") && error.contains("ninther"));
+        assert!(registry.create_variant("tim", &options(&["hoare"])).unwrap_err().contains("takes no options"));
+        assert_eq!(registry.create_variant("shell", &options(&["ciura"])).unwrap().name(), "Shell Sort (Ciura)");
+        assert_eq!(registry.create_variant("merge", &options(&["in-place"])).unwrap().name(), "Merge Sort (in-place)");
+        assert_eq!(
+            registry.create_variant("heap", &options(&["4ary", "bottom-up"])).unwrap().name(),
+            "Heap Sort (4-ary, bottom-up)"
+        );
+        assert!(registry.create_variant("bogo", &[]).is_err());
+    }
+
+    #[test]
+    fn test_register_custom_sorter() {
+        let mut registry = SorterRegistry::with_builtins();
+        let algorithm = registry
+            .register(SorterInfo::new("my-sort", "My Sort"), || Box::new(InsertionSort::new()))
+            .unwrap();
+
+        assert_eq!(algorithm.key(), "my-sort");
+        assert_eq!(registry.position("my-sort"), Some(21));
+        assert!(registry.create("my-sort").is_some());
+    }
+
+    #[test]
+    fn test_register_rejects_duplicate_and_invalid_keys() {
+        let mut registry = SorterRegistry::with_builtins();
+        let factory = || Box::new(InsertionSort::new()) as Box<dyn Sorter>;
+
+        assert!(registry.register(SorterInfo::new("quick", "Another Quick"), factory).is_err());
+        assert!(registry.register(SorterInfo::new("My Sort", "My Sort"), factory).is_err());
+        assert!(registry.register(SorterInfo::new("a:b", "Colon"), factory).is_err());
+        assert!(registry.register(SorterInfo::new("", "Empty"), factory).is_err());
+        assert_eq!(registry.len(), 21);
+    }
+}
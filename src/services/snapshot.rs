@@ -1,11 +1,19 @@
 //! Snapshot functionality for algorithm states
 
+use crate::models::element::Element;
 use crate::models::traits::{Sorter, Telemetry};
 use crate::models::metrics::MetricsSnapshot;
+use crate::services::verify::{LaneVerification, Stability};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
 use std::time::SystemTime;
 
 /// A snapshot of the complete algorithm race state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RaceSnapshot {
     /// Timestamp when snapshot was taken
     pub timestamp: SystemTime,
@@ -18,11 +26,11 @@ pub struct RaceSnapshot {
 }
 
 /// Snapshot of a single algorithm's state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AlgorithmSnapshot {
     /// Algorithm name
     pub name: String,
-    /// Current array state
+    /// Current array state, as bar heights for non-`i32` races
     pub array_state: Vec<i32>,
     /// Telemetry data
     pub telemetry: Telemetry,
@@ -30,56 +38,285 @@ pub struct AlgorithmSnapshot {
     pub is_complete: bool,
     /// Metrics snapshot
     pub metrics: MetricsSnapshot,
+    /// Memory reported by `get_memory_usage` when the snapshot was taken
+    #[serde(default)]
+    pub memory_bytes: usize,
+    /// Why the lane's output failed verification, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Stability verdict, present once the lane completes in a stability-checked race
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability: Option<Stability>,
+}
+
+impl AlgorithmSnapshot {
+    /// Capture the current state of a single algorithm
+    pub fn capture(algorithm: &dyn Sorter, step: usize) -> Self {
+        Self::capture_with_array(algorithm, algorithm.get_array().to_vec(), step)
+    }
+
+    /// Capture a single algorithm of any element type, with its array already projected to bar heights
+    pub fn capture_with_array<T: Element>(algorithm: &dyn Sorter<T>, array_state: Vec<i32>, step: usize) -> Self {
+        let telemetry = algorithm.get_telemetry();
+        let metrics = crate::models::metrics::Metrics {
+            comparisons: telemetry.total_comparisons,
+            moves: telemetry.total_moves,
+            writes: telemetry.total_writes,
+            parallel_depth: telemetry.parallel_depth,
+            execution_time_us: 0, // Stub value
+            peak_memory_bytes: telemetry.memory_peak,
+            current_memory_bytes: telemetry.memory_current,
+            steps: step,
+            array_accesses: telemetry.total_comparisons + telemetry.total_moves,
+            recursive_calls: 0, // Stub value
+        };
+
+        Self {
+            name: algorithm.name().to_string(),
+            array_state,
+            telemetry,
+            is_complete: algorithm.is_complete(),
+            metrics: MetricsSnapshot::new(
+                metrics,
+                SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap()
+                    .as_micros() as u64,
+                algorithm.name().to_string(),
+            ),
+            memory_bytes: algorithm.get_memory_usage(),
+            error: None,
+            stability: None,
+        }
+    }
+}
+
+/// Every this many frames, history stores full arrays instead of deltas,
+/// bounding the work needed to rebuild any frame
+const KEYFRAME_INTERVAL: usize = 64;
+
+/// Array contents of one lane as stored in history
+#[derive(Debug, Clone)]
+enum StoredArray {
+    /// Full copy of the array
+    Full(Vec<i32>),
+    /// Changed `(index, value)` pairs relative to the previous frame
+    Delta(Vec<(usize, i32)>),
+}
+
+impl StoredArray {
+    /// Encode `current` against the same lane in the previous frame
+    fn encode(previous: &[i32], current: &[i32]) -> Self {
+        if previous.len() != current.len() {
+            return StoredArray::Full(current.to_vec());
+        }
+
+        let changes: Vec<(usize, i32)> = previous
+            .iter()
+            .zip(current.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(index, (_, new))| (index, *new))
+            .collect();
+
+        // A change entry is four times the size of an element
+        if changes.len() * 4 >= current.len() {
+            StoredArray::Full(current.to_vec())
+        } else {
+            StoredArray::Delta(changes)
+        }
+    }
+
+    fn element_count(&self) -> usize {
+        match self {
+            StoredArray::Full(array) => array.len(),
+            StoredArray::Delta(changes) => changes.len(),
+        }
+    }
+}
+
+/// One lane of a stored history frame
+#[derive(Debug, Clone)]
+struct StoredLane {
+    name: String,
+    array: StoredArray,
+    telemetry: Telemetry,
+    is_complete: bool,
+    metrics: MetricsSnapshot,
+    memory_bytes: usize,
+    error: Option<String>,
+    stability: Option<Stability>,
+}
+
+/// A history frame with arrays stored as full copies or deltas
+#[derive(Debug, Clone)]
+struct StoredSnapshot {
+    timestamp: SystemTime,
+    lanes: Vec<StoredLane>,
+    step: usize,
+    race_complete: bool,
+}
+
+/// Destination for a JSON-lines race recording
+struct Recorder {
+    writer: Box<dyn Write + Send>,
+    frames_written: usize,
+}
+
+impl fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder")
+            .field("frames_written", &self.frames_written)
+            .finish_non_exhaustive()
+    }
 }
 
 /// Service for creating and managing snapshots
+///
+/// History keeps the latest snapshot in full and stores older arrays as
+/// deltas against the previous frame, with a full keyframe every
+/// `KEYFRAME_INTERVAL` frames. The oldest frame is always a keyframe.
 #[derive(Debug, Default)]
 pub struct SnapshotService {
-    snapshots: Vec<RaceSnapshot>,
+    history: VecDeque<StoredSnapshot>,
+    latest: Option<RaceSnapshot>,
+    frames_since_keyframe: usize,
     max_snapshots: usize,
+    recorder: Option<Recorder>,
+    recording_error: Option<String>,
 }
 
 impl SnapshotService {
     /// Create a new snapshot service
     pub fn new(max_snapshots: usize) -> Self {
         Self {
-            snapshots: Vec::new(),
+            history: VecDeque::new(),
+            latest: None,
+            frames_since_keyframe: 0,
             max_snapshots: max_snapshots.max(1),
+            recorder: None,
+            recording_error: None,
+        }
+    }
+
+    /// Start streaming every new snapshot to a JSON-lines file
+    pub fn start_recording<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.record_to(BufWriter::new(file));
+        Ok(())
+    }
+
+    /// Start streaming every new snapshot to an arbitrary writer, one JSON object per line
+    pub fn record_to<W: Write + Send + 'static>(&mut self, writer: W) {
+        self.recorder = Some(Recorder {
+            writer: Box::new(writer),
+            frames_written: 0,
+        });
+        self.recording_error = None;
+    }
+
+    /// Stop recording and flush the destination, returning the number of frames written
+    pub fn stop_recording(&mut self) -> io::Result<usize> {
+        if let Some(error) = self.recording_error.take() {
+            return Err(io::Error::other(error));
+        }
+
+        match self.recorder.take() {
+            Some(mut recorder) => {
+                recorder.writer.flush()?;
+                Ok(recorder.frames_written)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Check if snapshots are being recorded
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Error that stopped the last recording, if any
+    pub fn get_recording_error(&self) -> Option<&str> {
+        self.recording_error.as_deref()
+    }
+
+    /// Load a JSON-lines recording from disk
+    pub fn load_recording<P: AsRef<Path>>(path: P) -> io::Result<Vec<RaceSnapshot>> {
+        Self::read_recording(BufReader::new(File::open(path)?))
+    }
+
+    /// Read a JSON-lines recording, skipping blank lines
+    pub fn read_recording<R: BufRead>(reader: R) -> io::Result<Vec<RaceSnapshot>> {
+        let mut snapshots = Vec::new();
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let snapshot = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {}", line_number + 1, e),
+                )
+            })?;
+            snapshots.push(snapshot);
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Append a snapshot to the active recording, stopping it on failure
+    fn record_snapshot(&mut self, snapshot: &RaceSnapshot) {
+        let Some(recorder) = self.recorder.as_mut() else {
+            return;
+        };
+
+        let result = serde_json::to_writer(&mut recorder.writer, snapshot)
+            .map_err(io::Error::from)
+            .and_then(|_| recorder.writer.write_all(b"\n"));
+
+        match result {
+            Ok(()) => recorder.frames_written += 1,
+            Err(error) => {
+                self.recording_error = Some(error.to_string());
+                self.recorder = None;
+            }
         }
     }
 
     /// Take a snapshot of the current race state
     pub fn take_snapshot(&mut self, algorithms: &[Box<dyn Sorter>], step: usize) -> &RaceSnapshot {
+        self.take_snapshot_with_verification(algorithms, &[], step, <[i32]>::to_vec)
+    }
+
+    /// Take a snapshot, attaching per-lane verification results
+    ///
+    /// `verification` is indexed like `algorithms`; missing entries mean no verdict yet.
+    /// `bar_heights` turns each lane's array into the values stored in the snapshot.
+    pub fn take_snapshot_with_verification<T: Element>(
+        &mut self,
+        algorithms: &[Box<dyn Sorter<T>>],
+        verification: &[LaneVerification],
+        step: usize,
+        bar_heights: impl Fn(&[T]) -> Vec<i32>,
+    ) -> &RaceSnapshot {
         let race_complete = algorithms.iter().all(|alg| alg.is_complete());
         
         let algorithm_snapshots = algorithms
             .iter()
-            .map(|algorithm| {
-                let telemetry = algorithm.get_telemetry();
-                let metrics = crate::models::metrics::Metrics {
-                    comparisons: telemetry.total_comparisons,
-                    moves: telemetry.total_moves,
-                    execution_time_us: 0, // Stub value
-                    peak_memory_bytes: telemetry.memory_peak,
-                    current_memory_bytes: telemetry.memory_current,
-                    steps: step,
-                    array_accesses: telemetry.total_comparisons + telemetry.total_moves,
-                    recursive_calls: 0, // Stub value
-                };
-
+            .enumerate()
+            .map(|(index, algorithm)| {
+                let lane = verification.get(index).cloned().unwrap_or_default();
                 AlgorithmSnapshot {
-                    name: algorithm.name().to_string(),
-                    array_state: algorithm.get_array().to_vec(),
-                    telemetry,
-                    is_complete: algorithm.is_complete(),
-                    metrics: MetricsSnapshot::new(
-                        metrics,
-                        SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .unwrap()
-                            .as_micros() as u64,
-                        algorithm.name().to_string(),
-                    ),
+                    error: lane.error,
+                    stability: lane.stability,
+                    ..AlgorithmSnapshot::capture_with_array(
+                        algorithm.as_ref(),
+                        bar_heights(algorithm.get_array()),
+                        step,
+                    )
                 }
             })
             .collect();
@@ -91,53 +328,179 @@ impl SnapshotService {
             race_complete,
         };
 
+        self.record_snapshot(&snapshot);
+
         // Add snapshot and maintain max limit
-        self.snapshots.push(snapshot);
-        if self.snapshots.len() > self.max_snapshots {
-            self.snapshots.remove(0);
+        self.push_history(&snapshot);
+        self.latest = Some(snapshot);
+        self.trim_history();
+
+        self.latest.as_ref().unwrap()
+    }
+
+    /// Store a snapshot in history, as deltas against the latest one where possible
+    fn push_history(&mut self, snapshot: &RaceSnapshot) {
+        let previous = self.latest.as_ref().filter(|previous| {
+            self.frames_since_keyframe + 1 < KEYFRAME_INTERVAL
+                && previous.algorithm_snapshots.len() == snapshot.algorithm_snapshots.len()
+        });
+
+        let lanes = snapshot
+            .algorithm_snapshots
+            .iter()
+            .enumerate()
+            .map(|(index, lane)| {
+                let array = match previous.map(|p| &p.algorithm_snapshots[index]) {
+                    Some(previous_lane) if previous_lane.name == lane.name => {
+                        StoredArray::encode(&previous_lane.array_state, &lane.array_state)
+                    }
+                    _ => StoredArray::Full(lane.array_state.clone()),
+                };
+
+                StoredLane {
+                    name: lane.name.clone(),
+                    array,
+                    telemetry: lane.telemetry.clone(),
+                    is_complete: lane.is_complete,
+                    metrics: lane.metrics.clone(),
+                    memory_bytes: lane.memory_bytes,
+                    error: lane.error.clone(),
+                    stability: lane.stability,
+                }
+            })
+            .collect();
+
+        self.frames_since_keyframe = if previous.is_some() {
+            self.frames_since_keyframe + 1
+        } else {
+            0
+        };
+
+        self.history.push_back(StoredSnapshot {
+            timestamp: snapshot.timestamp,
+            lanes,
+            step: snapshot.step,
+            race_complete: snapshot.race_complete,
+        });
+    }
+
+    /// Drop the oldest frames beyond the limit, promoting the new oldest frame to a keyframe
+    fn trim_history(&mut self) {
+        while self.history.len() > self.max_snapshots {
+            let Some(evicted) = self.history.pop_front() else {
+                break;
+            };
+
+            if let Some(front) = self.history.front_mut() {
+                for (lane, evicted_lane) in front.lanes.iter_mut().zip(evicted.lanes) {
+                    if let (StoredArray::Delta(changes), StoredArray::Full(mut array)) =
+                        (&lane.array, evicted_lane.array)
+                    {
+                        for &(index, value) in changes {
+                            array[index] = value;
+                        }
+                        lane.array = StoredArray::Full(array);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Rebuild the full array of one lane at a history index
+    fn rebuild_array(&self, index: usize, lane: usize) -> Vec<i32> {
+        let mut keyframe = index;
+        while let Some(StoredArray::Delta(_)) = self.history[keyframe].lanes.get(lane).map(|l| &l.array) {
+            keyframe -= 1;
+        }
+
+        let mut array = match &self.history[keyframe].lanes[lane].array {
+            StoredArray::Full(array) => array.clone(),
+            StoredArray::Delta(_) => unreachable!("history always starts with a keyframe"),
+        };
+        for frame in self.history.range(keyframe + 1..=index) {
+            if let StoredArray::Delta(changes) = &frame.lanes[lane].array {
+                for &(position, value) in changes {
+                    array[position] = value;
+                }
+            }
         }
 
-        self.snapshots.last().unwrap()
+        array
     }
 
-    /// Get all snapshots
-    pub fn get_snapshots(&self) -> &[RaceSnapshot] {
-        &self.snapshots
+    /// Number of array elements held in history, counting each delta entry as one
+    pub fn get_stored_element_count(&self) -> usize {
+        self.history
+            .iter()
+            .flat_map(|frame| frame.lanes.iter())
+            .map(|lane| lane.array.element_count())
+            .sum()
+    }
+
+    /// Get all snapshots, oldest first, rebuilding arrays from stored deltas
+    pub fn get_snapshots(&self) -> Vec<RaceSnapshot> {
+        (0..self.len()).filter_map(|index| self.get_snapshot(index)).collect()
     }
 
     /// Get the most recent snapshot
     pub fn get_latest_snapshot(&self) -> Option<&RaceSnapshot> {
-        self.snapshots.last()
+        self.latest.as_ref()
     }
 
-    /// Get snapshot by index
-    pub fn get_snapshot(&self, index: usize) -> Option<&RaceSnapshot> {
-        self.snapshots.get(index)
+    /// Get snapshot by index, rebuilding its arrays from stored deltas
+    pub fn get_snapshot(&self, index: usize) -> Option<RaceSnapshot> {
+        if index + 1 == self.history.len() {
+            return self.latest.clone();
+        }
+
+        let frame = self.history.get(index)?;
+        let algorithm_snapshots = frame
+            .lanes
+            .iter()
+            .enumerate()
+            .map(|(lane_index, lane)| AlgorithmSnapshot {
+                name: lane.name.clone(),
+                array_state: self.rebuild_array(index, lane_index),
+                telemetry: lane.telemetry.clone(),
+                is_complete: lane.is_complete,
+                metrics: lane.metrics.clone(),
+                memory_bytes: lane.memory_bytes,
+                error: lane.error.clone(),
+                stability: lane.stability,
+            })
+            .collect();
+
+        Some(RaceSnapshot {
+            timestamp: frame.timestamp,
+            algorithm_snapshots,
+            step: frame.step,
+            race_complete: frame.race_complete,
+        })
     }
 
     /// Clear all snapshots
     pub fn clear(&mut self) {
-        self.snapshots.clear();
+        self.history.clear();
+        self.latest = None;
+        self.frames_since_keyframe = 0;
     }
 
     /// Get number of snapshots
     pub fn len(&self) -> usize {
-        self.snapshots.len()
+        self.history.len()
     }
 
     /// Check if no snapshots exist
     pub fn is_empty(&self) -> bool {
-        self.snapshots.is_empty()
+        self.history.is_empty()
     }
 
     /// Set maximum number of snapshots to keep
     pub fn set_max_snapshots(&mut self, max: usize) {
         self.max_snapshots = max.max(1);
-        
+
         // Trim existing snapshots if necessary
-        while self.snapshots.len() > self.max_snapshots {
-            self.snapshots.remove(0);
-        }
+        self.trim_history();
     }
 
     /// Get maximum number of snapshots
@@ -192,4 +555,112 @@ mod tests {
         assert_eq!(snapshots[1].step, 3);
         assert_eq!(snapshots[2].step, 4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let mut service = SnapshotService::new(5);
+        let mut bubble = BubbleSort::new();
+        bubble.reset(vec![3, 1, 2]);
+        bubble.step(1);
+        let algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(bubble)];
+
+        let snapshot = service.take_snapshot(&algorithms, 1).clone();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let parsed: RaceSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, snapshot);
+    }
+
+    #[test]
+    fn test_recording_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("race.jsonl");
+
+        let mut service = SnapshotService::new(2);
+        let mut bubble = BubbleSort::new();
+        bubble.reset(vec![4, 3, 2, 1]);
+        let mut algorithms: Vec<Box<dyn Sorter>> = vec![Box::new(bubble)];
+
+        service.start_recording(&path).unwrap();
+        assert!(service.is_recording());
+
+        let mut step = 0;
+        service.take_snapshot(&algorithms, step);
+        while !algorithms[0].is_complete() {
+            algorithms[0].step(2);
+            step += 1;
+            service.take_snapshot(&algorithms, step);
+        }
+        let frames = service.stop_recording().unwrap();
+        assert!(!service.is_recording());
+
+        // The recording keeps every frame even though the service only holds two
+        let recording = SnapshotService::load_recording(&path).unwrap();
+        assert_eq!(recording.len(), frames);
+        assert_eq!(recording.len(), step + 1);
+        assert_eq!(service.len(), 2);
+        assert_eq!(recording.last(), service.get_latest_snapshot());
+        assert_eq!(recording[0].algorithm_snapshots[0].array_state, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_read_recording_reports_bad_lines() {
+        let input = "\n{not json}\n";
+        let error = SnapshotService::read_recording(input.as_bytes()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().starts_with("line 2"));
+    }
+
+    /// Race Bubble Sort lanes to completion, returning every snapshot taken
+    fn record_race(service: &mut SnapshotService, lanes: usize, size: usize) -> Vec<RaceSnapshot> {
+        let data: Vec<i32> = (0..size as i32).rev().collect();
+        let mut algorithms: Vec<Box<dyn Sorter>> = (0..lanes)
+            .map(|_| Box::new(BubbleSort::new()) as Box<dyn Sorter>)
+            .collect();
+        for algorithm in &mut algorithms {
+            algorithm.reset(data.clone());
+        }
+
+        let mut frames = vec![service.take_snapshot(&algorithms, 0).clone()];
+        let mut step = 0;
+        while !algorithms.iter().all(|a| a.is_complete()) {
+            for (offset, algorithm) in algorithms.iter_mut().enumerate() {
+                algorithm.step(1 + offset);
+            }
+            step += 1;
+            frames.push(service.take_snapshot(&algorithms, step).clone());
+        }
+
+        frames
+    }
+
+    #[test]
+    fn test_history_rebuilds_every_frame_from_deltas() {
+        let mut service = SnapshotService::new(10_000);
+        let frames = record_race(&mut service, 2, 40);
+
+        assert!(frames.len() > KEYFRAME_INTERVAL);
+        assert_eq!(service.get_snapshots(), frames);
+    }
+
+    #[test]
+    fn test_history_stores_deltas_not_full_copies() {
+        let mut service = SnapshotService::new(10_000);
+        let frames = record_race(&mut service, 1, 100);
+
+        let full_copies = frames.len() * 100;
+        assert!(service.get_stored_element_count() * 10 < full_copies);
+    }
+
+    #[test]
+    fn test_trimmed_history_still_rebuilds() {
+        let mut service = SnapshotService::new(10_000);
+        let frames = record_race(&mut service, 2, 30);
+
+        // Evict frames from the middle of a keyframe interval
+        service.set_max_snapshots(frames.len() - 10);
+        assert_eq!(service.len(), frames.len() - 10);
+        assert_eq!(service.get_snapshots(), frames[10..].to_vec());
+    }
+}
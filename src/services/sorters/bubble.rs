@@ -1,28 +1,46 @@
 //! Bubble Sort implementation
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
 
 /// Bubble Sort algorithm implementation
 #[derive(Debug)]
-pub struct BubbleSort {
-    data: Vec<i32>,
+pub struct BubbleSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
     current_pass: usize,
     current_pos: usize,
+    /// The pair at `current_pos` is out of order but not yet swapped
+    swap_due: bool,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
 }
 
 impl BubbleSort {
     /// Create a new BubbleSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> BubbleSort<T> {
+    /// Create a BubbleSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
             current_pass: 0,
             current_pos: 0,
+            swap_due: false,
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
         }
     }
@@ -34,51 +52,58 @@ impl Default for BubbleSort {
     }
 }
 
-impl Sorter for BubbleSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
-
-        let mut comparisons_used = 0;
-        let mut moves_made = 0;
+impl<T: Element> BubbleSort<T> {
+    /// Compare and swap neighbours until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
         let n = self.data.len();
-
-        while comparisons_used < budget {
-            if self.current_pos < n - 1 - self.current_pass {
+        loop {
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(self.current_pos, self.current_pos + 1);
+                self.origins.swap(self.current_pos, self.current_pos + 1);
+                self.moves += 1;
+                self.writes += 2;
+                self.swap_due = false;
+                self.current_pos += 1;
+            } else if self.current_pos < n - 1 - self.current_pass {
                 // Compare adjacent elements
-                comparisons_used += 1;
+                budget.compare()?;
                 self.comparisons += 1;
-
-                if self.data[self.current_pos] > self.data[self.current_pos + 1] {
-                    // Swap elements
-                    self.data.swap(self.current_pos, self.current_pos + 1);
-                    moves_made += 1;
-                    self.moves += 1;
+                self.swap_due = self.compare.greater(&self.data[self.current_pos], &self.data[self.current_pos + 1]);
+                if !self.swap_due {
+                    self.current_pos += 1;
                 }
-
-                self.current_pos += 1;
             } else {
                 // End of current pass
                 self.current_pass += 1;
                 self.current_pos = 0;
 
                 if self.current_pass >= n - 1 {
-                    self.complete = true;
-                    break;
+                    return Some(());
                 }
             }
         }
+    }
 
-        StepResult {
-            comparisons_used,
-            moves_made,
-            continued: !self.complete,
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for BubbleSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -98,6 +123,8 @@ impl Sorter for BubbleSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
@@ -116,12 +143,15 @@ impl Sorter for BubbleSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
+        self.origins = (0..self.data.len()).collect();
         self.current_pass = 0;
         self.current_pos = 0;
+        self.swap_due = false;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
     }
 
@@ -129,13 +159,17 @@ impl Sorter for BubbleSort {
         "Bubble Sort"
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
         // Report size of the data array in bytes
-        self.data.len() * std::mem::size_of::<i32>()
+        self.data.len() * std::mem::size_of::<T>()
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -0,0 +1,278 @@
+//! Bucket Sort implementation
+//!
+//! Spreads the key range evenly over about `n / 4` buckets, distributes the
+//! elements with one counting pass and insertion sorts each bucket. Evenly
+//! spread keys leave a few elements per bucket; clustered keys pile up in
+//! a few buckets and the insertion sorts turn quadratic.
+//!
+//! Distribution spends one unit of budget per element moved, insertion one
+//! per comparison and one per swap.
+
+use crate::models::element::RadixKey;
+use crate::models::traits::{BudgetUnit, Granularity, Markers, Sorter, StepResult, Telemetry};
+use crate::services::sorters::buckets::{Digit, KeyWorkspace, LsdPasses, LsdPhase, PassPhase};
+use std::any::Any;
+
+/// Average number of elements per bucket
+const ELEMENTS_PER_BUCKET: usize = 4;
+
+/// Insertion sort of one bucket `[begin, end)` by key
+#[derive(Debug, Clone)]
+struct BucketInsertion {
+    begin: usize,
+    end: usize,
+    /// Element being inserted
+    i: usize,
+    /// Current position of that element
+    j: usize,
+    /// A comparison found the element out of order but the swap is unpaid
+    swap_due: bool,
+}
+
+impl BucketInsertion {
+    fn new(begin: usize, end: usize) -> Self {
+        Self {
+            begin,
+            end,
+            i: begin + 1,
+            j: begin + 1,
+            swap_due: false,
+        }
+    }
+
+    fn run<T: RadixKey>(&mut self, ws: &mut KeyWorkspace<T>, budget: &mut usize) -> Option<()> {
+        while self.i < self.end {
+            if self.swap_due {
+                ws.touch(budget)?;
+                ws.swap(self.j, self.j - 1);
+                self.j -= 1;
+                self.swap_due = false;
+                continue;
+            }
+
+            if self.j > self.begin && ws.less(self.j, self.j - 1, budget)? {
+                self.swap_due = true;
+                continue;
+            }
+
+            self.i += 1;
+            self.j = self.i;
+        }
+        Some(())
+    }
+}
+
+/// Bucket Sort algorithm implementation
+#[derive(Debug)]
+pub struct BucketSort<T = i32> {
+    ws: KeyWorkspace<T>,
+    distribution: LsdPasses,
+    /// Buckets left to insertion sort, next one last
+    pending: Vec<(usize, usize)>,
+    current: Option<BucketInsertion>,
+    bucket_count: usize,
+    complete: bool,
+}
+
+impl BucketSort {
+    /// Create a new BucketSort instance
+    pub fn new() -> Self {
+        Self::for_keys()
+    }
+}
+
+impl<T: RadixKey> BucketSort<T> {
+    /// Create a BucketSort instance for any element with a radix key
+    pub fn for_keys() -> Self {
+        Self {
+            ws: KeyWorkspace::new(),
+            distribution: LsdPasses::new(0),
+            pending: Vec::new(),
+            current: None,
+            bucket_count: 0,
+            complete: true,
+        }
+    }
+
+    fn run(&mut self, budget: &mut usize) -> Option<()> {
+        if !self.distribution.is_done() {
+            let buckets = self.bucket_count;
+            self.distribution.run(&mut self.ws, budget, |min, max| {
+                vec![Digit::Scaled {
+                    offset: min,
+                    span: (max - min) as u128 + 1,
+                    buckets,
+                }]
+            })?;
+            // Insertion sort the buckets left to right, skipping single elements
+            self.pending = self
+                .distribution
+                .buckets()
+                .iter()
+                .rev()
+                .filter(|(low, high)| high > low)
+                .copied()
+                .collect();
+        }
+
+        loop {
+            let mut insertion = match self.current.take() {
+                Some(insertion) => insertion,
+                None => match self.pending.pop() {
+                    Some((low, high)) => BucketInsertion::new(low, high + 1),
+                    None => return Some(()),
+                },
+            };
+
+            if insertion.run(&mut self.ws, budget).is_none() {
+                self.current = Some(insertion);
+                return None;
+            }
+        }
+    }
+}
+
+impl Default for BucketSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RadixKey> Sorter<T> for BucketSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        if self.complete {
+            return StepResult {
+                comparisons_used: 0,
+                moves_made: 0,
+                continued: false,
+            };
+        }
+
+        let initial_comparisons = self.ws.comparisons;
+        let initial_moves = self.ws.moves;
+        let mut remaining_budget = budget;
+
+        self.complete = self.run(&mut remaining_budget).is_some();
+
+        self.ws.track_memory(self.get_memory_usage());
+        if self.complete {
+            self.ws.release();
+        }
+
+        StepResult {
+            comparisons_used: (self.ws.comparisons - initial_comparisons) as usize,
+            moves_made: (self.ws.moves - initial_moves) as usize,
+            continued: !self.complete,
+        }
+    }
+
+    fn budget_unit(&self) -> BudgetUnit {
+        BudgetUnit::Operations
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+        let last = self.ws.data.len().saturating_sub(1);
+
+        let status_text = match &self.distribution.phase {
+            _ if self.complete => "Completed".to_string(),
+            LsdPhase::Scan(scan) => {
+                markers.cursors = vec![scan.next.min(last)];
+                "Scanning for the key range".to_string()
+            }
+            LsdPhase::Pass { pass, .. } => {
+                markers.cursors = vec![pass.next.min(last)];
+                if pass.phase == PassPhase::Counting {
+                    format!("Counting bucket sizes for {} buckets", self.bucket_count)
+                } else {
+                    markers.buckets = pass.buckets();
+                    "Distributing elements into buckets".to_string()
+                }
+            }
+            LsdPhase::Done => {
+                markers.buckets = self.pending.clone();
+                match &self.current {
+                    Some(insertion) => {
+                        markers.insertion_range = Some((insertion.begin, insertion.end - 1));
+                        markers.cursors = vec![insertion.j];
+                        format!(
+                            "Insertion sorting bucket [{}, {}), {} buckets left",
+                            insertion.begin,
+                            insertion.end,
+                            self.pending.len()
+                        )
+                    }
+                    None => "Sorting buckets".to_string(),
+                }
+            }
+        };
+
+        let progress_hint = if self.complete {
+            1.0
+        } else if !self.distribution.is_done() {
+            self.distribution.progress(self.ws.data.len()) * 0.5
+        } else {
+            let left: usize = self.pending.iter().map(|(low, high)| high - low + 1).sum::<usize>()
+                + self.current.as_ref().map_or(0, |insertion| insertion.end - insertion.i);
+            1.0 - 0.5 * left as f32 / self.ws.data.len().max(1) as f32
+        };
+
+        Telemetry {
+            total_comparisons: self.ws.comparisons,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.memory_peak,
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint,
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.distribution = LsdPasses::new(data.len());
+        self.bucket_count = (data.len() / ELEMENTS_PER_BUCKET).max(1);
+        self.pending.clear();
+        self.current = None;
+        self.complete = data.len() <= 1;
+        self.ws.reset(data);
+    }
+
+    fn name(&self) -> &str {
+        "Bucket Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        self.ws.array()
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(self.ws.array_origins())
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + output buffer + bucket counts + bucket bounds
+        self.ws.data_bytes()
+            + self.ws.buffer_bytes()
+            + self.distribution.memory()
+            + self.pending.len() * std::mem::size_of::<(usize, usize)>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
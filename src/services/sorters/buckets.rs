@@ -0,0 +1,417 @@
+//! Resumable building blocks shared by the comparison-free sorters
+//!
+//! Radix, counting and bucket sorts order elements by their
+//! [`RadixKey`] instead of comparing them. They spend one unit of budget per
+//! element operation: reading a key into a histogram, writing an element to
+//! its bucket or copying it back. Each of these is reported as a move.
+//! Operations return `None` when the budget runs out before they finish.
+
+use crate::models::element::RadixKey;
+
+/// Array being sorted, with origins, the output buffer and counters
+#[derive(Debug)]
+pub(crate) struct KeyWorkspace<T> {
+    pub data: Vec<T>,
+    pub origins: Vec<usize>,
+    /// Output of distribution passes, allocated on first use
+    buffer: Vec<T>,
+    buffer_origins: Vec<usize>,
+    /// Whether a pass over the whole array is writing into the buffer
+    scattering: bool,
+    pub comparisons: u64,
+    pub moves: u64,
+    pub writes: u64,
+    pub memory_peak: usize,
+}
+
+impl<T: RadixKey> KeyWorkspace<T> {
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            origins: Vec::new(),
+            buffer: Vec::new(),
+            buffer_origins: Vec::new(),
+            scattering: false,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            memory_peak: 0,
+        }
+    }
+
+    pub fn reset(&mut self, data: Vec<T>) {
+        self.origins = (0..data.len()).collect();
+        self.data = data;
+        self.release();
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.memory_peak = self.data_bytes();
+    }
+
+    /// Spend one unit of budget on an element move, or `None` if it is spent
+    pub fn touch(&mut self, budget: &mut usize) -> Option<()> {
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        self.moves += 1;
+        Some(())
+    }
+
+    /// Check `key(a) < key(b)`, or `None` if the budget is spent
+    pub fn less(&mut self, a: usize, b: usize, budget: &mut usize) -> Option<bool> {
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        self.comparisons += 1;
+        Some(self.key(a) < self.key(b))
+    }
+
+    pub fn key(&self, index: usize) -> u64 {
+        self.data[index].radix_key()
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+        self.origins.swap(a, b);
+        self.writes += 2;
+    }
+
+    /// Array to display: the buffer while a whole-array pass fills it
+    pub fn array(&self) -> &[T] {
+        if self.scattering { &self.buffer } else { &self.data }
+    }
+
+    /// Origins matching [`KeyWorkspace::array`]
+    pub fn array_origins(&self) -> &[usize] {
+        if self.scattering { &self.buffer_origins } else { &self.origins }
+    }
+
+    /// Free the output buffer once sorting is done
+    pub fn release(&mut self) {
+        self.buffer = Vec::new();
+        self.buffer_origins = Vec::new();
+        self.scattering = false;
+    }
+
+    pub fn data_bytes(&self) -> usize {
+        self.data.len() * std::mem::size_of::<T>()
+    }
+
+    pub fn buffer_bytes(&self) -> usize {
+        self.buffer.len() * std::mem::size_of::<T>()
+    }
+
+    /// Record the current memory use towards the peak
+    pub fn track_memory(&mut self, bytes: usize) {
+        self.memory_peak = self.memory_peak.max(bytes);
+    }
+
+    fn allocate(&mut self) {
+        if self.buffer.len() != self.data.len() {
+            self.buffer = self.data.clone();
+            self.buffer_origins = self.origins.clone();
+        }
+    }
+
+    fn scatter(&mut self, from: usize, to: usize) {
+        self.buffer[to] = self.data[from].clone();
+        self.buffer_origins[to] = self.origins[from];
+        self.writes += 1;
+    }
+
+    fn copy_back(&mut self, index: usize) {
+        self.data[index] = self.buffer[index].clone();
+        self.origins[index] = self.buffer_origins[index];
+        self.writes += 1;
+    }
+
+    /// Make the filled buffer the array, keeping the old array as the next buffer
+    fn swap_buffers(&mut self) {
+        std::mem::swap(&mut self.data, &mut self.buffer);
+        std::mem::swap(&mut self.origins, &mut self.buffer_origins);
+        self.scattering = false;
+    }
+}
+
+/// Pass over `[begin, end)` finding the smallest and largest key
+#[derive(Debug, Clone)]
+pub(crate) struct KeyScan {
+    /// Next element to read
+    pub next: usize,
+    end: usize,
+    min: u64,
+    max: u64,
+}
+
+impl KeyScan {
+    pub fn new(begin: usize, end: usize) -> Self {
+        Self {
+            next: begin,
+            end,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    /// Returns the smallest and largest key
+    pub fn run<T: RadixKey>(&mut self, ws: &mut KeyWorkspace<T>, budget: &mut usize) -> Option<(u64, u64)> {
+        while self.next < self.end {
+            ws.touch(budget)?;
+            let key = ws.key(self.next);
+            self.min = self.min.min(key);
+            self.max = self.max.max(key);
+            self.next += 1;
+        }
+        Some((self.min, self.max))
+    }
+}
+
+/// Bucket an element's key falls into during a distribution pass
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Digit {
+    /// `bits` bits of `key - offset`, starting at bit `shift`
+    Bits { offset: u64, shift: u32, bits: u32 },
+    /// Position of `key - offset` among `span` keys split evenly into `buckets`
+    Scaled { offset: u64, span: u128, buckets: usize },
+}
+
+impl Digit {
+    /// Number of buckets
+    pub fn radix(&self) -> usize {
+        match *self {
+            Digit::Bits { bits, .. } => 1 << bits,
+            Digit::Scaled { buckets, .. } => buckets,
+        }
+    }
+
+    pub fn of(&self, key: u64) -> usize {
+        match *self {
+            Digit::Bits { offset, shift, bits } => (((key - offset) >> shift) & ((1 << bits) - 1)) as usize,
+            Digit::Scaled { offset, span, buckets } => ((key - offset) as u128 * buckets as u128 / span) as usize,
+        }
+    }
+}
+
+/// Stage of a [`CountingPass`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PassPhase {
+    /// Building the histogram of digits
+    Counting,
+    /// Writing each element to its bucket in the buffer
+    Scattering,
+    /// Copying a partial range back from the buffer
+    CopyingBack,
+}
+
+/// Stable distribution of `[begin, end)` into buckets by a digit of each key
+#[derive(Debug, Clone)]
+pub(crate) struct CountingPass {
+    pub begin: usize,
+    pub end: usize,
+    pub phase: PassPhase,
+    /// Next element to count, scatter or copy back
+    pub next: usize,
+    digit: Digit,
+    counts: Vec<usize>,
+    /// First index of each bucket, once counting is done
+    starts: Vec<usize>,
+}
+
+impl CountingPass {
+    pub fn new(begin: usize, end: usize, digit: Digit) -> Self {
+        Self {
+            begin,
+            end,
+            phase: PassPhase::Counting,
+            next: begin,
+            digit,
+            counts: vec![0; digit.radix()],
+            starts: Vec::new(),
+        }
+    }
+
+    pub fn run<T: RadixKey>(&mut self, ws: &mut KeyWorkspace<T>, budget: &mut usize) -> Option<()> {
+        loop {
+            match self.phase {
+                PassPhase::Counting => {
+                    while self.next < self.end {
+                        ws.touch(budget)?;
+                        self.counts[self.digit.of(ws.key(self.next))] += 1;
+                        self.next += 1;
+                    }
+
+                    let mut start = self.begin;
+                    self.starts = self
+                        .counts
+                        .iter()
+                        .map(|&count| {
+                            let bucket = start;
+                            start += count;
+                            bucket
+                        })
+                        .collect();
+
+                    // Every key shares the digit, so the range is already distributed
+                    if self.counts.iter().any(|&count| count == self.end - self.begin) {
+                        return Some(());
+                    }
+
+                    ws.allocate();
+                    ws.scattering = self.begin == 0 && self.end == ws.data.len();
+                    self.counts.clone_from(&self.starts);
+                    self.phase = PassPhase::Scattering;
+                    self.next = self.begin;
+                }
+                PassPhase::Scattering => {
+                    while self.next < self.end {
+                        ws.touch(budget)?;
+                        let bucket = self.digit.of(ws.key(self.next));
+                        ws.scatter(self.next, self.counts[bucket]);
+                        self.counts[bucket] += 1;
+                        self.next += 1;
+                    }
+
+                    if ws.scattering {
+                        ws.swap_buffers();
+                        return Some(());
+                    }
+                    self.phase = PassPhase::CopyingBack;
+                    self.next = self.begin;
+                }
+                PassPhase::CopyingBack => {
+                    while self.next < self.end {
+                        ws.touch(budget)?;
+                        ws.copy_back(self.next);
+                        self.next += 1;
+                    }
+                    return Some(());
+                }
+            }
+        }
+    }
+
+    /// Non-empty buckets as inclusive index ranges, once counting is done
+    pub fn buckets(&self) -> Vec<(usize, usize)> {
+        let ends = self.starts.iter().skip(1).copied().chain(std::iter::once(self.end));
+        self.starts
+            .iter()
+            .zip(ends)
+            .filter(|(start, end)| **start < *end)
+            .map(|(&start, end)| (start, end - 1))
+            .collect()
+    }
+
+    /// Elements already handled in the current phase
+    pub fn done(&self) -> usize {
+        self.next - self.begin
+    }
+
+    /// Bytes held by the histogram
+    pub fn memory(&self) -> usize {
+        (self.counts.len() + self.starts.len()) * std::mem::size_of::<usize>()
+    }
+}
+
+/// Number of bits needed to tell keys in `[min, max]` apart
+pub(crate) fn key_bits(min: u64, max: u64) -> u32 {
+    u64::BITS - (max - min).leading_zeros()
+}
+
+/// Stage of an [`LsdPasses`] run
+#[derive(Debug, Clone)]
+pub(crate) enum LsdPhase {
+    /// Finding the key range, which decides the digits
+    Scan(KeyScan),
+    /// Distributing the whole array by digit `index` of `passes`
+    Pass { pass: CountingPass, index: usize, passes: usize },
+    Done,
+}
+
+/// Key scan followed by whole-array counting passes, least significant digit first
+#[derive(Debug, Clone)]
+pub(crate) struct LsdPasses {
+    pub phase: LsdPhase,
+    digits: Vec<Digit>,
+    /// Buckets of the last finished pass
+    buckets: Vec<(usize, usize)>,
+}
+
+impl LsdPasses {
+    pub fn new(len: usize) -> Self {
+        Self {
+            phase: if len <= 1 { LsdPhase::Done } else { LsdPhase::Scan(KeyScan::new(0, len)) },
+            digits: Vec::new(),
+            buckets: Vec::new(),
+        }
+    }
+
+    /// Run the passes, with `plan` picking the digits once the smallest and
+    /// largest key are known
+    pub fn run<T: RadixKey>(
+        &mut self,
+        ws: &mut KeyWorkspace<T>,
+        budget: &mut usize,
+        plan: impl Fn(u64, u64) -> Vec<Digit>,
+    ) -> Option<()> {
+        loop {
+            let index = match &mut self.phase {
+                LsdPhase::Scan(scan) => {
+                    let (min, max) = scan.run(ws, budget)?;
+                    self.digits = plan(min, max);
+                    0
+                }
+                LsdPhase::Pass { pass, index, .. } => {
+                    pass.run(ws, budget)?;
+                    self.buckets = pass.buckets();
+                    *index + 1
+                }
+                LsdPhase::Done => return Some(()),
+            };
+
+            self.phase = match self.digits.get(index) {
+                Some(&digit) => LsdPhase::Pass {
+                    pass: CountingPass::new(0, ws.data.len(), digit),
+                    index,
+                    passes: self.digits.len(),
+                },
+                None => LsdPhase::Done,
+            };
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self.phase, LsdPhase::Done)
+    }
+
+    /// Non-empty buckets of the last finished pass, as inclusive ranges
+    pub fn buckets(&self) -> &[(usize, usize)] {
+        &self.buckets
+    }
+
+    /// Share of the element operations done so far
+    ///
+    /// The number of passes is only known after the scan, which assumes one.
+    pub fn progress(&self, len: usize) -> f32 {
+        let len = len.max(1) as f32;
+        let (done, passes) = match &self.phase {
+            LsdPhase::Scan(scan) => (scan.next as f32, 1.0),
+            LsdPhase::Pass { pass, index, passes } => {
+                let scattered = if pass.phase == PassPhase::Counting { 0.0 } else { len };
+                (len * (1.0 + 2.0 * *index as f32) + scattered + pass.done() as f32, *passes as f32)
+            }
+            LsdPhase::Done => return 1.0,
+        };
+        (done / (len * (1.0 + 2.0 * passes))).clamp(0.0, 1.0)
+    }
+
+    /// Bytes held by the active pass's histogram
+    pub fn memory(&self) -> usize {
+        match &self.phase {
+            LsdPhase::Pass { pass, .. } => pass.memory(),
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Step budgets shared by the comparison sorts
+//!
+//! A step is either counted in comparisons ([`Sorter::step`]) or in
+//! operations ([`Sorter::step_operations`]). Counted in comparisons, the
+//! moves a comparison calls for come free with it. Counted in operations,
+//! every comparison and every element move costs one unit, so sorters keep
+//! enough state to make a move they could not pay for in the next step.
+//!
+//! [`Sorter::step`]: crate::models::traits::Sorter::step
+//! [`Sorter::step_operations`]: crate::models::traits::Sorter::step_operations
+
+use crate::models::traits::StepResult;
+
+/// Work one step may still do, and the work it has done
+#[derive(Debug)]
+pub(crate) struct Budget {
+    remaining: usize,
+    moves_are_free: bool,
+    comparisons: usize,
+    moves: usize,
+}
+
+impl Budget {
+    /// Budget of `budget` comparisons; moves are free
+    pub fn comparisons(budget: usize) -> Self {
+        Self {
+            remaining: budget,
+            moves_are_free: true,
+            comparisons: 0,
+            moves: 0,
+        }
+    }
+
+    /// Budget of `budget` operations, comparisons and moves alike
+    pub fn operations(budget: usize) -> Self {
+        Self {
+            moves_are_free: false,
+            ..Self::comparisons(budget)
+        }
+    }
+
+    /// Pay for a comparison, or `None` if the budget is spent
+    pub fn compare(&mut self) -> Option<()> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.comparisons += 1;
+        Some(())
+    }
+
+    /// Pay for an element move, or `None` if the budget is spent
+    pub fn shift(&mut self) -> Option<()> {
+        if !self.moves_are_free {
+            if self.remaining == 0 {
+                return None;
+            }
+            self.remaining -= 1;
+        }
+        self.moves += 1;
+        Some(())
+    }
+
+    /// Whether another comparison can be paid for
+    pub fn can_compare(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Result of a step that spent this budget
+    pub fn result(&self, continued: bool) -> StepResult {
+        StepResult {
+            comparisons_used: self.comparisons,
+            moves_made: self.moves,
+            continued,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moves_are_free_by_comparison() {
+        let mut budget = Budget::comparisons(1);
+        assert_eq!(budget.compare(), Some(()));
+        assert_eq!(budget.shift(), Some(()));
+        assert_eq!(budget.shift(), Some(()));
+        assert_eq!(budget.compare(), None);
+        assert_eq!(budget.result(true).moves_made, 2);
+    }
+
+    #[test]
+    fn test_moves_cost_one_by_operation() {
+        let mut budget = Budget::operations(2);
+        assert_eq!(budget.compare(), Some(()));
+        assert_eq!(budget.shift(), Some(()));
+        assert!(!budget.can_compare());
+        assert_eq!(budget.shift(), None);
+        assert_eq!(budget.compare(), None);
+        assert_eq!(budget.result(true), StepResult { comparisons_used: 1, moves_made: 1, continued: true });
+    }
+}
@@ -0,0 +1,238 @@
+//! Cocktail Shaker Sort implementation
+//!
+//! Bubble sort that alternates direction: forward passes carry the largest
+//! element to the end, backward passes carry the smallest to the front, so
+//! small elements near the end ("turtles") no longer need one pass each.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Cocktail Shaker Sort algorithm implementation
+#[derive(Debug)]
+pub struct CocktailSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    /// First index not yet known to be in its final place
+    start: usize,
+    /// Last index not yet known to be in its final place
+    end: usize,
+    /// Left element of the pair compared next
+    current_pos: usize,
+    /// The pair at `current_pos` is out of order but not yet swapped
+    swap_due: bool,
+    forward: bool,
+    swapped: bool,
+    current_pass: usize,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+impl CocktailSort {
+    /// Create a new CocktailSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> CocktailSort<T> {
+    /// Create a CocktailSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            start: 0,
+            end: 0,
+            current_pos: 0,
+            swap_due: false,
+            forward: true,
+            swapped: false,
+            current_pass: 0,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+
+    /// Finish a pass, turning around unless it made no swaps
+    fn end_pass(&mut self) {
+        if self.forward {
+            self.end -= 1;
+            self.current_pos = self.end.saturating_sub(1);
+        } else {
+            self.start += 1;
+            self.current_pos = self.start;
+        }
+        self.forward = !self.forward;
+        self.current_pass += 1;
+
+        if !self.swapped || self.start >= self.end {
+            self.complete = true;
+        }
+        self.swapped = false;
+    }
+}
+
+impl Default for CocktailSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> CocktailSort<T> {
+    /// Shake until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        while !self.complete {
+            let pos = self.current_pos;
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(pos, pos + 1);
+                self.origins.swap(pos, pos + 1);
+                self.moves += 1;
+                self.writes += 2;
+                self.swapped = true;
+                self.swap_due = false;
+                self.advance(pos);
+                continue;
+            }
+
+            let in_pass = if self.forward {
+                pos < self.end
+            } else {
+                pos >= self.start && pos < self.end
+            };
+            if !in_pass {
+                self.end_pass();
+                continue;
+            }
+
+            budget.compare()?;
+            self.comparisons += 1;
+            self.swap_due = self.compare.greater(&self.data[pos], &self.data[pos + 1]);
+            if !self.swap_due {
+                self.advance(pos);
+            }
+        }
+        Some(())
+    }
+
+    /// Move on from the pair at `pos` in the direction of the pass
+    fn advance(&mut self, pos: usize) {
+        if self.forward {
+            self.current_pos += 1;
+        } else if pos == self.start {
+            // Past the front of the unsorted range
+            self.current_pos = self.end;
+        } else {
+            self.current_pos -= 1;
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for CocktailSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        if !self.complete && self.current_pos < self.end {
+            markers.cursors = vec![self.current_pos, self.current_pos + 1];
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else {
+                format!(
+                    "Pass {} {}, Position {}",
+                    self.current_pass + 1,
+                    if self.forward { "forward" } else { "backward" },
+                    self.current_pos
+                )
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                // Every pass fixes one element at either end
+                let placed = self.start + (self.data.len() - 1 - self.end);
+                (placed as f32 / (self.data.len() - 1) as f32).clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.start = 0;
+        self.end = self.data.len().saturating_sub(1);
+        self.current_pos = 0;
+        self.swap_due = false;
+        self.forward = true;
+        self.swapped = false;
+        self.current_pass = 0;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+    }
+
+    fn name(&self) -> &str {
+        "Cocktail Shaker Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Report size of the data array in bytes
+        self.data.len() * std::mem::size_of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,199 @@
+//! Comb Sort implementation
+//!
+//! Bubble sort over a gap that shrinks by a factor of 1.3 each pass, so
+//! elements far from their place move a long way early on. Once the gap
+//! reaches 1 it is plain bubble sort, stopping after a pass without swaps.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Comb Sort algorithm implementation
+#[derive(Debug)]
+pub struct CombSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    gap: usize,
+    current_pos: usize,
+    /// The pair at `current_pos` is out of order but not yet swapped
+    swap_due: bool,
+    swapped: bool,
+    current_pass: usize,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+/// Next gap after `gap`, shrinking by the usual factor of 1.3
+fn shrink(gap: usize) -> usize {
+    (gap * 10 / 13).max(1)
+}
+
+impl CombSort {
+    /// Create a new CombSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> CombSort<T> {
+    /// Create a CombSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            gap: 1,
+            current_pos: 0,
+            swap_due: false,
+            swapped: false,
+            current_pass: 0,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+}
+
+impl Default for CombSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> CombSort<T> {
+    /// Comb until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        let n = self.data.len();
+        loop {
+            let (left, right) = (self.current_pos, self.current_pos + self.gap);
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(left, right);
+                self.origins.swap(left, right);
+                self.moves += 1;
+                self.writes += 2;
+                self.swapped = true;
+                self.swap_due = false;
+                self.current_pos += 1;
+            } else if right < n {
+                budget.compare()?;
+                self.comparisons += 1;
+                self.swap_due = self.compare.greater(&self.data[left], &self.data[right]);
+                if !self.swap_due {
+                    self.current_pos += 1;
+                }
+            } else {
+                // End of current pass
+                if self.gap == 1 && !self.swapped {
+                    return Some(());
+                }
+                self.gap = shrink(self.gap);
+                self.current_pos = 0;
+                self.swapped = false;
+                self.current_pass += 1;
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for CombSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        if !self.complete && self.current_pos + self.gap < self.data.len() {
+            markers.cursors = vec![self.current_pos, self.current_pos + self.gap];
+            markers.gap = Some(self.gap);
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else {
+                format!("Pass {}, Gap {}, Position {}", self.current_pass + 1, self.gap, self.current_pos)
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                // The gap shrinks geometrically, so passes so far over passes
+                // until it reaches 1 is a fair estimate
+                let passes = ((self.data.len() as f32).ln() / 1.3f32.ln()).max(1.0);
+                (self.current_pass as f32 / passes).clamp(0.0, 0.99)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.gap = shrink(self.data.len());
+        self.current_pos = 0;
+        self.swap_due = false;
+        self.swapped = false;
+        self.current_pass = 0;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+    }
+
+    fn name(&self) -> &str {
+        "Comb Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Report size of the data array in bytes
+        self.data.len() * std::mem::size_of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
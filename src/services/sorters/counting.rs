@@ -0,0 +1,171 @@
+//! Counting Sort implementation
+//!
+//! Counts how often each key occurs, then writes every element straight to
+//! its final position. Key ranges wider than [`MAX_COUNTS`] are counted one
+//! 16-bit digit at a time, to keep the count array bounded.
+
+use crate::models::element::RadixKey;
+use crate::models::traits::{BudgetUnit, Granularity, Markers, Sorter, StepResult, Telemetry};
+use crate::services::sorters::buckets::{key_bits, Digit, KeyWorkspace, LsdPasses, LsdPhase, PassPhase};
+use std::any::Any;
+
+/// Largest number of distinct key values counted in a single pass
+pub const MAX_COUNTS: usize = 1 << 16;
+
+/// Counting Sort algorithm implementation
+#[derive(Debug)]
+pub struct CountingSort<T = i32> {
+    ws: KeyWorkspace<T>,
+    passes: LsdPasses,
+}
+
+impl CountingSort {
+    /// Create a new CountingSort instance
+    pub fn new() -> Self {
+        Self::for_keys()
+    }
+}
+
+impl<T: RadixKey> CountingSort<T> {
+    /// Create a CountingSort instance for any element with a radix key
+    pub fn for_keys() -> Self {
+        Self {
+            ws: KeyWorkspace::new(),
+            passes: LsdPasses::new(0),
+        }
+    }
+
+    /// One count per key value, or 16-bit digits for wide ranges
+    fn plan(min: u64, max: u64) -> Vec<Digit> {
+        let span = (max - min) as u128 + 1;
+        if span <= MAX_COUNTS as u128 {
+            return vec![Digit::Scaled {
+                offset: min,
+                span,
+                buckets: span as usize,
+            }];
+        }
+
+        let bits = MAX_COUNTS.ilog2();
+        (0..key_bits(min, max).div_ceil(bits))
+            .map(|pass| Digit::Bits { offset: min, shift: pass * bits, bits })
+            .collect()
+    }
+}
+
+impl Default for CountingSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RadixKey> Sorter<T> for CountingSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        if self.passes.is_done() {
+            return StepResult {
+                comparisons_used: 0,
+                moves_made: 0,
+                continued: false,
+            };
+        }
+
+        let initial_moves = self.ws.moves;
+        let mut remaining_budget = budget;
+        self.passes.run(&mut self.ws, &mut remaining_budget, Self::plan);
+
+        self.ws.track_memory(self.get_memory_usage());
+        if self.passes.is_done() {
+            self.ws.release();
+        }
+
+        StepResult {
+            comparisons_used: 0,
+            moves_made: (self.ws.moves - initial_moves) as usize,
+            continued: !self.passes.is_done(),
+        }
+    }
+
+    fn budget_unit(&self) -> BudgetUnit {
+        BudgetUnit::Operations
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.passes.is_done()
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+        let last = self.ws.data.len().saturating_sub(1);
+
+        let status_text = match &self.passes.phase {
+            LsdPhase::Done => "Completed".to_string(),
+            LsdPhase::Scan(scan) => {
+                markers.cursors = vec![scan.next.min(last)];
+                "Scanning for the key range".to_string()
+            }
+            LsdPhase::Pass { pass, index, passes } => {
+                markers.cursors = vec![pass.next.min(last)];
+                let activity = match pass.phase {
+                    PassPhase::Counting => "Counting keys",
+                    _ => {
+                        markers.buckets = pass.buckets();
+                        "Writing elements to their positions"
+                    }
+                };
+                if *passes > 1 {
+                    markers.digit_pass = Some((index + 1, *passes));
+                    format!("{} by 16-bit digit {}/{}", activity, index + 1, passes)
+                } else {
+                    activity.to_string()
+                }
+            }
+        };
+
+        Telemetry {
+            total_comparisons: 0,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.memory_peak,
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint: self.passes.progress(self.ws.data.len()),
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.passes = LsdPasses::new(data.len());
+        self.ws.reset(data);
+    }
+
+    fn name(&self) -> &str {
+        "Counting Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        self.ws.array()
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(self.ws.array_origins())
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + output buffer + key counts
+        self.ws.data_bytes() + self.ws.buffer_bytes() + self.passes.memory()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
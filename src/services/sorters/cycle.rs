@@ -0,0 +1,255 @@
+//! Cycle Sort implementation
+//!
+//! Picks up the element at the start of each cycle and counts the elements
+//! smaller than it to find its final position, drops it there and picks up
+//! the element it displaced, until the cycle closes. Every element is written
+//! at most once, so the write count is the minimum possible for an in-place
+//! sort, paid for with a quadratic number of comparisons.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+use std::cmp::Ordering;
+
+/// What the sorter is doing with the element in hand
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CycleState {
+    /// Counting elements smaller than the one in hand; `next` is the next to
+    /// compare and `pos` the position found so far
+    Counting { next: usize, pos: usize },
+    /// Stepping `pos` past elements equal to the one in hand
+    SkippingEqual { pos: usize },
+    /// Dropping the element in hand at `pos`, its final position
+    Placing { pos: usize },
+}
+
+/// Cycle Sort algorithm implementation
+#[derive(Debug)]
+pub struct CycleSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    cycle_start: usize,
+    /// Element being carried to its final position, with its origin
+    hand: Option<(T, usize)>,
+    /// Whether the current cycle has written anything yet
+    displaced: bool,
+    state: CycleState,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+impl CycleSort {
+    /// Create a new CycleSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> CycleSort<T> {
+    /// Create a CycleSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            cycle_start: 0,
+            hand: None,
+            displaced: false,
+            state: CycleState::Counting { next: 1, pos: 0 },
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+
+    /// Move on to the next cycle
+    fn next_cycle(&mut self) {
+        self.hand = None;
+        self.displaced = false;
+        self.cycle_start += 1;
+        self.state = CycleState::Counting { next: self.cycle_start + 1, pos: self.cycle_start };
+        if self.cycle_start + 1 >= self.data.len() {
+            self.complete = true;
+        }
+    }
+
+    /// Drop the element in hand at `pos`, picking up the one it displaces
+    fn place(&mut self, pos: usize) {
+        let (item, origin) = self.hand.take().expect("an element is in hand while placing");
+        let displaced = std::mem::replace(&mut self.data[pos], item);
+        let displaced_origin = std::mem::replace(&mut self.origins[pos], origin);
+        self.moves += 1;
+        self.writes += 1;
+        self.hand = Some((displaced, displaced_origin));
+        self.displaced = true;
+    }
+}
+
+impl Default for CycleSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> CycleSort<T> {
+    /// Rotate cycles until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        let n = self.data.len();
+        while !self.complete {
+            let cycle_start = self.cycle_start;
+            if self.hand.is_none() {
+                self.hand = Some((self.data[cycle_start].clone(), self.origins[cycle_start]));
+            }
+
+            match self.state {
+                CycleState::Counting { next, pos } if next < n => {
+                    budget.compare()?;
+                    self.comparisons += 1;
+
+                    let (item, _) = self.hand.as_ref().expect("an element is in hand");
+                    let smaller = self.compare.less(&self.data[next], item);
+                    self.state = CycleState::Counting { next: next + 1, pos: pos + usize::from(smaller) };
+                }
+                CycleState::Counting { pos, .. } => {
+                    if pos != cycle_start {
+                        self.state = CycleState::SkippingEqual { pos };
+                    } else if self.displaced {
+                        // The cycle closes: the element in hand belongs where
+                        // it started
+                        budget.shift()?;
+                        self.place(cycle_start);
+                        self.next_cycle();
+                    } else {
+                        // Already in place, nothing to write
+                        self.next_cycle();
+                    }
+                }
+                CycleState::SkippingEqual { pos } => {
+                    budget.compare()?;
+                    self.comparisons += 1;
+
+                    let (item, _) = self.hand.as_ref().expect("an element is in hand");
+                    self.state = if self.compare.compare(item, &self.data[pos]) == Ordering::Equal {
+                        CycleState::SkippingEqual { pos: pos + 1 }
+                    } else {
+                        CycleState::Placing { pos }
+                    };
+                }
+                CycleState::Placing { pos } => {
+                    budget.shift()?;
+                    self.place(pos);
+                    self.state = CycleState::Counting { next: cycle_start + 1, pos: cycle_start };
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for CycleSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        if !self.complete {
+            markers.cursors.push(self.cycle_start);
+            match self.state {
+                CycleState::Counting { next, .. } if next < self.data.len() => markers.cursors.push(next),
+                CycleState::SkippingEqual { pos } | CycleState::Placing { pos } => markers.cursors.push(pos),
+                CycleState::Counting { .. } => {}
+            }
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else {
+                match self.state {
+                    CycleState::Counting { next, pos } => {
+                        format!("Cycle {}, counting smaller elements ({} so far, at {})", self.cycle_start, pos - self.cycle_start, next)
+                    }
+                    CycleState::SkippingEqual { pos } => format!("Cycle {}, skipping duplicates at {}", self.cycle_start, pos),
+                    CycleState::Placing { pos } => format!("Cycle {}, placing element at {}", self.cycle_start, pos),
+                }
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                (self.cycle_start as f32 / (self.data.len() - 1) as f32).clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.cycle_start = 0;
+        self.hand = None;
+        self.displaced = false;
+        self.state = CycleState::Counting { next: 1, pos: 0 };
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+    }
+
+    fn name(&self) -> &str {
+        "Cycle Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array plus the element in hand
+        (self.data.len() + 1) * std::mem::size_of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
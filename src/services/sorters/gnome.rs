@@ -0,0 +1,190 @@
+//! Gnome Sort implementation
+//!
+//! A single cursor walks forward while adjacent elements are in order and
+//! swaps its way back when they are not, like insertion sort done with
+//! adjacent swaps and no memory of where the insertion started.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Gnome Sort algorithm implementation
+#[derive(Debug)]
+pub struct GnomeSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    /// Right element of the pair compared next
+    current_pos: usize,
+    /// Furthest position the cursor has reached; everything before it has
+    /// been sorted at least once
+    frontier: usize,
+    /// The pair ending at `current_pos` is out of order but not yet swapped
+    swap_due: bool,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+impl GnomeSort {
+    /// Create a new GnomeSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> GnomeSort<T> {
+    /// Create a GnomeSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            current_pos: 1,
+            frontier: 1,
+            swap_due: false,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+}
+
+impl Default for GnomeSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> GnomeSort<T> {
+    /// Walk the cursor until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        let n = self.data.len();
+        loop {
+            let pos = self.current_pos;
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(pos - 1, pos);
+                self.origins.swap(pos - 1, pos);
+                self.moves += 1;
+                self.writes += 2;
+                self.swap_due = false;
+                self.current_pos -= 1;
+                continue;
+            }
+
+            if pos >= n {
+                return Some(());
+            }
+            if pos == 0 {
+                // At the front there is nothing to compare with
+                self.current_pos = 1;
+                continue;
+            }
+
+            budget.compare()?;
+            self.comparisons += 1;
+            self.swap_due = self.compare.greater(&self.data[pos - 1], &self.data[pos]);
+            if !self.swap_due {
+                self.current_pos += 1;
+                self.frontier = self.frontier.max(self.current_pos);
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for GnomeSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        if !self.complete && self.current_pos > 0 && self.current_pos < self.data.len() {
+            markers.cursors = vec![self.current_pos - 1, self.current_pos];
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else if self.current_pos < self.frontier {
+                format!("Stepping back at {}, sorted up to {}", self.current_pos, self.frontier)
+            } else {
+                format!("Position {}", self.current_pos)
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                (self.frontier as f32 / self.data.len() as f32).clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.current_pos = 1;
+        self.frontier = 1;
+        self.swap_due = false;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+    }
+
+    fn name(&self) -> &str {
+        "Gnome Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Report size of the data array in bytes
+        self.data.len() * std::mem::size_of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
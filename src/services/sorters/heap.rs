@@ -1,8 +1,29 @@
 //! Heap Sort implementation
+//!
+//! Heaps may have two, three or four children per node. Wider heaps are
+//! shallower, so sifting makes fewer moves but compares more children per
+//! level. The bottom-up sift first follows the larger children down to a
+//! leaf and then climbs back to the sifted element's place, saving most of
+//! the comparisons against the element itself.
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
 
+/// Lane options understood by [`HeapSort::with_option`]
+pub const OPTIONS: [&str; 5] = ["2ary", "3ary", "4ary", "top-down", "bottom-up"];
+
+/// How an element is sifted down the heap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SiftDown {
+    /// Compare the element with all its children on every level
+    #[default]
+    TopDown,
+    /// Follow the larger children to a leaf, then climb back up (Wegener)
+    BottomUp,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum HeapSortState {
     BuildHeap,
@@ -11,71 +32,260 @@ enum HeapSortState {
 
 /// Heap Sort algorithm implementation
 #[derive(Debug)]
-pub struct HeapSort {
-    data: Vec<i32>,
+pub struct HeapSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
     heap_size: usize,
     current_index: usize,
     state: HeapSortState,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
+    /// Sift started by an earlier step and not yet finished
+    sift: Option<Sift>,
+    /// Children per heap node
+    arity: usize,
+    sift_down: SiftDown,
+    name: String,
+}
+
+/// Sift-down in progress, kept between steps so one sift can span several
+#[derive(Debug, Clone, Copy)]
+struct Sift {
+    /// Node the sifted element started at
+    root: usize,
+    /// Node the sift has reached: the sifted element's position top-down;
+    /// bottom-up, the end of the larger-child path, then while climbing the
+    /// candidate for the element's place
+    node: usize,
+    /// Largest of the elements compared so far on this level
+    largest: usize,
+    /// Next child of `node` to compare
+    next_child: usize,
+    /// Bottom-up only: the path reached a leaf and the sift is climbing back
+    climbing: bool,
+    /// Bottom-up only: once the element's place is found, where it sits
+    /// while the path above that place shifts up
+    hole: Option<usize>,
 }
 
 impl HeapSort {
     /// Create a new HeapSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> HeapSort<T> {
+    /// Create a HeapSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
             heap_size: 0,
             current_index: 0,
             state: HeapSortState::BuildHeap,
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
+            sift: None,
+            arity: 2,
+            sift_down: SiftDown::default(),
+            name: "Heap Sort".to_string(),
         }
     }
 
-    fn heapify(&mut self, mut root: usize, budget: &mut usize) -> bool {
-        // Check if we have enough budget for worst case (2 * log(n) comparisons)
-        let estimated_comparisons = ((self.heap_size as f32).log2().ceil() * 2.0) as usize;
-        if *budget < estimated_comparisons {
-            return false; // Not enough budget to complete heapify
+    /// Use a heap with `arity` children per node
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arity` is less than 2.
+    pub fn with_arity(mut self, arity: usize) -> Self {
+        assert!(arity >= 2, "a heap needs at least two children per node");
+        self.arity = arity;
+        self.update_name();
+        self
+    }
+
+    /// Sift elements down with `sift`
+    pub fn with_sift(mut self, sift: SiftDown) -> Self {
+        self.sift_down = sift;
+        self.update_name();
+        self
+    }
+
+    /// Apply a lane option from [`OPTIONS`], or `None` if it is unknown
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        Some(match option {
+            "2ary" => self.with_arity(2),
+            "3ary" => self.with_arity(3),
+            "4ary" => self.with_arity(4),
+            "top-down" => self.with_sift(SiftDown::TopDown),
+            "bottom-up" => self.with_sift(SiftDown::BottomUp),
+            _ => return None,
+        })
+    }
+
+    /// Get the number of children per heap node
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// Get the sift-down variant
+    pub fn sift_down(&self) -> SiftDown {
+        self.sift_down
+    }
+
+    /// Name the sorter after every non-default choice, e.g. `Heap Sort (4-ary, bottom-up)`
+    fn update_name(&mut self) {
+        let mut parts = Vec::new();
+        if self.arity != 2 {
+            parts.push(format!("{}-ary", self.arity));
         }
-        
-        loop {
-            let mut largest = root;
-            let left = 2 * root + 1;
-            let right = 2 * root + 2;
+        if self.sift_down == SiftDown::BottomUp {
+            parts.push("bottom-up".to_string());
+        }
+        self.name = if parts.is_empty() {
+            "Heap Sort".to_string()
+        } else {
+            format!("Heap Sort ({})", parts.join(", "))
+        };
+    }
 
-            // Compare with left child
-            if left < self.heap_size {
-                *budget = budget.saturating_sub(1);
-                self.comparisons += 1;
-                if self.data[left] > self.data[largest] {
-                    largest = left;
-                }
+    /// One past the last node with children, where heap building starts
+    fn build_start(&self) -> usize {
+        (self.data.len() + self.arity).saturating_sub(2) / self.arity
+    }
+
+    /// Children of `node` still inside the heap
+    fn children(&self, node: usize) -> std::ops::Range<usize> {
+        let first = (self.arity * node + 1).min(self.heap_size);
+        first..(first + self.arity).min(self.heap_size)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+        self.origins.swap(a, b);
+        self.moves += 1;
+        self.writes += 2;
+    }
+
+    /// Start sifting the element at `root` down the heap
+    fn start_sift(&mut self, root: usize) {
+        self.sift = Some(self.enter(Sift {
+            root,
+            node: root,
+            largest: root,
+            next_child: 0,
+            climbing: false,
+            hole: None,
+        }));
+    }
+
+    /// Point `sift` at the children of `sift.node`, before any are compared
+    fn enter(&self, mut sift: Sift) -> Sift {
+        let children = self.children(sift.node);
+        match self.sift_down {
+            SiftDown::TopDown => {
+                sift.largest = sift.node;
+                sift.next_child = children.start;
+            }
+            // The first child is the larger one until another beats it
+            SiftDown::BottomUp if children.is_empty() => sift.climbing = true,
+            SiftDown::BottomUp => {
+                sift.largest = children.start;
+                sift.next_child = children.start + 1;
+            }
+        }
+        sift
+    }
+
+    /// Continue the sift in progress, or `None` if the budget runs out
+    /// before it finishes
+    fn continue_sift(&mut self, budget: &mut Budget) -> Option<()> {
+        let Some(mut sift) = self.sift.take() else {
+            return Some(());
+        };
+
+        let finished = match self.sift_down {
+            SiftDown::TopDown => self.sift_top_down(&mut sift, budget),
+            SiftDown::BottomUp => self.sift_bottom_up(&mut sift, budget),
+        };
+        if finished.is_none() {
+            self.sift = Some(sift);
+        }
+        finished
+    }
+
+    /// Compare the children of `sift.node` not yet compared, or `None` if
+    /// the budget runs out first
+    fn compare_children(&mut self, sift: &mut Sift, budget: &mut Budget) -> Option<()> {
+        let end = self.children(sift.node).end;
+        while sift.next_child < end {
+            budget.compare()?;
+            self.comparisons += 1;
+            if self.compare.greater(&self.data[sift.next_child], &self.data[sift.largest]) {
+                sift.largest = sift.next_child;
+            }
+            sift.next_child += 1;
+        }
+        Some(())
+    }
+
+    fn sift_top_down(&mut self, sift: &mut Sift, budget: &mut Budget) -> Option<()> {
+        loop {
+            // Compare every child with the largest element seen so far
+            self.compare_children(sift, budget)?;
+            if sift.largest == sift.node {
+                return Some(()); // Heap property satisfied
             }
 
-            // Compare with right child  
-            if right < self.heap_size {
-                *budget = budget.saturating_sub(1);
+            // Swap with the largest child and continue from there
+            budget.shift()?;
+            self.swap(sift.node, sift.largest);
+            sift.node = sift.largest;
+            *sift = self.enter(*sift);
+        }
+    }
+
+    fn sift_bottom_up(&mut self, sift: &mut Sift, budget: &mut Budget) -> Option<()> {
+        // Follow the larger children down to a leaf, without looking at the root
+        while !sift.climbing {
+            self.compare_children(sift, budget)?;
+            sift.node = sift.largest;
+            *sift = self.enter(*sift);
+        }
+
+        // Climb back to the deepest element on the path not smaller than the root
+        if sift.hole.is_none() {
+            while sift.node != sift.root {
+                budget.compare()?;
                 self.comparisons += 1;
-                if self.data[right] > self.data[largest] {
-                    largest = right;
+                if !self.compare.less(&self.data[sift.node], &self.data[sift.root]) {
+                    break;
                 }
+                sift.node = (sift.node - 1) / self.arity;
             }
+            sift.hole = Some(sift.root);
+        }
 
-            // If largest is not root, swap and continue
-            if largest != root {
-                self.data.swap(root, largest);
-                self.moves += 1;
-                root = largest; // Continue with the child
-            } else {
-                break; // Heap property satisfied
+        // Shift the path up by one level, carrying the root down into place
+        while let Some(hole) = sift.hole
+            && hole != sift.node
+        {
+            let mut next = sift.node;
+            while (next - 1) / self.arity != hole {
+                next = (next - 1) / self.arity;
             }
+            budget.shift()?;
+            self.swap(hole, next);
+            sift.hole = Some(next);
         }
-
-        true // Heapify complete
+        Some(())
     }
 }
 
@@ -85,70 +295,63 @@ impl Default for HeapSort {
     }
 }
 
-impl Sorter for HeapSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
+impl<T: Element> HeapSort<T> {
+    /// Build the heap and extract from it until sorted, or `None` if the
+    /// budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
+            match self.state {
+                HeapSortState::BuildHeap => {
+                    if self.sift.is_none() {
+                        self.start_sift(self.current_index - 1);
+                    }
+                    self.continue_sift(budget)?;
 
-        let initial_comparisons = self.comparisons;
-        let initial_moves = self.moves;
-        let mut remaining_budget = budget;
-
-        match self.state {
-            HeapSortState::BuildHeap => {
-                while remaining_budget > 0 && self.current_index > 0 {
-                    let finished = self.heapify(self.current_index - 1, &mut remaining_budget);
-                    
-                    if finished {
-                        if self.current_index == 1 {
-                            // Heap building complete, start extraction
-                            self.state = HeapSortState::ExtractMax;
-                            self.current_index = self.data.len();
-                            break;
-                        } else {
-                            self.current_index -= 1;
-                        }
+                    if self.current_index == 1 {
+                        // Heap building complete, start extraction
+                        self.state = HeapSortState::ExtractMax;
+                        self.current_index = self.data.len();
                     } else {
-                        break; // Need more budget
+                        self.current_index -= 1;
                     }
                 }
-            }
-            HeapSortState::ExtractMax => {
-                while remaining_budget > 0 && self.heap_size > 1 {
-                    // Move current maximum to the end
-                    self.data.swap(0, self.heap_size - 1);
-                    self.moves += 1;
-                    self.heap_size -= 1;
-                    
-                    // Heapify the reduced heap
-                    let finished = self.heapify(0, &mut remaining_budget);
-                    
-                    if !finished {
-                        // Restore heap_size since we couldn't complete the heapify
-                        self.heap_size += 1;
-                        self.data.swap(0, self.heap_size - 1);
-                        self.moves += 1;
-                        break; // Need more budget
+                HeapSortState::ExtractMax => {
+                    if self.sift.is_none() {
+                        // Move current maximum to the end and sift the new root
+                        budget.shift()?;
+                        self.swap(0, self.heap_size - 1);
+                        self.heap_size -= 1;
+                        self.start_sift(0);
                     }
-                    
+                    self.continue_sift(budget)?;
+
                     if self.heap_size <= 1 {
-                        self.complete = true;
-                        break;
+                        return Some(());
                     }
                 }
             }
         }
+    }
 
-        StepResult {
-            comparisons_used: (self.comparisons - initial_comparisons) as usize,
-            moves_made: (self.moves - initial_moves) as usize,
-            continued: !self.complete,
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for HeapSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -182,6 +385,8 @@ impl Sorter for HeapSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
@@ -206,8 +411,8 @@ impl Sorter for HeapSort {
             } else {
                 match self.state {
                     HeapSortState::BuildHeap => {
-                        let total_build_steps = (self.data.len() / 2).max(1) as f32;
-                        let completed_steps = (self.data.len() / 2) as f32 - self.current_index as f32;
+                        let total_build_steps = self.build_start().max(1) as f32;
+                        let completed_steps = self.build_start() as f32 - self.current_index as f32;
                         let progress = (completed_steps / total_build_steps).max(0.0).min(0.5);
                         if progress.is_finite() { progress } else { 0.0 }
                     }
@@ -225,27 +430,34 @@ impl Sorter for HeapSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
+        self.origins = (0..self.data.len()).collect();
         self.heap_size = self.data.len();
-        self.current_index = self.data.len() / 2; // Start from last non-leaf node
+        self.current_index = self.build_start(); // Start from last non-leaf node
         self.state = HeapSortState::BuildHeap;
+        self.sift = None;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
     }
 
     fn name(&self) -> &str {
-        "Heap Sort"
+        &self.name
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
         // Report size of the data array in bytes
-        self.data.len() * std::mem::size_of::<i32>()
+        self.data.len() * std::mem::size_of::<T>()
     }
 
     fn as_any(&self) -> &dyn Any {
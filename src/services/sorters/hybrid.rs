@@ -0,0 +1,479 @@
+//! Resumable building blocks shared by the quicksorts
+//!
+//! Each operation keeps its cursors between calls and pays for every
+//! comparison and swap from a [`Budget`], so `QuickSort`, `IntroSort` and
+//! `PdqSort` can pause anywhere, even between a comparison and the swap it
+//! calls for. Operations return `None` when the budget runs out before they
+//! finish.
+
+use crate::models::element::Comparator;
+use crate::services::sorters::budget::Budget;
+use std::cmp::Ordering;
+
+/// Array being sorted, with origins and operation counters
+#[derive(Debug)]
+pub(crate) struct Workspace<T> {
+    pub data: Vec<T>,
+    pub origins: Vec<usize>,
+    pub compare: Comparator<T>,
+    pub comparisons: u64,
+    pub moves: u64,
+    pub writes: u64,
+}
+
+impl<T> Workspace<T> {
+    pub fn new(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            origins: Vec::new(),
+            compare,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+        }
+    }
+
+    pub fn reset(&mut self, data: Vec<T>) {
+        self.origins = (0..data.len()).collect();
+        self.data = data;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+    }
+
+    /// Check `data[a] < data[b]`, or `None` if the budget is spent
+    pub fn less(&mut self, a: usize, b: usize, budget: &mut Budget) -> Option<bool> {
+        budget.compare()?;
+        self.comparisons += 1;
+        Some(self.compare.less(&self.data[a], &self.data[b]))
+    }
+
+    /// Compare `data[a]` with `data[b]`, or `None` if the budget is spent
+    pub fn compare(&mut self, a: usize, b: usize, budget: &mut Budget) -> Option<Ordering> {
+        budget.compare()?;
+        self.comparisons += 1;
+        Some(self.compare.compare(&self.data[a], &self.data[b]))
+    }
+
+    /// Swap `data[a]` and `data[b]`, or `None` if the budget is spent
+    ///
+    /// Swapping an element with itself is free.
+    pub fn swap(&mut self, a: usize, b: usize, budget: &mut Budget) -> Option<()> {
+        if a != b {
+            budget.shift()?;
+            self.data.swap(a, b);
+            self.origins.swap(a, b);
+            self.moves += 1;
+            self.writes += 2;
+        }
+        Some(())
+    }
+}
+
+/// Fixed sequence of swaps, such as pdqsort's pattern-breaking swaps
+#[derive(Debug, Clone)]
+pub(crate) struct Swaps {
+    pairs: Vec<(usize, usize)>,
+    next: usize,
+}
+
+impl Swaps {
+    pub fn new(pairs: Vec<(usize, usize)>) -> Self {
+        Self { pairs, next: 0 }
+    }
+
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<()> {
+        while let Some(&(a, b)) = self.pairs.get(self.next) {
+            ws.swap(a, b, budget)?;
+            self.next += 1;
+        }
+        Some(())
+    }
+}
+
+/// Sequence of compare-and-swaps, used for median-of-three and ninther
+#[derive(Debug, Clone)]
+pub(crate) struct Sort2Chain {
+    pairs: Vec<(usize, usize)>,
+    next: usize,
+    /// The pair at `next` is out of order but not yet swapped
+    swap_due: bool,
+}
+
+impl Sort2Chain {
+    /// Sort the elements at `a`, `b` and `c` so the median lands on `b`
+    pub fn sort3(triples: &[(usize, usize, usize)]) -> Self {
+        let pairs = triples
+            .iter()
+            .flat_map(|&(a, b, c)| [(a, b), (b, c), (a, b)])
+            .collect();
+        Self { pairs, next: 0, swap_due: false }
+    }
+
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<()> {
+        while let Some(&(a, b)) = self.pairs.get(self.next) {
+            if !self.swap_due {
+                self.swap_due = ws.less(b, a, budget)?;
+            }
+            if self.swap_due {
+                ws.swap(a, b, budget)?;
+                self.swap_due = false;
+            }
+            self.next += 1;
+        }
+        Some(())
+    }
+}
+
+/// Side that keys equal to the pivot go to during a partition
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Ties {
+    Left,
+    Right,
+    /// Both scans stop on equal keys, as in Hoare's scheme
+    Split,
+}
+
+/// Partition of `[begin, end)` around the pivot at `begin`
+///
+/// The pivot ends up at the returned index.
+#[derive(Debug, Clone)]
+pub(crate) struct Partition {
+    pub begin: usize,
+    /// First element not yet known to belong left
+    pub i: usize,
+    /// Last element not yet known to belong right
+    pub j: usize,
+    scanning_right: bool,
+    /// Both scans stopped and the elements at `i` and `j` are not yet swapped
+    swap_due: bool,
+    swapped: bool,
+    ties: Ties,
+}
+
+/// Where a finished partition left the pivot
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PartitionResult {
+    pub pivot: usize,
+    /// No element had to be swapped across the pivot
+    pub already_partitioned: bool,
+}
+
+impl Partition {
+    pub fn new(begin: usize, end: usize, ties: Ties) -> Self {
+        Self {
+            begin,
+            i: begin + 1,
+            j: end - 1,
+            scanning_right: false,
+            swap_due: false,
+            swapped: false,
+            ties,
+        }
+    }
+
+    pub fn ties(&self) -> Ties {
+        self.ties
+    }
+
+    /// Whether the left scan can step over `index`
+    fn stays_left<T>(&self, ws: &mut Workspace<T>, index: usize, budget: &mut Budget) -> Option<bool> {
+        match self.ties {
+            Ties::Left => ws.less(self.begin, index, budget).map(|greater| !greater),
+            Ties::Right | Ties::Split => ws.less(index, self.begin, budget),
+        }
+    }
+
+    /// Whether the right scan can step over `index`
+    fn stays_right<T>(&self, ws: &mut Workspace<T>, index: usize, budget: &mut Budget) -> Option<bool> {
+        match self.ties {
+            Ties::Right => ws.less(index, self.begin, budget).map(|smaller| !smaller),
+            Ties::Left | Ties::Split => ws.less(self.begin, index, budget),
+        }
+    }
+
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<PartitionResult> {
+        loop {
+            if self.swap_due {
+                ws.swap(self.i, self.j, budget)?;
+                self.swap_due = false;
+                self.swapped = true;
+                self.i += 1;
+                self.j -= 1;
+                self.scanning_right = false;
+            }
+
+            if !self.scanning_right {
+                while self.i <= self.j && self.stays_left(ws, self.i, budget)? {
+                    self.i += 1;
+                }
+                self.scanning_right = true;
+            }
+            while self.i <= self.j && self.stays_right(ws, self.j, budget)? {
+                self.j -= 1;
+            }
+            if self.i > self.j {
+                break;
+            }
+            self.swap_due = true;
+        }
+
+        let pivot = self.i - 1;
+        ws.swap(self.begin, pivot, budget)?;
+        Some(PartitionResult {
+            pivot,
+            already_partitioned: !self.swapped,
+        })
+    }
+}
+
+/// Lomuto partition of `[begin, end)` around the pivot at `end - 1`
+///
+/// Keys equal to the pivot go left. The pivot ends up at the returned index.
+#[derive(Debug, Clone)]
+pub(crate) struct Lomuto {
+    /// End of the elements known to belong left
+    pub i: usize,
+    /// Next element to compare with the pivot
+    pub j: usize,
+    pivot: usize,
+    /// The element at `j` belongs left but is not yet swapped there
+    swap_due: bool,
+}
+
+impl Lomuto {
+    pub fn new(begin: usize, end: usize) -> Self {
+        Self {
+            i: begin,
+            j: begin,
+            pivot: end - 1,
+            swap_due: false,
+        }
+    }
+
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<usize> {
+        while self.j < self.pivot {
+            if !self.swap_due {
+                self.swap_due = !ws.less(self.pivot, self.j, budget)?;
+            }
+            if self.swap_due {
+                ws.swap(self.i, self.j, budget)?;
+                self.swap_due = false;
+                self.i += 1;
+            }
+            self.j += 1;
+        }
+        ws.swap(self.i, self.pivot, budget)?;
+        Some(self.i)
+    }
+}
+
+/// Dutch national flag partition of `[begin, end)` around the pivot at `begin`
+///
+/// Splits the range into keys smaller than, equal to and greater than the
+/// pivot, using one three-way comparison per element.
+#[derive(Debug, Clone)]
+pub(crate) struct ThreeWay {
+    /// First key equal to the pivot
+    pub lt: usize,
+    /// Next element to compare
+    pub i: usize,
+    /// First key known to be greater than the pivot
+    pub gt: usize,
+    /// How the element at `i` compared, while its swap is not yet paid for
+    due: Option<Ordering>,
+}
+
+impl ThreeWay {
+    pub fn new(begin: usize, end: usize) -> Self {
+        Self {
+            lt: begin,
+            i: begin + 1,
+            gt: end,
+            due: None,
+        }
+    }
+
+    /// Returns the range `[lt, gt)` of keys equal to the pivot
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<(usize, usize)> {
+        while self.i < self.gt {
+            // `data[lt]` always holds a key equal to the pivot
+            let ordering = match self.due {
+                Some(ordering) => ordering,
+                None => ws.compare(self.i, self.lt, budget)?,
+            };
+            self.due = Some(ordering);
+            match ordering {
+                Ordering::Less => {
+                    ws.swap(self.lt, self.i, budget)?;
+                    self.lt += 1;
+                    self.i += 1;
+                }
+                Ordering::Greater => {
+                    ws.swap(self.i, self.gt - 1, budget)?;
+                    self.gt -= 1;
+                }
+                Ordering::Equal => self.i += 1,
+            }
+            self.due = None;
+        }
+        Some((self.lt, self.gt))
+    }
+}
+
+/// Insertion sort of `[begin, end)`, optionally giving up after `limit` moves
+#[derive(Debug, Clone)]
+pub(crate) struct Insertion {
+    pub begin: usize,
+    pub end: usize,
+    /// Element being inserted
+    pub i: usize,
+    /// Current position of that element
+    pub j: usize,
+    limit: Option<usize>,
+    moved: usize,
+    /// The element at `j` belongs before its neighbour but is not yet swapped
+    swap_due: bool,
+}
+
+impl Insertion {
+    pub fn new(begin: usize, end: usize) -> Self {
+        Self {
+            begin,
+            end,
+            i: begin + 1,
+            j: begin + 1,
+            limit: None,
+            moved: 0,
+            swap_due: false,
+        }
+    }
+
+    /// Insertion sort that bails out once more than `limit` elements moved
+    pub fn partial(begin: usize, end: usize, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new(begin, end)
+        }
+    }
+
+    /// Returns `Some(false)` if a partial sort gave up, leaving the range unsorted
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<bool> {
+        while self.i < self.end {
+            if !self.swap_due {
+                self.swap_due = self.j > self.begin && ws.less(self.j, self.j - 1, budget)?;
+            }
+            if self.swap_due {
+                ws.swap(self.j, self.j - 1, budget)?;
+                self.swap_due = false;
+                self.j -= 1;
+                continue;
+            }
+
+            self.moved += self.i - self.j;
+            self.i += 1;
+            self.j = self.i;
+            if self.limit.is_some_and(|limit| self.moved > limit) {
+                return Some(false);
+            }
+        }
+        Some(true)
+    }
+}
+
+/// Heap sort of `[begin, end)`
+#[derive(Debug, Clone)]
+pub(crate) struct HeapRange {
+    pub begin: usize,
+    /// Elements still in the heap; the rest of the range is sorted
+    pub heap_size: usize,
+    /// Next root to sift while building the heap
+    next_build: usize,
+    sift: Option<Sift>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sift {
+    root: usize,
+    /// Larger child, once the two children have been compared
+    larger: Option<usize>,
+    /// The root is smaller than its larger child but not yet swapped with it
+    swap_due: bool,
+}
+
+impl Sift {
+    fn new(root: usize) -> Self {
+        Self { root, larger: None, swap_due: false }
+    }
+}
+
+impl HeapRange {
+    pub fn new(begin: usize, end: usize) -> Self {
+        let len = end - begin;
+        Self {
+            begin,
+            heap_size: len,
+            next_build: len / 2,
+            sift: None,
+        }
+    }
+
+    pub fn run<T>(&mut self, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<()> {
+        loop {
+            if let Some(sift) = self.sift {
+                self.sift_down(sift, ws, budget)?;
+                self.sift = None;
+            }
+
+            if self.next_build > 0 {
+                self.next_build -= 1;
+                self.sift = Some(Sift::new(self.next_build));
+            } else if self.heap_size > 1 {
+                ws.swap(self.begin, self.begin + self.heap_size - 1, budget)?;
+                self.heap_size -= 1;
+                self.sift = Some(Sift::new(0));
+            } else {
+                return Some(());
+            }
+        }
+    }
+
+    fn sift_down<T>(&mut self, mut sift: Sift, ws: &mut Workspace<T>, budget: &mut Budget) -> Option<()> {
+        loop {
+            let left = 2 * sift.root + 1;
+            if left >= self.heap_size {
+                return Some(());
+            }
+
+            let larger = match sift.larger {
+                Some(larger) => larger,
+                None if left + 1 < self.heap_size => {
+                    let right_larger = ws.less(self.begin + left, self.begin + left + 1, budget);
+                    let Some(right_larger) = right_larger else {
+                        self.sift = Some(sift);
+                        return None;
+                    };
+                    if right_larger { left + 1 } else { left }
+                }
+                None => left,
+            };
+            sift.larger = Some(larger);
+
+            if !sift.swap_due {
+                let Some(smaller_root) = ws.less(self.begin + sift.root, self.begin + larger, budget) else {
+                    self.sift = Some(sift);
+                    return None;
+                };
+                if !smaller_root {
+                    return Some(());
+                }
+                sift.swap_due = true;
+            }
+            if ws.swap(self.begin + sift.root, self.begin + larger, budget).is_none() {
+                self.sift = Some(sift);
+                return None;
+            }
+            sift = Sift::new(larger);
+        }
+    }
+}
@@ -1,31 +1,50 @@
 //! Insertion Sort implementation
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
 
 /// Insertion Sort algorithm implementation
 #[derive(Debug)]
-pub struct InsertionSort {
-    data: Vec<i32>,
+pub struct InsertionSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
     current_index: usize,
     insert_pos: usize,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
     in_insertion: bool,
+    /// The element at `insert_pos` belongs before its neighbour but is not
+    /// yet swapped
+    swap_due: bool,
 }
 
 impl InsertionSort {
     /// Create a new InsertionSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> InsertionSort<T> {
+    /// Create a InsertionSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
             current_index: 1,
             insert_pos: 0,
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
             in_insertion: false,
+            swap_due: false,
         }
     }
 }
@@ -36,58 +55,65 @@ impl Default for InsertionSort {
     }
 }
 
-impl Sorter for InsertionSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
-
-        let mut comparisons_used = 0;
-        let mut moves_made = 0;
-
-        while comparisons_used < budget && !self.complete {
+impl<T: Element> InsertionSort<T> {
+    /// Insert elements until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
             if !self.in_insertion {
                 if self.current_index >= self.data.len() {
-                    self.complete = true;
-                    break;
+                    return Some(());
                 }
-                
+
                 self.insert_pos = self.current_index;
                 self.in_insertion = true;
             }
 
-            if self.in_insertion && self.insert_pos > 0 {
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(self.insert_pos, self.insert_pos - 1);
+                self.origins.swap(self.insert_pos, self.insert_pos - 1);
+                self.moves += 1;
+                self.writes += 2;
+                self.insert_pos -= 1;
+                self.swap_due = false;
+            } else if self.insert_pos > 0 {
                 // Compare with previous element
-                comparisons_used += 1;
+                budget.compare()?;
                 self.comparisons += 1;
 
-                if self.data[self.insert_pos] < self.data[self.insert_pos - 1] {
-                    // Swap elements
-                    self.data.swap(self.insert_pos, self.insert_pos - 1);
-                    moves_made += 1;
-                    self.moves += 1;
-                    self.insert_pos -= 1;
-                } else {
+                self.swap_due = self.compare.less(&self.data[self.insert_pos], &self.data[self.insert_pos - 1]);
+                if !self.swap_due {
                     // Found correct position
                     self.in_insertion = false;
                     self.current_index += 1;
                 }
             } else {
-                // Reached beginning of array or not in insertion
+                // Reached beginning of array
                 self.in_insertion = false;
                 self.current_index += 1;
             }
         }
+    }
 
-        StepResult {
-            comparisons_used,
-            moves_made,
-            continued: !self.complete,
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for InsertionSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -111,6 +137,8 @@ impl Sorter for InsertionSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
@@ -131,27 +159,34 @@ impl Sorter for InsertionSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
+        self.origins = (0..self.data.len()).collect();
         self.current_index = 1;
         self.insert_pos = 0;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
         self.in_insertion = false;
+        self.swap_due = false;
     }
 
     fn name(&self) -> &str {
         "Insertion Sort"
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
         // Report size of the data array in bytes
-        self.data.len() * std::mem::size_of::<i32>()
+        self.data.len() * std::mem::size_of::<T>()
     }
 
     fn as_any(&self) -> &dyn Any {
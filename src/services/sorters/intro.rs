@@ -0,0 +1,266 @@
+//! Intro Sort implementation
+//!
+//! Median-of-three quicksort that switches to heap sort for any range left
+//! once the recursion depth exceeds `2 * log2(n)`, and to insertion sort for
+//! small ranges, as in C++ `std::sort`.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use crate::services::sorters::hybrid::{HeapRange, Insertion, Partition, Sort2Chain, Ties, Workspace};
+use std::any::Any;
+
+/// Ranges up to this size are insertion sorted
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Pending range `[low, high)` with its remaining depth budget
+#[derive(Debug, Clone)]
+struct StackFrame {
+    low: usize,
+    high: usize,
+    depth: usize,
+}
+
+/// Operation in progress on the frame being processed
+#[derive(Debug, Clone)]
+enum IntroOp {
+    /// Median-of-three pivot selection, leaving the pivot at `mid`
+    Median { chain: Sort2Chain, mid: usize },
+    Partition(Partition),
+    Insertion(Insertion),
+    /// Heap sort after the depth limit was hit
+    Heap(HeapRange),
+}
+
+/// Intro Sort algorithm implementation
+#[derive(Debug)]
+pub struct IntroSort<T = i32> {
+    ws: Workspace<T>,
+    stack: Vec<StackFrame>,
+    current: Option<(StackFrame, IntroOp)>,
+    heap_fallbacks: usize,
+    complete: bool,
+    max_stack: usize,
+}
+
+impl IntroSort {
+    /// Create a new IntroSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> IntroSort<T> {
+    /// Create an IntroSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            ws: Workspace::new(compare),
+            stack: Vec::new(),
+            current: None,
+            heap_fallbacks: 0,
+            complete: false,
+            max_stack: 0,
+        }
+    }
+
+    /// Pick the operation that starts work on `frame`
+    fn begin_frame(&mut self, frame: &StackFrame) -> IntroOp {
+        let size = frame.high - frame.low;
+        if size <= INSERTION_THRESHOLD {
+            IntroOp::Insertion(Insertion::new(frame.low, frame.high))
+        } else if frame.depth == 0 {
+            self.heap_fallbacks += 1;
+            IntroOp::Heap(HeapRange::new(frame.low, frame.high))
+        } else {
+            let mid = frame.low + size / 2;
+            IntroOp::Median {
+                chain: Sort2Chain::sort3(&[(frame.low, mid, frame.high - 1)]),
+                mid,
+            }
+        }
+    }
+
+    /// Advance `op` on `frame`, returning the follow-up operation if any
+    fn advance(&mut self, frame: &StackFrame, op: &mut IntroOp, budget: &mut Budget) -> Option<Option<IntroOp>> {
+        match op {
+            IntroOp::Median { chain, mid } => {
+                chain.run(&mut self.ws, budget)?;
+                self.ws.swap(frame.low, *mid, budget)?;
+                Some(Some(IntroOp::Partition(Partition::new(frame.low, frame.high, Ties::Split))))
+            }
+            IntroOp::Partition(partition) => {
+                let pivot = partition.run(&mut self.ws, budget)?.pivot;
+                let depth = frame.depth - 1;
+                self.stack.push(StackFrame { low: pivot + 1, high: frame.high, depth });
+                self.stack.push(StackFrame { low: frame.low, high: pivot, depth });
+                self.max_stack = self.max_stack.max(self.stack.len());
+                Some(None)
+            }
+            IntroOp::Insertion(insertion) => insertion.run(&mut self.ws, budget).map(|_| None),
+            IntroOp::Heap(heap) => heap.run(&mut self.ws, budget).map(|_| None),
+        }
+    }
+
+    /// Work through pending ranges until none are left or the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
+            let (frame, mut op) = match self.current.take() {
+                Some(current) => current,
+                None => match self.stack.pop() {
+                    Some(frame) if frame.high - frame.low <= 1 => continue,
+                    Some(frame) => {
+                        let op = self.begin_frame(&frame);
+                        (frame, op)
+                    }
+                    None => return Some(()),
+                },
+            };
+
+            match self.advance(&frame, &mut op, budget) {
+                None => {
+                    self.current = Some((frame, op));
+                    return None;
+                }
+                Some(Some(next)) => self.current = Some((frame, next)),
+                Some(None) => {}
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.ws.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl Default for IntroSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> Sorter<T> for IntroSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        let status_text = match &self.current {
+            _ if self.complete => "Completed".to_string(),
+            Some((frame, IntroOp::Median { mid, .. })) => {
+                markers.cursors = vec![frame.low, *mid, frame.high - 1];
+                format!("Median of three for [{}, {}), depth budget {}", frame.low, frame.high, frame.depth)
+            }
+            Some((frame, IntroOp::Partition(partition))) => {
+                markers.pivot = Some(frame.low);
+                markers.cursors = vec![partition.i.min(frame.high - 1), partition.j];
+                format!("Partitioning range [{}, {}), depth budget {}", frame.low, frame.high, frame.depth)
+            }
+            Some((frame, IntroOp::Insertion(insertion))) => {
+                markers.insertion_range = Some((frame.low, frame.high - 1));
+                markers.cursors = vec![insertion.j.min(frame.high - 1)];
+                format!("Insertion sorting small range [{}, {})", frame.low, frame.high)
+            }
+            Some((frame, IntroOp::Heap(heap))) => {
+                markers.heapsort_range = Some((frame.low, frame.high - 1));
+                markers.heap_boundary = Some(heap.begin + heap.heap_size);
+                markers.cursors = vec![heap.begin];
+                format!(
+                    "Depth limit hit: heap sorting [{}, {}) (fallback #{})",
+                    frame.low, frame.high, self.heap_fallbacks
+                )
+            }
+            None => "Processing".to_string(),
+        };
+
+        // Pending ranges, with the next one to be processed last
+        if !self.complete && markers.cursors.is_empty()
+            && let Some(frame) = self.stack.last() {
+                markers.cursors = vec![frame.low, frame.high.saturating_sub(1)];
+            }
+
+        let progress_hint = if self.complete || self.ws.data.len() <= 1 {
+            1.0
+        } else {
+            // Elements in pending or active ranges are not yet in their final place
+            let active = self.current.as_ref().map_or(0, |(frame, _)| frame.high - frame.low);
+            let pending: usize = self.stack.iter().map(|frame| frame.high - frame.low).sum::<usize>() + active;
+            let progress = 1.0 - pending as f32 / self.ws.data.len() as f32;
+            if progress.is_finite() { progress.min(1.0).max(0.0) } else { 0.0 }
+        };
+
+        Telemetry {
+            total_comparisons: self.ws.comparisons,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.data.len() * std::mem::size_of::<T>()
+                + self.max_stack * std::mem::size_of::<StackFrame>(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint,
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.ws.reset(data);
+        self.stack.clear();
+        self.current = None;
+        self.heap_fallbacks = 0;
+        self.complete = self.ws.data.len() <= 1;
+
+        if !self.complete {
+            let n = self.ws.data.len();
+            self.stack.push(StackFrame {
+                low: 0,
+                high: n,
+                depth: 2 * n.ilog2() as usize,
+            });
+        }
+        self.max_stack = self.stack.len();
+    }
+
+    fn name(&self) -> &str {
+        "Intro Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.ws.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.ws.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + stack memory
+        self.ws.data.len() * std::mem::size_of::<T>()
+            + self.stack.len() * std::mem::size_of::<StackFrame>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,8 +1,47 @@
 //! Merge Sort implementation
+//!
+//! The classic sort splits ranges in half top-down and merges through a
+//! buffer as large as the input. The bottom-up variant merges runs of
+//! doubling width without a split stack, and the natural variant merges the
+//! ascending runs already present in the input. The in-place variant merges
+//! by rotating blocks of the right run into the left run, trading the buffer
+//! for extra moves. Each rotation is three reversals, so it can pause
+//! between any two of its swaps.
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
 
+/// Lane options understood by [`MergeSort::with_option`]
+pub const OPTIONS: [&str; 4] = ["top-down", "bottom-up", "natural", "in-place"];
+
+/// Order in which ranges are merged, and how
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Recursively halve ranges, merging through a full-size buffer
+    #[default]
+    TopDown,
+    /// Merge runs of width 1, 2, 4, ... through a full-size buffer
+    BottomUp,
+    /// Detect ascending runs first, then merge neighbouring runs
+    Natural,
+    /// Merge runs of doubling width by rotations, without a buffer
+    InPlace,
+}
+
+impl MergeStrategy {
+    /// Label used in lane names
+    pub fn label(self) -> &'static str {
+        match self {
+            MergeStrategy::TopDown => "top-down",
+            MergeStrategy::BottomUp => "bottom-up",
+            MergeStrategy::Natural => "natural",
+            MergeStrategy::InPlace => "in-place",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct MergeFrame {
     left: usize,
@@ -14,105 +53,291 @@ struct MergeFrame {
     output_idx: usize,
 }
 
+/// Moves the merge in progress still owes for a comparison it already paid for
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Owed {
+    Nothing,
+    /// Copy the next element of the left or right half to the output
+    Take { from_left: bool },
+    /// Rotating `[temp_left_idx, output_idx)` by reversing `[i, j)`, then
+    /// `[j, k)`, then all of it; `phase` counts the reversals already done
+    /// and `[lo, hi)` is what is left of the current one
+    Reverse { phase: u8, lo: usize, hi: usize },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum MergeState {
     Split,
     Merge,
+    /// In-place merge: `temp_left_idx` is the next left element to place,
+    /// `temp_right_idx` the start of the right run and `output_idx` the end of
+    /// the right elements known to be smaller than it
+    Rotate,
+}
+
+/// Where the bottom-up strategies take their next merge from
+#[derive(Debug, Clone, PartialEq)]
+enum Schedule {
+    /// Split and merge frames are all on the stack
+    TopDown,
+    /// Merging runs of `width` elements, the next pair starting at `next`
+    Widths { width: usize, next: usize },
+    /// Looking for the end of the ascending run starting at `run_start`
+    Scanning { run_start: usize, next: usize },
+    /// Merging neighbouring natural runs pairwise; the next pair starts at
+    /// run `next`, just after the pair on the stack
+    Runs { next: usize },
+    Done,
 }
 
 /// Merge Sort algorithm implementation
 #[derive(Debug)]
-pub struct MergeSort {
-    data: Vec<i32>,
-    temp_buffer: Vec<i32>,
+pub struct MergeSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    temp_buffer: Vec<T>,
+    temp_origins: Vec<usize>,
     stack: Vec<MergeFrame>,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
-    memory_usage: usize,
+    strategy: MergeStrategy,
+    schedule: Schedule,
+    /// Sorted runs left to right (natural strategy)
+    runs: Vec<(usize, usize)>,
+    /// Owed by the frame at the top of the stack
+    owed: Owed,
+    peak_memory: usize,
 }
 
 impl MergeSort {
     /// Create a new MergeSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> MergeSort<T> {
+    /// Create a MergeSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
             temp_buffer: Vec::new(),
+            temp_origins: Vec::new(),
             stack: Vec::new(),
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
-            memory_usage: 0,
+            strategy: MergeStrategy::default(),
+            schedule: Schedule::TopDown,
+            runs: Vec::new(),
+            owed: Owed::Nothing,
+            peak_memory: 0,
         }
     }
 
-    fn merge(&mut self, frame: &mut MergeFrame, budget: &mut usize) -> bool {
-        let mid = frame.mid;
-        let right = frame.right;
+    /// Merge ranges with `strategy`
+    pub fn with_strategy(mut self, strategy: MergeStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Apply a lane option from [`OPTIONS`], or `None` if it is unknown
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        Some(match option {
+            "top-down" => self.with_strategy(MergeStrategy::TopDown),
+            "bottom-up" => self.with_strategy(MergeStrategy::BottomUp),
+            "natural" => self.with_strategy(MergeStrategy::Natural),
+            "in-place" => self.with_strategy(MergeStrategy::InPlace),
+            _ => return None,
+        })
+    }
+
+    /// Get the merge strategy
+    pub fn strategy(&self) -> MergeStrategy {
+        self.strategy
+    }
+
+    /// Buffers, pending frames and natural runs, in bytes
+    fn auxiliary_memory(&self) -> usize {
+        self.temp_buffer.capacity() * std::mem::size_of::<T>()
+            + self.temp_origins.capacity() * std::mem::size_of::<usize>()
+            + self.stack.capacity() * std::mem::size_of::<MergeFrame>()
+            + self.runs.capacity() * std::mem::size_of::<(usize, usize)>()
+    }
+
+    /// Frame merging `[left, mid]` with `[mid + 1, right]`
+    fn merge_frame(&self, left: usize, mid: usize, right: usize) -> MergeFrame {
+        MergeFrame {
+            left,
+            mid,
+            right,
+            state: if self.strategy == MergeStrategy::InPlace {
+                MergeState::Rotate
+            } else {
+                MergeState::Merge
+            },
+            temp_left_idx: left,
+            temp_right_idx: mid + 1,
+            output_idx: if self.strategy == MergeStrategy::InPlace { mid + 1 } else { left },
+        }
+    }
 
-        // Copy data to temp buffer if not already done
-        if frame.temp_left_idx == frame.left {
-            for i in frame.left..=right {
-                self.temp_buffer[i] = self.data[i];
+    /// Get the next merge of a bottom-up strategy, or `None` if there is none
+    /// yet because the budget ran out, or because sorting is done
+    fn next_merge(&mut self, budget: &mut Budget) -> Option<MergeFrame> {
+        let n = self.data.len();
+        loop {
+            match self.schedule {
+                Schedule::TopDown | Schedule::Done => return None,
+                Schedule::Widths { width, next } => {
+                    if width >= n {
+                        self.schedule = Schedule::Done;
+                    } else if next + width >= n {
+                        // The rest is a single run, so this pass is over
+                        self.schedule = Schedule::Widths { width: width * 2, next: 0 };
+                    } else {
+                        let right = (next + 2 * width - 1).min(n - 1);
+                        self.schedule = Schedule::Widths { width, next: right + 1 };
+                        return Some(self.merge_frame(next, next + width - 1, right));
+                    }
+                }
+                Schedule::Scanning { mut run_start, mut next } => {
+                    while next < n {
+                        if budget.compare().is_none() {
+                            self.schedule = Schedule::Scanning { run_start, next };
+                            return None;
+                        }
+                        self.comparisons += 1;
+                        if self.compare.less(&self.data[next], &self.data[next - 1]) {
+                            self.runs.push((run_start, next - 1));
+                            run_start = next;
+                        }
+                        next += 1;
+                    }
+                    self.runs.push((run_start, n - 1));
+                    self.schedule = Schedule::Runs { next: 0 };
+                }
+                Schedule::Runs { next } => {
+                    if self.runs.len() <= 1 {
+                        self.schedule = Schedule::Done;
+                    } else if next + 1 >= self.runs.len() {
+                        self.schedule = Schedule::Runs { next: 0 };
+                    } else {
+                        let (left, mid) = self.runs[next];
+                        let (_, right) = self.runs[next + 1];
+                        self.schedule = Schedule::Runs { next: next + 1 };
+                        return Some(self.merge_frame(left, mid, right));
+                    }
+                }
             }
         }
+    }
 
-        while *budget > 0 && frame.output_idx <= right {
-            if frame.temp_left_idx > mid {
-                // Left half exhausted, copy from right
-                self.data[frame.output_idx] = self.temp_buffer[frame.temp_right_idx];
-                self.moves += 1;
-                frame.temp_right_idx += 1;
-            } else if frame.temp_right_idx > right {
-                // Right half exhausted, copy from left
-                self.data[frame.output_idx] = self.temp_buffer[frame.temp_left_idx];
-                self.moves += 1;
-                frame.temp_left_idx += 1;
-            } else {
-                // Compare and merge
-                *budget -= 1;
-                self.comparisons += 1;
-
-                if self.temp_buffer[frame.temp_left_idx] <= self.temp_buffer[frame.temp_right_idx] {
-                    self.data[frame.output_idx] = self.temp_buffer[frame.temp_left_idx];
-                    frame.temp_left_idx += 1;
-                } else {
-                    self.data[frame.output_idx] = self.temp_buffer[frame.temp_right_idx];
-                    frame.temp_right_idx += 1;
+    /// Merge without a buffer by rotating each block of right elements that
+    /// belongs before the next left element into place
+    fn rotate_merge(&mut self, frame: &mut MergeFrame, budget: &mut Budget) -> bool {
+        while frame.temp_left_idx < frame.temp_right_idx && frame.temp_right_idx <= frame.right {
+            let (i, j) = (frame.temp_left_idx, frame.temp_right_idx);
+
+            if self.owed == Owed::Nothing {
+                // Ties keep the left element first, which keeps the sort stable
+                while frame.output_idx <= frame.right {
+                    if budget.compare().is_none() {
+                        return false;
+                    }
+                    self.comparisons += 1;
+                    if !self.compare.less(&self.data[frame.output_idx], &self.data[i]) {
+                        break;
+                    }
+                    frame.output_idx += 1;
                 }
-                self.moves += 1;
+                self.owed = Owed::Reverse { phase: 0, lo: i, hi: j };
             }
 
-            frame.output_idx += 1;
+            let k = frame.output_idx;
+            if k > j && !self.rotate(frame, budget) {
+                return false;
+            }
+            self.owed = Owed::Nothing;
+
+            // The left element now sits before every remaining right element
+            frame.temp_left_idx = i + (k - j) + 1;
+            frame.temp_right_idx = k;
         }
+        true
+    }
 
-        frame.output_idx > right // Return true if merge is complete
+    /// Continue rotating `[temp_left_idx, output_idx)` so the right block
+    /// comes first, returning whether the rotation finished
+    fn rotate(&mut self, frame: &mut MergeFrame, budget: &mut Budget) -> bool {
+        let (i, j, k) = (frame.temp_left_idx, frame.temp_right_idx, frame.output_idx);
+        while let Owed::Reverse { phase, lo, hi } = self.owed {
+            if lo + 1 < hi {
+                if budget.shift().is_none() {
+                    return false;
+                }
+                self.data.swap(lo, hi - 1);
+                self.origins.swap(lo, hi - 1);
+                self.moves += 1;
+                self.writes += 2;
+                self.owed = Owed::Reverse { phase, lo: lo + 1, hi: hi - 1 };
+            } else {
+                self.owed = match phase {
+                    0 => Owed::Reverse { phase: 1, lo: j, hi: k },
+                    1 => Owed::Reverse { phase: 2, lo: i, hi: k },
+                    _ => Owed::Nothing,
+                };
+            }
+        }
+        true
     }
 }
 
-impl Default for MergeSort {
-    fn default() -> Self {
-        Self::new()
+impl<T: Clone> MergeSort<T> {
+    /// Continue the merge described by `frame`, returning whether it finished
+    fn merge_range(&mut self, frame: &mut MergeFrame, budget: &mut Budget) -> bool {
+        match frame.state {
+            MergeState::Rotate => self.rotate_merge(frame, budget),
+            _ => self.merge(frame, budget),
+        }
     }
-}
 
-impl Sorter for MergeSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
+    /// Run merges handed out by the bottom-up schedule until the budget is spent
+    fn run_schedule(&mut self, budget: &mut Budget) {
+        loop {
+            let mut frame = match self.stack.pop() {
+                Some(frame) => frame,
+                None => match self.next_merge(budget) {
+                    Some(frame) => frame,
+                    None => {
+                        self.complete = self.schedule == Schedule::Done;
+                        return;
+                    }
+                },
             };
-        }
 
-        let initial_comparisons = self.comparisons;
-        let initial_moves = self.moves;
-        let mut remaining_budget = budget;
+            if !self.merge_range(&mut frame, budget) {
+                self.stack.push(frame);
+                return;
+            }
 
-        while remaining_budget > 0 && !self.stack.is_empty() {
-            let mut frame = self.stack.pop().unwrap();
+            // The two natural runs are one run now
+            if let Schedule::Runs { next } = self.schedule {
+                self.runs[next - 1].1 = frame.right;
+                self.runs.remove(next);
+            }
+        }
+    }
 
+    /// Split and merge the frames on the stack until it is empty or the budget is spent
+    fn run_top_down(&mut self, budget: &mut Budget) {
+        while let Some(mut frame) = self.stack.pop() {
             match frame.state {
                 MergeState::Split => {
                     if frame.left < frame.right {
@@ -152,27 +377,99 @@ impl Sorter for MergeSort {
                         });
                     }
                 }
-                MergeState::Merge => {
-                    let finished = self.merge(&mut frame, &mut remaining_budget);
-                    
-                    if !finished {
+                MergeState::Merge | MergeState::Rotate => {
+                    if !self.merge_range(&mut frame, budget) {
                         // Merge not finished, push back to stack
                         self.stack.push(frame);
-                        break;
+                        return;
                     }
                 }
             }
         }
+        self.complete = true;
+    }
+
+    fn merge(&mut self, frame: &mut MergeFrame, budget: &mut Budget) -> bool {
+        let mid = frame.mid;
+        let right = frame.right;
 
-        if self.stack.is_empty() {
-            self.complete = true;
+        // Copy data to temp buffer on the first call for this frame; the left
+        // cursor can stay at `left` across resumed calls, the output cannot
+        if frame.output_idx == frame.left && self.owed == Owed::Nothing {
+            // Only start a merge with budget left to make progress
+            if !budget.can_compare() {
+                return false;
+            }
+            self.temp_buffer[frame.left..=right].clone_from_slice(&self.data[frame.left..=right]);
+            self.temp_origins[frame.left..=right].copy_from_slice(&self.origins[frame.left..=right]);
+            self.writes += (right + 1 - frame.left) as u64;
+        }
+
+        while frame.output_idx <= right {
+            let from_left = match self.owed {
+                Owed::Take { from_left } => from_left,
+                // Left half exhausted, copy from right
+                _ if frame.temp_left_idx > mid => false,
+                // Right half exhausted, copy from left
+                _ if frame.temp_right_idx > right => true,
+                _ => {
+                    if budget.compare().is_none() {
+                        return false;
+                    }
+                    self.comparisons += 1;
+                    // Ties take from the left half, which keeps the sort stable
+                    !self.compare.greater(&self.temp_buffer[frame.temp_left_idx], &self.temp_buffer[frame.temp_right_idx])
+                }
+            };
+
+            self.owed = Owed::Take { from_left };
+            if budget.shift().is_none() {
+                return false;
+            }
+            self.owed = Owed::Nothing;
+
+            let source = if from_left { &mut frame.temp_left_idx } else { &mut frame.temp_right_idx };
+            self.data[frame.output_idx] = self.temp_buffer[*source].clone();
+            self.origins[frame.output_idx] = self.temp_origins[*source];
+            *source += 1;
+            self.moves += 1;
+            self.writes += 1;
+            frame.output_idx += 1;
         }
 
-        StepResult {
-            comparisons_used: (self.comparisons - initial_comparisons) as usize,
-            moves_made: (self.moves - initial_moves) as usize,
-            continued: !self.complete,
+        true
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            if self.schedule == Schedule::TopDown {
+                self.run_top_down(&mut budget);
+            } else {
+                self.run_schedule(&mut budget);
+            }
+            self.peak_memory = self.peak_memory.max(self.auxiliary_memory());
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl Default for MergeSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> Sorter<T> for MergeSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -182,7 +479,25 @@ impl Sorter for MergeSort {
     fn get_telemetry(&self) -> Telemetry {
         let mut markers = Markers::default();
         
+        if let Schedule::Scanning { run_start, next } = self.schedule {
+            markers.runs = self.runs.clone();
+            markers.runs.push((run_start, next - 1));
+            markers.cursors.push(next);
+        } else if self.strategy == MergeStrategy::Natural && !self.complete {
+            markers.runs = self.runs.clone();
+        }
+
         if let Some(frame) = self.stack.last()
+            && frame.state == MergeState::Rotate {
+                markers.merge_runs.push((frame.left, frame.right));
+                markers.cursors.push(frame.temp_left_idx);
+                if frame.temp_right_idx <= frame.right {
+                    markers.cursors.push(frame.temp_right_idx);
+                }
+                if frame.output_idx <= frame.right && frame.output_idx != frame.temp_right_idx {
+                    markers.cursors.push(frame.output_idx);
+                }
+        } else if let Some(frame) = self.stack.last()
             && frame.state == MergeState::Merge {
                 markers.merge_runs.push((frame.left, frame.right));
                 
@@ -201,8 +516,10 @@ impl Sorter for MergeSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
-            memory_current: self.memory_usage,
-            memory_peak: self.memory_usage, // Simplified for stub
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.auxiliary_memory(),
+            memory_peak: self.peak_memory,
             highlights: markers.cursors.clone(),
             markers,
             status_text: if self.complete {
@@ -211,12 +528,21 @@ impl Sorter for MergeSort {
                 match frame.state {
                     MergeState::Split => format!("Splitting range [{}, {}]", frame.left, frame.right),
                     MergeState::Merge => format!("Merging range [{}, {}]", frame.left, frame.right),
+                    MergeState::Rotate => format!("Rotating range [{}, {}] in place", frame.left, frame.right),
                 }
+            } else if let Schedule::Scanning { next, .. } = self.schedule {
+                format!("Scanning for runs ({} found, at {})", self.runs.len(), next)
             } else {
                 "Processing".to_string()
             },
-            progress_hint: if self.data.len() <= 1 {
+            progress_hint: if self.data.len() <= 1 || self.complete {
                 1.0
+            } else if let Schedule::Widths { width, .. } = self.schedule {
+                (width as f32).log2() / (self.data.len() as f32).log2()
+            } else if self.strategy == MergeStrategy::Natural {
+                // Each pass halves the runs; scanning counts as the first pass
+                let passes = (self.data.len() as f32).log2().max(1.0);
+                (1.0 - (self.runs.len().max(1) as f32).log2() / passes).clamp(0.0, 1.0)
             } else {
                 let progress = 1.0 - (self.stack.len() as f32 / (self.data.len() as f32).log2().max(1.0));
                 if progress.is_finite() { progress.min(1.0).max(0.0) } else { 0.0 }
@@ -224,18 +550,31 @@ impl Sorter for MergeSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
-        self.temp_buffer = vec![0; self.data.len()];
-        self.stack.clear();
+        self.origins = (0..self.data.len()).collect();
+        if self.strategy == MergeStrategy::InPlace {
+            self.temp_buffer = Vec::new();
+            self.temp_origins = Vec::new();
+        } else {
+            self.temp_buffer = self.data.clone();
+            self.temp_origins = vec![0; self.data.len()];
+        }
+        self.stack = Vec::new();
+        self.runs = Vec::new();
+        self.owed = Owed::Nothing;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
-        
-        // Calculate memory usage: temp buffer + stack
-        self.memory_usage = self.temp_buffer.len() * std::mem::size_of::<i32>();
+        self.schedule = match self.strategy {
+            _ if self.complete => Schedule::Done,
+            MergeStrategy::TopDown => Schedule::TopDown,
+            MergeStrategy::BottomUp | MergeStrategy::InPlace => Schedule::Widths { width: 1, next: 0 },
+            MergeStrategy::Natural => Schedule::Scanning { run_start: 0, next: 1 },
+        };
 
-        if !self.complete {
+        if self.schedule == Schedule::TopDown {
             self.stack.push(MergeFrame {
                 left: 0,
                 mid: 0,
@@ -245,22 +584,30 @@ impl Sorter for MergeSort {
                 temp_right_idx: 0,
                 output_idx: 0,
             });
-            
-            self.memory_usage += self.stack.capacity() * std::mem::size_of::<MergeFrame>();
         }
+        self.peak_memory = self.auxiliary_memory();
     }
 
     fn name(&self) -> &str {
-        "Merge Sort"
+        match self.strategy {
+            MergeStrategy::TopDown => "Merge Sort",
+            MergeStrategy::BottomUp => "Merge Sort (bottom-up)",
+            MergeStrategy::Natural => "Merge Sort (natural)",
+            MergeStrategy::InPlace => "Merge Sort (in-place)",
+        }
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
-        // Data array + temp buffer + stack
-        self.data.len() * std::mem::size_of::<i32>() + self.memory_usage
+        // Data array + buffers + stack + runs
+        self.data.len() * std::mem::size_of::<T>() + self.auxiliary_memory()
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -7,6 +7,21 @@ pub mod quick;
 pub mod heap;
 pub mod merge;
 pub mod shell;
+pub mod tim;
+pub mod intro;
+pub mod pdq;
+pub mod radix;
+pub mod counting;
+pub mod bucket;
+pub mod cocktail;
+pub mod comb;
+pub mod gnome;
+pub mod odd_even;
+pub mod cycle;
+pub mod network;
+pub(crate) mod budget;
+pub(crate) mod hybrid;
+pub(crate) mod buckets;
 
 pub use bubble::BubbleSort;
 pub use insertion::InsertionSort;
@@ -14,4 +29,16 @@ pub use selection::SelectionSort;
 pub use quick::QuickSort;
 pub use heap::HeapSort;
 pub use merge::MergeSort;
-pub use shell::ShellSort;
\ No newline at end of file
+pub use shell::ShellSort;
+pub use tim::TimSort;
+pub use intro::IntroSort;
+pub use pdq::PdqSort;
+pub use radix::{LsdRadixSort, MsdRadixSort};
+pub use counting::CountingSort;
+pub use bucket::BucketSort;
+pub use cocktail::CocktailSort;
+pub use comb::CombSort;
+pub use gnome::GnomeSort;
+pub use odd_even::OddEvenSort;
+pub use cycle::CycleSort;
+pub use network::NetworkSort;
\ No newline at end of file
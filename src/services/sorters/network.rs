@@ -0,0 +1,343 @@
+//! Sorting network implementations: bitonic sort and Batcher's odd-even
+//! merge sort
+//!
+//! A sorting network is a fixed sequence of comparators chosen without
+//! looking at the data. Comparators are grouped into layers that touch
+//! disjoint pairs, so each layer could run in one parallel step, which is how
+//! GPUs and SIMD units sort. The number of layers is the network's depth.
+//!
+//! Both networks are built for the next power of two and written so every
+//! comparator puts the smaller element at the lower index. Sizes in between
+//! are padded with virtual elements larger than any real one; those never
+//! move, so comparators touching them are simply left out.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Comparator network a [`NetworkSort`] runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Network {
+    /// Bitonic sort: merges sorted halves by comparing mirrored pairs, then
+    /// halving distances
+    #[default]
+    Bitonic,
+    /// Batcher's odd-even merge sort: merges sorted halves by merging their
+    /// even and odd positions; fewer comparators at the same depth
+    OddEvenMerge,
+}
+
+/// One layer of comparators, all of which could run at once
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layer {
+    /// Compare mirrored pairs within each block of `block` elements (bitonic)
+    Flip { block: usize },
+    /// Compare pairs `distance` apart within blocks of twice that (bitonic)
+    HalfClean { distance: usize },
+    /// Compare pairs `distance` apart that lie in the same merge of two
+    /// `run`-element runs (odd-even merge)
+    Merge { run: usize, distance: usize },
+}
+
+impl Layer {
+    /// Comparators of this layer for a network `width` wide, leaving out
+    /// those that touch padding at or beyond `len`
+    fn comparators(self, width: usize, len: usize) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        match self {
+            Layer::Flip { block } => {
+                for base in (0..width).step_by(block) {
+                    pairs.extend((0..block / 2).map(|i| (base + i, base + block - 1 - i)));
+                }
+            }
+            Layer::HalfClean { distance } => {
+                for base in (0..width).step_by(2 * distance) {
+                    pairs.extend((0..distance).map(|i| (base + i, base + i + distance)));
+                }
+            }
+            Layer::Merge { run, distance } => {
+                for start in (distance % run..width - distance).step_by(2 * distance) {
+                    for i in start..(start + distance).min(width - distance) {
+                        if i / (2 * run) == (i + distance) / (2 * run) {
+                            pairs.push((i, i + distance));
+                        }
+                    }
+                }
+            }
+        }
+        pairs.retain(|&(_, high)| high < len);
+        pairs
+    }
+}
+
+/// Layers of `network` for a power-of-two `width`, in the order they run
+fn schedule(network: Network, width: usize) -> Vec<Layer> {
+    let mut layers = Vec::new();
+    match network {
+        Network::Bitonic => {
+            let mut block = 2;
+            while block <= width {
+                layers.push(Layer::Flip { block });
+                let mut distance = block / 4;
+                while distance >= 1 {
+                    layers.push(Layer::HalfClean { distance });
+                    distance /= 2;
+                }
+                block *= 2;
+            }
+        }
+        Network::OddEvenMerge => {
+            let mut run = 1;
+            while run < width {
+                let mut distance = run;
+                while distance >= 1 {
+                    layers.push(Layer::Merge { run, distance });
+                    distance /= 2;
+                }
+                run *= 2;
+            }
+        }
+    }
+    layers
+}
+
+/// Sorting network algorithm implementation
+#[derive(Debug)]
+pub struct NetworkSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    network: Network,
+    /// Layers with at least one comparator for the current size
+    layers: Vec<Layer>,
+    /// Index of the layer running now
+    layer: usize,
+    /// Comparators of the layer running now
+    comparators: Vec<(usize, usize)>,
+    /// Next comparator to run in the current layer
+    position: usize,
+    /// The comparator at `position` found its pair out of order but has not
+    /// swapped it yet
+    swap_due: bool,
+    /// Comparators in the whole network
+    total_comparators: u64,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+impl NetworkSort {
+    /// Create a bitonic sort instance
+    pub fn bitonic() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+
+    /// Create an odd-even merge sort instance
+    pub fn odd_even_merge() -> Self {
+        Self::with_comparator(Comparator::natural()).with_network(Network::OddEvenMerge)
+    }
+}
+
+impl<T> NetworkSort<T> {
+    /// Create a bitonic sort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            network: Network::default(),
+            layers: Vec::new(),
+            layer: 0,
+            comparators: Vec::new(),
+            position: 0,
+            swap_due: false,
+            total_comparators: 0,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+
+    /// Run `network` instead of the bitonic network
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Layers started so far, the time the comparisons would take on
+    /// parallel hardware
+    pub fn parallel_depth(&self) -> u64 {
+        (self.layer + usize::from(self.position > 0)) as u64
+    }
+
+    /// Move on to the next layer, or finish after the last
+    fn next_layer(&mut self) {
+        self.layer += 1;
+        self.position = 0;
+        match self.layers.get(self.layer) {
+            Some(layer) => self.comparators = layer.comparators(self.data.len().next_power_of_two(), self.data.len()),
+            None => {
+                self.comparators.clear();
+                self.complete = true;
+            }
+        }
+    }
+}
+
+impl Default for NetworkSort {
+    fn default() -> Self {
+        Self::bitonic()
+    }
+}
+
+impl<T: Element> NetworkSort<T> {
+    /// Run comparators until the network is done, or `None` if the budget
+    /// runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        while !self.complete {
+            if self.swap_due {
+                let (low, high) = self.comparators[self.position];
+                budget.shift()?;
+                self.data.swap(low, high);
+                self.origins.swap(low, high);
+                self.moves += 1;
+                self.writes += 2;
+                self.swap_due = false;
+                self.position += 1;
+                continue;
+            }
+            if self.position == self.comparators.len() {
+                self.next_layer();
+                continue;
+            }
+
+            let (low, high) = self.comparators[self.position];
+            budget.compare()?;
+            self.comparisons += 1;
+            self.swap_due = self.compare.greater(&self.data[low], &self.data[high]);
+            if !self.swap_due {
+                self.position += 1;
+            }
+        }
+        Some(())
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for NetworkSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+        let mut highlights = Vec::new();
+
+        if !self.complete && self.position < self.comparators.len() {
+            let (low, high) = self.comparators[self.position];
+            markers.cursors = vec![low, high];
+            markers.comparators = self.comparators.clone();
+            markers.network_layer = Some((self.layer + 1, self.layers.len()));
+            highlights = self.comparators.iter().flat_map(|&(low, high)| [low, high]).collect();
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: Some(self.parallel_depth()),
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights,
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else {
+                format!(
+                    "Layer {}/{}, comparator {}/{}",
+                    self.layer + 1,
+                    self.layers.len(),
+                    self.position + 1,
+                    self.comparators.len()
+                )
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                // The network is fixed, so the comparators left are known
+                (self.comparisons as f32 / self.total_comparators as f32).clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+
+        let (width, len) = (self.data.len().next_power_of_two(), self.data.len());
+        self.layers = schedule(self.network, width)
+            .into_iter()
+            .filter(|layer| !layer.comparators(width, len).is_empty())
+            .collect();
+        self.total_comparators = self.layers.iter().map(|layer| layer.comparators(width, len).len() as u64).sum();
+        self.layer = 0;
+        self.position = 0;
+        self.swap_due = false;
+        self.comparators = self.layers.first().map(|layer| layer.comparators(width, len)).unwrap_or_default();
+
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.layers.is_empty();
+    }
+
+    fn name(&self) -> &str {
+        match self.network {
+            Network::Bitonic => "Bitonic Sort",
+            Network::OddEvenMerge => "Odd-Even Merge Sort",
+        }
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array plus the comparators of the current layer
+        self.data.len() * std::mem::size_of::<T>()
+            + self.comparators.capacity() * std::mem::size_of::<(usize, usize)>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,199 @@
+//! Odd-Even Transposition Sort implementation
+//!
+//! Alternates between comparing the pairs starting at even indices and the
+//! pairs starting at odd indices. The pairs within a phase are disjoint, so
+//! on parallel hardware each phase takes one step; here they are compared
+//! one after another. `n` phases always suffice.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Odd-Even Transposition Sort algorithm implementation
+#[derive(Debug)]
+pub struct OddEvenSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    /// Phase number; even phases start at index 0, odd phases at index 1
+    phase: usize,
+    current_pos: usize,
+    /// The pair at `current_pos` is out of order but not yet swapped
+    swap_due: bool,
+    swapped: bool,
+    /// Consecutive phases without a swap
+    clean_phases: usize,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+}
+
+impl OddEvenSort {
+    /// Create a new OddEvenSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> OddEvenSort<T> {
+    /// Create an OddEvenSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            phase: 0,
+            current_pos: 0,
+            swap_due: false,
+            swapped: false,
+            clean_phases: 0,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+        }
+    }
+}
+
+impl Default for OddEvenSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> OddEvenSort<T> {
+    /// Run phases until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        let n = self.data.len();
+        loop {
+            let pos = self.current_pos;
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(pos, pos + 1);
+                self.origins.swap(pos, pos + 1);
+                self.moves += 1;
+                self.writes += 2;
+                self.swapped = true;
+                self.swap_due = false;
+                self.current_pos += 2;
+            } else if pos + 1 < n {
+                budget.compare()?;
+                self.comparisons += 1;
+                self.swap_due = self.compare.greater(&self.data[pos], &self.data[pos + 1]);
+                if !self.swap_due {
+                    self.current_pos += 2;
+                }
+            } else {
+                // End of current phase; an odd and an even phase in a row
+                // without swaps means every adjacent pair is in order
+                self.clean_phases = if self.swapped { 0 } else { self.clean_phases + 1 };
+                self.phase += 1;
+                if self.clean_phases >= 2 || self.phase >= n {
+                    return Some(());
+                }
+                self.current_pos = self.phase % 2;
+                self.swapped = false;
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for OddEvenSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+
+        if !self.complete && self.current_pos + 1 < self.data.len() {
+            markers.cursors = vec![self.current_pos, self.current_pos + 1];
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.get_memory_usage(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: if self.complete {
+                "Completed".to_string()
+            } else {
+                format!(
+                    "Phase {} ({}), Position {}",
+                    self.phase + 1,
+                    if self.phase.is_multiple_of(2) { "even" } else { "odd" },
+                    self.current_pos
+                )
+            },
+            progress_hint: if self.data.len() <= 1 || self.complete {
+                1.0
+            } else {
+                (self.phase as f32 / self.data.len() as f32).clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.phase = 0;
+        self.current_pos = 0;
+        self.swap_due = false;
+        self.swapped = false;
+        self.clean_phases = 0;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+    }
+
+    fn name(&self) -> &str {
+        "Odd-Even Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Report size of the data array in bytes
+        self.data.len() * std::mem::size_of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,464 @@
+//! Pattern-defeating Quick Sort implementation
+//!
+//! Follows Orson Peters' pdqsort, the algorithm behind Rust's
+//! `sort_unstable`: ninther pivots for large ranges, a partition that
+//! reports whether the range was already partitioned (then tries a bounded
+//! insertion sort), a left partition that skips runs of keys equal to the
+//! previous pivot, pattern-breaking swaps after unbalanced partitions and a
+//! heap sort fallback once too many partitions were bad.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use crate::services::sorters::hybrid::{HeapRange, Insertion, Partition, Sort2Chain, Swaps, Ties, Workspace};
+use std::any::Any;
+
+/// Ranges below this size are insertion sorted
+const INSERTION_THRESHOLD: usize = 24;
+
+/// Ranges above this size pick the pivot as a median of medians
+const NINTHER_THRESHOLD: usize = 128;
+
+/// Element moves a partial insertion sort may make before giving up
+const PARTIAL_INSERTION_LIMIT: usize = 8;
+
+/// Pending range `[low, high)`
+#[derive(Debug, Clone)]
+struct StackFrame {
+    low: usize,
+    high: usize,
+    /// Unbalanced partitions still tolerated before falling back to heap sort
+    bad_allowed: usize,
+    /// Whether the range starts the array, so it has no predecessor pivot
+    leftmost: bool,
+}
+
+/// Operation in progress on the frame being processed
+#[derive(Debug, Clone)]
+enum PdqOp {
+    /// Pivot selection, leaving the pivot at `low`
+    Median { chain: Sort2Chain, mid: Option<usize> },
+    /// Checking whether the pivot equals the previous pivot
+    CheckEqual,
+    /// Partition with equal keys going right, or left after an equal pivot
+    Partition(Partition),
+    /// Pattern-breaking swaps after an unbalanced partition around `pivot`
+    BreakPatterns { swaps: Swaps, pivot: usize },
+    /// Bounded insertion sorts of both sides of an already partitioned range
+    PartialInsertion { insertion: Insertion, pivot: usize, right_side: bool },
+    Insertion(Insertion),
+    /// Heap sort after too many unbalanced partitions
+    Heap(HeapRange),
+}
+
+/// Pattern pdqsort reacted to after its most recent partition
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pattern {
+    AlreadyPartitioned,
+    EqualKeys,
+    BrokenUp,
+    HeapFallback,
+}
+
+impl Pattern {
+    fn label(self) -> &'static str {
+        match self {
+            Pattern::AlreadyPartitioned => "partial insertion sort",
+            Pattern::EqualKeys => "equal keys",
+            Pattern::BrokenUp => "pattern broken",
+            Pattern::HeapFallback => "heapsort fallback",
+        }
+    }
+}
+
+/// Pdq Sort algorithm implementation
+#[derive(Debug)]
+pub struct PdqSort<T = i32> {
+    ws: Workspace<T>,
+    stack: Vec<StackFrame>,
+    current: Option<(StackFrame, PdqOp)>,
+    pattern: Option<Pattern>,
+    complete: bool,
+    max_stack: usize,
+}
+
+impl PdqSort {
+    /// Create a new PdqSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> PdqSort<T> {
+    /// Create a PdqSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            ws: Workspace::new(compare),
+            stack: Vec::new(),
+            current: None,
+            pattern: None,
+            complete: false,
+            max_stack: 0,
+        }
+    }
+
+    /// Pick the operation that starts work on `frame`
+    fn begin_frame(&mut self, frame: &StackFrame) -> PdqOp {
+        let (low, high) = (frame.low, frame.high);
+        let size = high - low;
+        if size < INSERTION_THRESHOLD {
+            return PdqOp::Insertion(Insertion::new(low, high));
+        }
+
+        let mid = low + size / 2;
+        if size > NINTHER_THRESHOLD {
+            PdqOp::Median {
+                chain: Sort2Chain::sort3(&[
+                    (low, mid, high - 1),
+                    (low + 1, mid - 1, high - 2),
+                    (low + 2, mid + 1, high - 3),
+                    (mid - 1, mid, mid + 1),
+                ]),
+                mid: Some(mid),
+            }
+        } else {
+            // Sorting (mid, low, high - 1) leaves the median at `low`
+            PdqOp::Median {
+                chain: Sort2Chain::sort3(&[(mid, low, high - 1)]),
+                mid: None,
+            }
+        }
+    }
+
+    fn push(&mut self, frame: StackFrame) {
+        if frame.high - frame.low > 1 {
+            self.stack.push(frame);
+            self.max_stack = self.max_stack.max(self.stack.len());
+        }
+    }
+
+    /// Queue both sides of a partition, right side first so the left runs next
+    fn push_sides(&mut self, frame: &StackFrame, pivot: usize) {
+        self.push(StackFrame {
+            low: pivot + 1,
+            high: frame.high,
+            bad_allowed: frame.bad_allowed,
+            leftmost: false,
+        });
+        self.push(StackFrame {
+            low: frame.low,
+            high: pivot,
+            bad_allowed: frame.bad_allowed,
+            leftmost: frame.leftmost,
+        });
+    }
+
+    /// Swaps into an unbalanced partition's sides that break up patterns
+    fn break_patterns(frame: &StackFrame, pivot: usize) -> Swaps {
+        let (low, high) = (frame.low, frame.high);
+        let mut pairs = Vec::new();
+        let left_size = pivot - low;
+        let right_size = high - (pivot + 1);
+
+        if left_size >= INSERTION_THRESHOLD {
+            let quarter = left_size / 4;
+            pairs.push((low, low + quarter));
+            pairs.push((pivot - 1, pivot - quarter));
+            if left_size > NINTHER_THRESHOLD {
+                pairs.push((low + 1, low + quarter + 1));
+                pairs.push((low + 2, low + quarter + 2));
+                pairs.push((pivot - 2, pivot - (quarter + 1)));
+                pairs.push((pivot - 3, pivot - (quarter + 2)));
+            }
+        }
+
+        if right_size >= INSERTION_THRESHOLD {
+            let quarter = right_size / 4;
+            pairs.push((pivot + 1, pivot + 1 + quarter));
+            pairs.push((high - 1, high - quarter));
+            if right_size > NINTHER_THRESHOLD {
+                pairs.push((pivot + 2, pivot + 2 + quarter));
+                pairs.push((pivot + 3, pivot + 3 + quarter));
+                pairs.push((high - 2, high - (1 + quarter)));
+                pairs.push((high - 3, high - (2 + quarter)));
+            }
+        }
+
+        Swaps::new(pairs)
+    }
+
+    /// Advance `op` on `frame`, returning the follow-up operation if any
+    fn advance(&mut self, frame: &mut StackFrame, op: &mut PdqOp, budget: &mut Budget) -> Option<Option<PdqOp>> {
+        match op {
+            PdqOp::Median { chain, mid } => {
+                chain.run(&mut self.ws, budget)?;
+                if let Some(mid) = *mid {
+                    self.ws.swap(frame.low, mid, budget)?;
+                }
+                if frame.leftmost {
+                    Some(Some(PdqOp::Partition(Partition::new(frame.low, frame.high, Ties::Right))))
+                } else {
+                    Some(Some(PdqOp::CheckEqual))
+                }
+            }
+            PdqOp::CheckEqual => {
+                // The previous pivot sits just before this range and is no
+                // greater than anything in it; an equal pivot means a run of
+                // equal keys that can be split off in one pass
+                let ties = if self.ws.less(frame.low - 1, frame.low, budget)? { Ties::Right } else { Ties::Left };
+                Some(Some(PdqOp::Partition(Partition::new(frame.low, frame.high, ties))))
+            }
+            PdqOp::Partition(partition) => {
+                let result = partition.run(&mut self.ws, budget)?;
+                let pivot = result.pivot;
+                self.pattern = None;
+
+                if partition.ties() == Ties::Left {
+                    // Everything left of the pivot equals it and is in place
+                    self.pattern = Some(Pattern::EqualKeys);
+                    self.push(StackFrame {
+                        low: pivot + 1,
+                        high: frame.high,
+                        bad_allowed: frame.bad_allowed,
+                        leftmost: false,
+                    });
+                    return Some(None);
+                }
+
+                let size = frame.high - frame.low;
+                let left_size = pivot - frame.low;
+                let right_size = frame.high - (pivot + 1);
+                if left_size < size / 8 || right_size < size / 8 {
+                    frame.bad_allowed -= 1;
+                    if frame.bad_allowed == 0 {
+                        self.pattern = Some(Pattern::HeapFallback);
+                        return Some(Some(PdqOp::Heap(HeapRange::new(frame.low, frame.high))));
+                    }
+                    self.pattern = Some(Pattern::BrokenUp);
+                    return Some(Some(PdqOp::BreakPatterns { swaps: Self::break_patterns(frame, pivot), pivot }));
+                } else if result.already_partitioned {
+                    self.pattern = Some(Pattern::AlreadyPartitioned);
+                    return Some(Some(PdqOp::PartialInsertion {
+                        insertion: Insertion::partial(frame.low, pivot, PARTIAL_INSERTION_LIMIT),
+                        pivot,
+                        right_side: false,
+                    }));
+                }
+
+                self.push_sides(frame, pivot);
+                Some(None)
+            }
+            PdqOp::BreakPatterns { swaps, pivot } => {
+                swaps.run(&mut self.ws, budget)?;
+                self.push_sides(frame, *pivot);
+                Some(None)
+            }
+            PdqOp::PartialInsertion { insertion, pivot, right_side } => {
+                let sorted = insertion.run(&mut self.ws, budget)?;
+                if !sorted {
+                    self.pattern = None;
+                    self.push_sides(frame, *pivot);
+                    Some(None)
+                } else if !*right_side {
+                    Some(Some(PdqOp::PartialInsertion {
+                        insertion: Insertion::partial(*pivot + 1, frame.high, PARTIAL_INSERTION_LIMIT),
+                        pivot: *pivot,
+                        right_side: true,
+                    }))
+                } else {
+                    Some(None)
+                }
+            }
+            PdqOp::Insertion(insertion) => insertion.run(&mut self.ws, budget).map(|_| None),
+            PdqOp::Heap(heap) => heap.run(&mut self.ws, budget).map(|_| None),
+        }
+    }
+
+    /// Work through pending ranges until none are left or the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
+            let (mut frame, mut op) = match self.current.take() {
+                Some(current) => current,
+                None => match self.stack.pop() {
+                    Some(frame) => {
+                        let op = self.begin_frame(&frame);
+                        (frame, op)
+                    }
+                    None => return Some(()),
+                },
+            };
+
+            match self.advance(&mut frame, &mut op, budget) {
+                None => {
+                    self.current = Some((frame, op));
+                    return None;
+                }
+                Some(Some(next)) => self.current = Some((frame, next)),
+                Some(None) => {}
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.ws.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+}
+
+impl Default for PdqSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> Sorter<T> for PdqSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers {
+            pattern: self.pattern.filter(|_| !self.complete).map(|pattern| pattern.label().to_string()),
+            ..Default::default()
+        };
+
+        let status_text = match &self.current {
+            _ if self.complete => "Completed".to_string(),
+            Some((frame, PdqOp::Median { mid, .. })) => {
+                let mid = mid.unwrap_or(frame.low + (frame.high - frame.low) / 2);
+                markers.cursors = vec![frame.low, mid, frame.high - 1];
+                if frame.high - frame.low > NINTHER_THRESHOLD {
+                    format!("Ninther pivot for [{}, {})", frame.low, frame.high)
+                } else {
+                    format!("Median of three for [{}, {})", frame.low, frame.high)
+                }
+            }
+            Some((frame, PdqOp::CheckEqual)) => {
+                markers.cursors = vec![frame.low - 1, frame.low];
+                format!("Comparing pivot with previous pivot at {}", frame.low - 1)
+            }
+            Some((frame, PdqOp::Partition(partition))) => {
+                markers.pivot = Some(frame.low);
+                markers.cursors = vec![partition.i.min(frame.high - 1), partition.j];
+                if partition.ties() == Ties::Left {
+                    format!("Splitting off keys equal to the pivot in [{}, {})", frame.low, frame.high)
+                } else {
+                    format!("Partitioning range [{}, {}), {} bad partitions left", frame.low, frame.high, frame.bad_allowed)
+                }
+            }
+            Some((frame, PdqOp::BreakPatterns { pivot, .. })) => {
+                markers.pivot = Some(*pivot);
+                markers.cursors = vec![frame.low, frame.high - 1];
+                format!("Breaking up patterns around the pivot at {}", pivot)
+            }
+            Some((_, PdqOp::PartialInsertion { insertion, .. })) => {
+                markers.insertion_range = Some((insertion.begin, insertion.end.saturating_sub(1)));
+                markers.cursors = vec![insertion.j.min(insertion.end.saturating_sub(1))];
+                format!(
+                    "Already partitioned: partial insertion sort of [{}, {})",
+                    insertion.begin, insertion.end
+                )
+            }
+            Some((frame, PdqOp::Insertion(insertion))) => {
+                markers.insertion_range = Some((frame.low, frame.high - 1));
+                markers.cursors = vec![insertion.j.min(frame.high - 1)];
+                format!("Insertion sorting small range [{}, {})", frame.low, frame.high)
+            }
+            Some((frame, PdqOp::Heap(heap))) => {
+                markers.heapsort_range = Some((frame.low, frame.high - 1));
+                markers.heap_boundary = Some(heap.begin + heap.heap_size);
+                markers.cursors = vec![heap.begin];
+                format!("Too many bad partitions: heap sorting [{}, {})", frame.low, frame.high)
+            }
+            None => "Processing".to_string(),
+        };
+
+        if !self.complete && markers.cursors.is_empty()
+            && let Some(frame) = self.stack.last() {
+                markers.cursors = vec![frame.low, frame.high - 1];
+            }
+
+        let progress_hint = if self.complete || self.ws.data.len() <= 1 {
+            1.0
+        } else {
+            // Elements in pending or active ranges are not yet in their final place
+            let active = self.current.as_ref().map_or(0, |(frame, _)| frame.high - frame.low);
+            let pending: usize = self.stack.iter().map(|frame| frame.high - frame.low).sum::<usize>() + active;
+            let progress = 1.0 - pending as f32 / self.ws.data.len() as f32;
+            if progress.is_finite() { progress.min(1.0).max(0.0) } else { 0.0 }
+        };
+
+        Telemetry {
+            total_comparisons: self.ws.comparisons,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.data.len() * std::mem::size_of::<T>()
+                + self.max_stack * std::mem::size_of::<StackFrame>(),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint,
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.ws.reset(data);
+        self.stack.clear();
+        self.current = None;
+        self.pattern = None;
+        self.complete = self.ws.data.len() <= 1;
+
+        if !self.complete {
+            let n = self.ws.data.len();
+            self.stack.push(StackFrame {
+                low: 0,
+                high: n,
+                bad_allowed: n.ilog2() as usize,
+                leftmost: true,
+            });
+        }
+        self.max_stack = self.stack.len();
+    }
+
+    fn name(&self) -> &str {
+        "Pdq Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.ws.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.ws.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + stack memory
+        self.ws.data.len() * std::mem::size_of::<T>()
+            + self.stack.len() * std::mem::size_of::<StackFrame>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
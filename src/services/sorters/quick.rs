@@ -1,8 +1,79 @@
 //! Quick Sort implementation
-
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+//!
+//! The pivot strategy and partition scheme are configurable, so lanes can
+//! show how much the choice matters on sorted or low-cardinality input.
+//! By default the last element is the pivot and the range is partitioned
+//! with Lomuto's scheme.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use crate::services::sorters::hybrid::{Lomuto, Partition, Sort2Chain, ThreeWay, Ties, Workspace};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::any::Any;
 
+/// Seed used by the `random` lane option
+pub const DEFAULT_RANDOM_SEED: u64 = 0x5eed;
+
+/// Ranges longer than this use a ninther instead of a median of three
+const NINTHER_THRESHOLD: usize = 40;
+
+/// Lane options understood by [`QuickSort::with_option`]
+pub const OPTIONS: [&str; 8] = ["first", "last", "random", "median3", "ninther", "lomuto", "hoare", "3way"];
+
+/// How the pivot of each range is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotStrategy {
+    /// First element of the range
+    First,
+    /// Last element of the range
+    #[default]
+    Last,
+    /// Uniformly random element, from a generator seeded on every reset
+    Random { seed: u64 },
+    /// Median of the first, middle and last elements
+    MedianOfThree,
+    /// Median of three medians of three, spread over the range
+    Ninther,
+}
+
+impl PivotStrategy {
+    /// Label used in lane names
+    pub fn label(self) -> &'static str {
+        match self {
+            PivotStrategy::First => "first pivot",
+            PivotStrategy::Last => "last pivot",
+            PivotStrategy::Random { .. } => "random pivot",
+            PivotStrategy::MedianOfThree => "median-of-3",
+            PivotStrategy::Ninther => "ninther",
+        }
+    }
+}
+
+/// How each range is split around its pivot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionScheme {
+    /// Single left-to-right scan, keys equal to the pivot go left
+    #[default]
+    Lomuto,
+    /// Two scans towards each other, both stopping on keys equal to the pivot
+    Hoare,
+    /// Dutch national flag split into smaller, equal and greater keys
+    ThreeWay,
+}
+
+impl PartitionScheme {
+    /// Label used in lane names
+    pub fn label(self) -> &'static str {
+        match self {
+            PartitionScheme::Lomuto => "Lomuto",
+            PartitionScheme::Hoare => "Hoare",
+            PartitionScheme::ThreeWay => "3-way",
+        }
+    }
+}
+
 /// Stack frame for Quick Sort recursion simulation
 #[derive(Debug, Clone)]
 struct StackFrame {
@@ -10,136 +81,246 @@ struct StackFrame {
     high: usize,
 }
 
+/// Work on the range `[low, high]` at the top of the stack
+#[derive(Debug, Clone)]
+enum QuickOp {
+    /// Sorting sample triples so the pivot's median lands on `median`
+    Selecting { chain: Sort2Chain, median: usize },
+    /// Swapping the chosen pivot into the slot the scheme expects it in
+    Placing { pivot: usize },
+    Lomuto(Lomuto),
+    Hoare(Partition),
+    ThreeWay(ThreeWay),
+}
+
 /// State for incremental partitioning
 #[derive(Debug, Clone)]
 enum PartitionState {
     /// No partitioning in progress
     NotStarted,
-    /// Partitioning in progress with current indices and pivot value
-    InProgress {
-        current_j: usize,
-        current_i: usize,
-        pivot: i32,
-        low: usize,
-        high: usize,
-    },
-    /// Partitioning completed
-    Complete,
+    /// Partitioning `[low, high]` in progress
+    InProgress { low: usize, high: usize, op: QuickOp },
 }
 
 /// Quick Sort algorithm implementation
 #[derive(Debug)]
-pub struct QuickSort {
-    data: Vec<i32>,
+pub struct QuickSort<T = i32> {
+    ws: Workspace<T>,
     stack: Vec<StackFrame>,
-    comparisons: u64,
-    moves: u64,
     complete: bool,
-    current_pivot: Option<usize>,
-    memory_usage: usize,
     partition_state: PartitionState,
     max_progress_seen: f32,
+    pivot: PivotStrategy,
+    scheme: PartitionScheme,
+    rng: StdRng,
+    name: String,
 }
 
 impl QuickSort {
     /// Create a new QuickSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> QuickSort<T> {
+    /// Create a QuickSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
-            data: Vec::new(),
+            ws: Workspace::new(compare),
             stack: Vec::new(),
-            comparisons: 0,
-            moves: 0,
             complete: false,
-            current_pivot: None,
-            memory_usage: 0,
             partition_state: PartitionState::NotStarted,
             max_progress_seen: 0.0,
+            pivot: PivotStrategy::default(),
+            scheme: PartitionScheme::default(),
+            rng: StdRng::seed_from_u64(0),
+            name: "Quick Sort".to_string(),
         }
     }
 
-    /// Start a new partition operation
-    fn start_partition(&mut self, low: usize, high: usize) {
-        let pivot = self.data[high];
-        self.partition_state = PartitionState::InProgress {
-            current_j: low,
-            current_i: low,
-            pivot,
-            low,
-            high,
+    /// Choose pivots with `pivot`
+    pub fn with_pivot(mut self, pivot: PivotStrategy) -> Self {
+        self.pivot = pivot;
+        self.update_name();
+        self
+    }
+
+    /// Partition ranges with `scheme`
+    pub fn with_partition(mut self, scheme: PartitionScheme) -> Self {
+        self.scheme = scheme;
+        self.update_name();
+        self
+    }
+
+    /// Apply a lane option from [`OPTIONS`], or `None` if it is unknown
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        Some(match option {
+            "first" => self.with_pivot(PivotStrategy::First),
+            "last" => self.with_pivot(PivotStrategy::Last),
+            "random" => self.with_pivot(PivotStrategy::Random { seed: DEFAULT_RANDOM_SEED }),
+            "median3" => self.with_pivot(PivotStrategy::MedianOfThree),
+            "ninther" => self.with_pivot(PivotStrategy::Ninther),
+            "lomuto" => self.with_partition(PartitionScheme::Lomuto),
+            "hoare" => self.with_partition(PartitionScheme::Hoare),
+            "3way" => self.with_partition(PartitionScheme::ThreeWay),
+            _ => return None,
+        })
+    }
+
+    /// Get the pivot strategy
+    pub fn pivot_strategy(&self) -> PivotStrategy {
+        self.pivot
+    }
+
+    /// Get the partition scheme
+    pub fn partition_scheme(&self) -> PartitionScheme {
+        self.scheme
+    }
+
+    /// Name the sorter after every non-default choice, e.g. `Quick Sort (ninther, Hoare)`
+    fn update_name(&mut self) {
+        let mut parts = Vec::new();
+        if self.pivot != PivotStrategy::default() {
+            parts.push(self.pivot.label());
+        }
+        if self.scheme != PartitionScheme::default() {
+            parts.push(self.scheme.label());
+        }
+        self.name = if parts.is_empty() {
+            "Quick Sort".to_string()
+        } else {
+            format!("Quick Sort ({})", parts.join(", "))
         };
-        self.current_pivot = Some(high);
     }
 
-    /// Continue or complete partition operation with given budget
-    /// Returns Some(pivot_index) if partition completes, None if more work needed
-    fn continue_partition(&mut self, budget: &mut usize) -> Option<usize> {
-        if *budget == 0 {
-            return None;
+    /// Slot the partition scheme expects the pivot in
+    fn pivot_slot(&self, low: usize, high: usize) -> usize {
+        match self.scheme {
+            PartitionScheme::Lomuto => high,
+            PartitionScheme::Hoare | PartitionScheme::ThreeWay => low,
         }
+    }
 
-        match &self.partition_state.clone() {
-            PartitionState::InProgress { current_j, current_i, pivot, low, high } => {
-                let mut j = *current_j;
-                let mut i = *current_i;
-                let pivot_val = *pivot;
-                let low_bound = *low;
-                let high_bound = *high;
-
-                // Continue partitioning from where we left off
-                while j < high_bound && *budget > 0 {
-                    *budget -= 1;
-                    self.comparisons += 1;
-
-                    if self.data[j] <= pivot_val {
-                        if i != j {
-                            self.data.swap(i, j);
-                            self.moves += 1;
-                        }
-                        i += 1;
+    /// Start work on `[low, high]`, sampling a median first if the strategy needs one
+    fn begin_partition(&mut self, low: usize, high: usize) -> QuickOp {
+        let len = high - low + 1;
+        let mid = low + len / 2;
+        let step = len / 8;
+        let pivot = match self.pivot {
+            PivotStrategy::First => low,
+            PivotStrategy::Last => high,
+            PivotStrategy::Random { .. } => self.rng.random_range(low..=high),
+            PivotStrategy::Ninther if len > NINTHER_THRESHOLD => {
+                return QuickOp::Selecting {
+                    chain: Sort2Chain::sort3(&[
+                        (low, low + step, low + 2 * step),
+                        (mid - step, mid, mid + step),
+                        (high - 2 * step, high - step, high),
+                        (low + step, mid, high - step),
+                    ]),
+                    median: mid,
+                };
+            }
+            PivotStrategy::MedianOfThree | PivotStrategy::Ninther if len >= 3 => {
+                return QuickOp::Selecting {
+                    chain: Sort2Chain::sort3(&[(low, mid, high)]),
+                    median: mid,
+                };
+            }
+            PivotStrategy::MedianOfThree | PivotStrategy::Ninther => high,
+        };
+        QuickOp::Placing { pivot }
+    }
+
+    /// Start the partition scheme once the pivot is in its slot
+    fn start_scheme(&self, low: usize, high: usize) -> QuickOp {
+        match self.scheme {
+            PartitionScheme::Lomuto => QuickOp::Lomuto(Lomuto::new(low, high + 1)),
+            PartitionScheme::Hoare => QuickOp::Hoare(Partition::new(low, high + 1, Ties::Split)),
+            PartitionScheme::ThreeWay => QuickOp::ThreeWay(ThreeWay::new(low, high + 1)),
+        }
+    }
+
+    /// Partition pending ranges until the stack is empty or the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
+            let (low, high, mut op) = match std::mem::replace(&mut self.partition_state, PartitionState::NotStarted) {
+                PartitionState::InProgress { low, high, op } => (low, high, op),
+                PartitionState::NotStarted => {
+                    let Some(frame) = self.stack.last().cloned() else {
+                        return Some(());
+                    };
+                    if frame.low >= frame.high {
+                        self.stack.pop(); // Remove trivial frame
+                        continue;
                     }
-                    j += 1;
+                    (frame.low, frame.high, self.begin_partition(frame.low, frame.high))
                 }
+            };
 
-                if j >= high_bound {
-                    // Partitioning complete - place pivot in final position
-                    if i != high_bound {
-                        self.data.swap(i, high_bound);
-                        self.moves += 1;
-                    }
-                    self.partition_state = PartitionState::Complete;
-                    self.current_pivot = None;
-                    Some(i)
-                } else {
-                    // More work needed - update state
-                    self.partition_state = PartitionState::InProgress {
-                        current_j: j,
-                        current_i: i,
-                        pivot: pivot_val,
-                        low: low_bound,
-                        high: high_bound,
+            // Range of keys equal to the pivot, already in their final place
+            let slot = self.pivot_slot(low, high);
+            let outcome = match &mut op {
+                QuickOp::Selecting { chain, .. } => chain.run(&mut self.ws, budget).map(|_| None),
+                QuickOp::Placing { pivot } => self.ws.swap(*pivot, slot, budget).map(|_| None),
+                QuickOp::Lomuto(partition) => partition.run(&mut self.ws, budget).map(|pivot| Some((pivot, pivot))),
+                QuickOp::Hoare(partition) => partition
+                    .run(&mut self.ws, budget)
+                    .map(|result| Some((result.pivot, result.pivot))),
+                QuickOp::ThreeWay(partition) => partition.run(&mut self.ws, budget).map(|(lt, gt)| Some((lt, gt - 1))),
+            };
+
+            let (first_equal, last_equal) = match outcome {
+                None => {
+                    // Out of budget - continue in the next step
+                    self.partition_state = PartitionState::InProgress { low, high, op };
+                    return None;
+                }
+                Some(None) => {
+                    let op = match op {
+                        QuickOp::Selecting { median, .. } => QuickOp::Placing { pivot: median },
+                        _ => self.start_scheme(low, high),
                     };
-                    None
+                    self.partition_state = PartitionState::InProgress { low, high, op };
+                    continue;
                 }
+                Some(Some(equal)) => equal,
+            };
+
+            self.stack.pop(); // Remove current frame
+
+            // Add right subarray if it has more than one element
+            if last_equal + 1 < high {
+                self.stack.push(StackFrame {
+                    low: last_equal + 1,
+                    high,
+                });
             }
-            _ => None, // Should not happen when called appropriately
-        }
-    }
 
-    /// Get partition progress (0.0 to 1.0)
-    #[allow(dead_code)]
-    fn get_partition_progress(&self) -> f32 {
-        match &self.partition_state {
-            PartitionState::NotStarted => 0.0,
-            PartitionState::Complete => 1.0,
-            PartitionState::InProgress { current_j, low, high, .. } => {
-                if *high <= *low {
-                    1.0
-                } else {
-                    (*current_j - *low) as f32 / (*high - *low) as f32
-                }
+            // Add left subarray if it is not empty
+            if low < first_equal {
+                self.stack.push(StackFrame {
+                    low,
+                    high: first_equal - 1,
+                });
             }
         }
     }
+
+    /// Fraction of the current partition already scanned
+    fn partition_progress(&self) -> Option<(usize, usize, f32)> {
+        let PartitionState::InProgress { low, high, op } = &self.partition_state else {
+            return None;
+        };
+        let scanned = match op {
+            QuickOp::Selecting { .. } | QuickOp::Placing { .. } => 0,
+            QuickOp::Lomuto(partition) => partition.j - low,
+            QuickOp::Hoare(partition) => (partition.i - low) + (high - partition.j.min(*high)),
+            QuickOp::ThreeWay(partition) => (partition.i - low) + (high + 1 - partition.gt),
+        };
+        Some((*low, *high, (scanned as f32 / (high - low) as f32).min(1.0)))
+    }
 }
 
 impl Default for QuickSort {
@@ -148,106 +329,17 @@ impl Default for QuickSort {
     }
 }
 
-impl Sorter for QuickSort {
+impl<T: Element> Sorter<T> for QuickSort<T> {
     fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
-
-        let initial_comparisons = self.comparisons;
-        let initial_moves = self.moves;
-        let mut remaining_budget = budget;
-
-        // Handle ongoing partition if in progress
-        if matches!(self.partition_state, PartitionState::InProgress { .. })
-            && let Some(pivot_pos) = self.continue_partition(&mut remaining_budget) {
-                // Partition completed - add sub-problems to stack
-                if let Some(frame) = self.stack.last() {
-                    let current_frame = frame.clone();
-                    self.stack.pop(); // Remove current frame
-                    
-                    // Add right subarray if it has more than one element
-                    if pivot_pos + 1 < current_frame.high {
-                        self.stack.push(StackFrame {
-                            low: pivot_pos + 1,
-                            high: current_frame.high,
-                        });
-                    }
-                    
-                    // Add left subarray if it has more than one element
-                    if current_frame.low < pivot_pos {
-                        self.stack.push(StackFrame {
-                            low: current_frame.low,
-                            high: pivot_pos - 1,
-                        });
-                    }
-                }
-                self.partition_state = PartitionState::NotStarted;
-            }
-            // If partition didn't complete, we'll continue it in the next step
-
-        // Start new partitions if budget allows and no partition is in progress
-        while remaining_budget > 0 && !self.stack.is_empty() && 
-              matches!(self.partition_state, PartitionState::NotStarted) {
-            
-            let frame = self.stack.last().unwrap().clone();
-            
-            if frame.low >= frame.high {
-                self.stack.pop(); // Remove trivial frame
-                continue;
-            }
-
-            // Start new partition
-            self.start_partition(frame.low, frame.high);
-            
-            // Try to make progress on the new partition
-            if let Some(pivot_pos) = self.continue_partition(&mut remaining_budget) {
-                // Partition completed immediately
-                self.stack.pop(); // Remove current frame
-                
-                // Add right subarray if it has more than one element
-                if pivot_pos + 1 < frame.high {
-                    self.stack.push(StackFrame {
-                        low: pivot_pos + 1,
-                        high: frame.high,
-                    });
-                }
-                
-                // Add left subarray if it has more than one element
-                if frame.low < pivot_pos {
-                    self.stack.push(StackFrame {
-                        low: frame.low,
-                        high: pivot_pos - 1,
-                    });
-                }
-                
-                self.partition_state = PartitionState::NotStarted;
-            } else {
-                // Partition started but not completed - will continue next step
-                break;
-            }
-        }
-
-        // Check completion
-        if self.stack.is_empty() && matches!(self.partition_state, PartitionState::NotStarted) {
-            self.complete = true;
-            self.current_pivot = None;
-        }
-
-        self.memory_usage = self.stack.len() * std::mem::size_of::<StackFrame>();
+        self.step_with(Budget::comparisons(budget))
+    }
 
-        // Update progress tracking for monotonicity
-        self.update_progress();
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
 
-        StepResult {
-            comparisons_used: (self.comparisons - initial_comparisons) as usize,
-            moves_made: (self.moves - initial_moves) as usize,
-            continued: !self.complete,
-        }
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -256,86 +348,98 @@ impl Sorter for QuickSort {
 
     fn get_telemetry(&self) -> Telemetry {
         let mut markers = Markers::default();
-        
-        if let Some(pivot) = self.current_pivot {
-            markers.pivot = Some(pivot);
-        }
 
-        // Add cursors for current partition state
-        match &self.partition_state {
-            PartitionState::InProgress { current_j, current_i, low, high, .. } => {
-                markers.cursors = vec![*current_i, *current_j, *low, *high];
-            }
-            _ => {
-                if let Some(frame) = self.stack.last() {
-                    markers.cursors = vec![frame.low, frame.high];
+        let status_text = match &self.partition_state {
+            _ if self.complete => "Completed".to_string(),
+            PartitionState::InProgress { low, high, op } => match op {
+                QuickOp::Selecting { median, .. } => {
+                    markers.cursors = vec![*low, *median, *high];
+                    format!("Choosing {} for range [{}, {}]", self.pivot.label(), low, high)
                 }
-            }
-        }
-
-        let progress_hint = self.calculate_progress();
-        
-        let status_text = if self.complete {
-            "Completed".to_string()
-        } else {
-            match &self.partition_state {
-                PartitionState::InProgress { current_j, low, high, .. } => {
-                    format!("Partitioning range [{}, {}] - progress: {}/{}", 
-                           low, high, current_j - low, high - low)
+                QuickOp::Placing { pivot } => {
+                    markers.pivot = Some(*pivot);
+                    markers.cursors = vec![*low, *high];
+                    format!("Moving the pivot into place for range [{}, {}]", low, high)
                 }
-                _ => {
-                    if let Some(frame) = self.stack.last() {
-                        format!("Partitioning range [{}, {}]", frame.low, frame.high)
-                    } else {
-                        "Processing".to_string()
-                    }
+                QuickOp::Lomuto(partition) => {
+                    markers.pivot = Some(*high);
+                    markers.cursors = vec![partition.i, partition.j, *low, *high];
+                    format!("Partitioning range [{}, {}] - progress: {}/{}",
+                           low, high, partition.j - low, high - low)
+                }
+                QuickOp::Hoare(partition) => {
+                    markers.pivot = Some(*low);
+                    markers.cursors = vec![partition.i.min(*high), partition.j, *low, *high];
+                    format!("Hoare partitioning range [{}, {}]", low, high)
+                }
+                QuickOp::ThreeWay(partition) => {
+                    markers.pivot = Some(partition.lt);
+                    markers.cursors = vec![partition.lt, partition.i.min(*high), partition.gt.min(*high), *low, *high];
+                    format!(
+                        "3-way partitioning range [{}, {}] - {} keys equal to the pivot",
+                        low,
+                        high,
+                        partition.i - partition.lt
+                    )
+                }
+            },
+            PartitionState::NotStarted => {
+                if let Some(frame) = self.stack.last() {
+                    markers.cursors = vec![frame.low, frame.high];
+                    format!("Partitioning range [{}, {}]", frame.low, frame.high)
+                } else {
+                    "Processing".to_string()
                 }
             }
         };
 
         Telemetry {
-            total_comparisons: self.comparisons,
-            total_moves: self.moves,
+            total_comparisons: self.ws.comparisons,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
             markers,
             status_text,
-            progress_hint,
+            progress_hint: self.calculate_progress(),
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
-        self.data = data;
+    fn reset(&mut self, data: Vec<T>) {
+        self.ws.reset(data);
         self.stack.clear();
-        self.comparisons = 0;
-        self.moves = 0;
-        self.complete = self.data.len() <= 1;
-        self.current_pivot = None;
-        self.memory_usage = 0;
+        self.complete = self.ws.data.len() <= 1;
         self.partition_state = PartitionState::NotStarted;
         self.max_progress_seen = 0.0;
+        if let PivotStrategy::Random { seed } = self.pivot {
+            self.rng = StdRng::seed_from_u64(seed);
+        }
 
         if !self.complete {
             self.stack.push(StackFrame {
                 low: 0,
-                high: self.data.len() - 1,
+                high: self.ws.data.len() - 1,
             });
-            self.memory_usage = std::mem::size_of::<StackFrame>();
         }
     }
 
     fn name(&self) -> &str {
-        "Quick Sort"
+        &self.name
     }
 
-    fn get_array(&self) -> &[i32] {
-        &self.data
+    fn get_array(&self) -> &[T] {
+        &self.ws.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.ws.origins)
     }
 
     fn get_memory_usage(&self) -> usize {
         // Data array + stack memory
-        self.data.len() * std::mem::size_of::<i32>() + 
+        self.ws.data.len() * std::mem::size_of::<T>() +
         self.stack.len() * std::mem::size_of::<StackFrame>()
     }
 
@@ -348,10 +452,19 @@ impl Sorter for QuickSort {
     }
 }
 
-impl QuickSort {
+impl<T> QuickSort<T> {
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.ws.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+            // Update progress tracking for monotonicity
+            self.update_progress();
+        }
+        budget.result(!self.complete)
+    }
+
     /// Calculate overall progress of the sorting algorithm (monotonic)
     fn calculate_progress(&self) -> f32 {
-        if self.data.len() <= 1 {
+        if self.ws.data.len() <= 1 {
             return 1.0;
         }
 
@@ -360,27 +473,21 @@ impl QuickSort {
         }
 
         // Base progress on comparisons made vs expected total comparisons
-        let n = self.data.len() as f32;
+        let n = self.ws.data.len() as f32;
         let expected_comparisons = n * n.log2(); // O(n log n) average case
-        let base_progress = (self.comparisons as f32 / expected_comparisons).min(0.95);
+        let base_progress = (self.ws.comparisons as f32 / expected_comparisons).min(0.95);
 
         // Add fine-grained progress from current partition
-        let partition_progress = match &self.partition_state {
-            PartitionState::InProgress { current_j, low, high, .. } => {
-                if *high > *low {
-                    let current_partition_size = high - low;
-                    let partition_weight = current_partition_size as f32 / n;
-                    let local_progress = (*current_j - *low) as f32 / (*high - *low) as f32;
-                    partition_weight * local_progress * 0.05 // Small contribution for smoothness
-                } else {
-                    0.0
-                }
+        let partition_progress = match self.partition_progress() {
+            Some((low, high, local_progress)) => {
+                let partition_weight = (high - low) as f32 / n;
+                partition_weight * local_progress * 0.05 // Small contribution for smoothness
             }
-            _ => 0.0,
+            None => 0.0,
         };
 
-        let current_progress = (base_progress + partition_progress).min(1.0).max(0.0);
-        
+        let current_progress = (base_progress + partition_progress).clamp(0.0, 1.0);
+
         // Ensure monotonicity: never decrease progress
         self.max_progress_seen.max(current_progress)
     }
@@ -390,4 +497,4 @@ impl QuickSort {
         let current_progress = self.calculate_progress();
         self.max_progress_seen = self.max_progress_seen.max(current_progress);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,418 @@
+//! Radix Sort implementations
+//!
+//! Both sorters subtract the smallest key first, so only the digits that
+//! actually differ are sorted. Digits are one byte wide.
+//!
+//! `LsdRadixSort` distributes the whole array once per digit, least
+//! significant first, relying on each counting pass being stable.
+//! `MsdRadixSort` distributes by the most significant digit and then sorts
+//! each bucket by the next digit, stopping as soon as a bucket holds a
+//! single element.
+
+use crate::models::element::RadixKey;
+use crate::models::traits::{BudgetUnit, Granularity, Markers, Sorter, StepResult, Telemetry};
+use crate::services::sorters::buckets::{
+    key_bits, CountingPass, Digit, KeyScan, KeyWorkspace, LsdPasses, LsdPhase, PassPhase,
+};
+use std::any::Any;
+
+/// Bits per digit
+const RADIX_BITS: u32 = 8;
+
+/// Describe what a counting pass is doing
+fn pass_activity(pass: &CountingPass) -> &'static str {
+    match pass.phase {
+        PassPhase::Counting => "counting digits",
+        PassPhase::Scattering => "writing buckets",
+        PassPhase::CopyingBack => "copying back",
+    }
+}
+
+/// Least significant digit Radix Sort
+#[derive(Debug)]
+pub struct LsdRadixSort<T = i32> {
+    ws: KeyWorkspace<T>,
+    passes: LsdPasses,
+}
+
+impl LsdRadixSort {
+    /// Create a new LsdRadixSort instance
+    pub fn new() -> Self {
+        Self::for_keys()
+    }
+}
+
+impl<T: RadixKey> LsdRadixSort<T> {
+    /// Create an LsdRadixSort instance for any element with a radix key
+    pub fn for_keys() -> Self {
+        Self {
+            ws: KeyWorkspace::new(),
+            passes: LsdPasses::new(0),
+        }
+    }
+
+    /// One byte-wide digit per pass, covering every bit that differs
+    fn plan(min: u64, max: u64) -> Vec<Digit> {
+        (0..key_bits(min, max).div_ceil(RADIX_BITS))
+            .map(|pass| Digit::Bits {
+                offset: min,
+                shift: pass * RADIX_BITS,
+                bits: RADIX_BITS,
+            })
+            .collect()
+    }
+}
+
+impl Default for LsdRadixSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RadixKey> Sorter<T> for LsdRadixSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        if self.passes.is_done() {
+            return StepResult {
+                comparisons_used: 0,
+                moves_made: 0,
+                continued: false,
+            };
+        }
+
+        let initial_moves = self.ws.moves;
+        let mut remaining_budget = budget;
+        self.passes.run(&mut self.ws, &mut remaining_budget, Self::plan);
+
+        self.ws.track_memory(self.get_memory_usage());
+        if self.passes.is_done() {
+            self.ws.release();
+        }
+
+        StepResult {
+            comparisons_used: 0,
+            moves_made: (self.ws.moves - initial_moves) as usize,
+            continued: !self.passes.is_done(),
+        }
+    }
+
+    fn budget_unit(&self) -> BudgetUnit {
+        BudgetUnit::Operations
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.passes.is_done()
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+        let last = self.ws.data.len().saturating_sub(1);
+
+        let status_text = match &self.passes.phase {
+            LsdPhase::Done => "Completed".to_string(),
+            LsdPhase::Scan(scan) => {
+                markers.cursors = vec![scan.next.min(last)];
+                "Scanning for the smallest and largest key".to_string()
+            }
+            LsdPhase::Pass { pass, index, passes } => {
+                markers.digit_pass = Some((index + 1, *passes));
+                markers.cursors = vec![pass.next.min(last)];
+                if pass.phase != PassPhase::Counting {
+                    markers.buckets = pass.buckets();
+                }
+                format!("Digit pass {}/{}: {}", index + 1, passes, pass_activity(pass))
+            }
+        };
+
+        Telemetry {
+            total_comparisons: 0,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.memory_peak,
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint: self.passes.progress(self.ws.data.len()),
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.passes = LsdPasses::new(data.len());
+        self.ws.reset(data);
+    }
+
+    fn name(&self) -> &str {
+        "LSD Radix Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        self.ws.array()
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(self.ws.array_origins())
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + output buffer + digit histogram
+        self.ws.data_bytes() + self.ws.buffer_bytes() + self.passes.memory()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Pending range `[begin, end)` still to be sorted by digit `level`
+#[derive(Debug, Clone)]
+struct StackFrame {
+    begin: usize,
+    end: usize,
+    /// Digits already sorted on, most significant first
+    level: usize,
+}
+
+/// Most significant digit Radix Sort
+#[derive(Debug)]
+pub struct MsdRadixSort<T = i32> {
+    ws: KeyWorkspace<T>,
+    scan: Option<KeyScan>,
+    stack: Vec<StackFrame>,
+    current: Option<(StackFrame, CountingPass)>,
+    offset: u64,
+    passes: usize,
+    complete: bool,
+}
+
+impl MsdRadixSort {
+    /// Create a new MsdRadixSort instance
+    pub fn new() -> Self {
+        Self::for_keys()
+    }
+}
+
+impl<T: RadixKey> MsdRadixSort<T> {
+    /// Create an MsdRadixSort instance for any element with a radix key
+    pub fn for_keys() -> Self {
+        Self {
+            ws: KeyWorkspace::new(),
+            scan: None,
+            stack: Vec::new(),
+            current: None,
+            offset: 0,
+            passes: 0,
+            complete: true,
+        }
+    }
+
+    fn digit(&self, level: usize) -> Digit {
+        Digit::Bits {
+            offset: self.offset,
+            shift: (self.passes - 1 - level) as u32 * RADIX_BITS,
+            bits: RADIX_BITS,
+        }
+    }
+
+    /// Run the scan and the pending distribution passes within `budget`
+    fn run(&mut self, budget: &mut usize) -> Option<()> {
+        if let Some(scan) = &mut self.scan {
+            let (min, max) = scan.run(&mut self.ws, budget)?;
+            self.scan = None;
+            self.offset = min;
+            self.passes = key_bits(min, max).div_ceil(RADIX_BITS) as usize;
+            self.stack.push(StackFrame {
+                begin: 0,
+                end: self.ws.data.len(),
+                level: 0,
+            });
+        }
+
+        loop {
+            let (frame, mut pass) = match self.current.take() {
+                Some(current) => current,
+                None => match self.stack.pop() {
+                    Some(frame) if frame.end - frame.begin <= 1 || frame.level >= self.passes => continue,
+                    Some(frame) => {
+                        let pass = CountingPass::new(frame.begin, frame.end, self.digit(frame.level));
+                        (frame, pass)
+                    }
+                    None => return Some(()),
+                },
+            };
+
+            if pass.run(&mut self.ws, budget).is_none() {
+                self.current = Some((frame, pass));
+                return None;
+            }
+
+            // Smallest bucket on top, so ranges finish left to right
+            for (low, high) in pass.buckets().into_iter().rev() {
+                self.stack.push(StackFrame {
+                    begin: low,
+                    end: high + 1,
+                    level: frame.level + 1,
+                });
+            }
+        }
+    }
+}
+
+impl Default for MsdRadixSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RadixKey> Sorter<T> for MsdRadixSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        if self.complete {
+            return StepResult {
+                comparisons_used: 0,
+                moves_made: 0,
+                continued: false,
+            };
+        }
+
+        let initial_moves = self.ws.moves;
+        let mut remaining_budget = budget;
+        if self.run(&mut remaining_budget).is_some() {
+            self.complete = true;
+        }
+
+        self.ws.track_memory(self.get_memory_usage());
+        if self.complete {
+            self.ws.release();
+        }
+
+        StepResult {
+            comparisons_used: 0,
+            moves_made: (self.ws.moves - initial_moves) as usize,
+            continued: !self.complete,
+        }
+    }
+
+    fn budget_unit(&self) -> BudgetUnit {
+        BudgetUnit::Operations
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers::default();
+        let last = self.ws.data.len().saturating_sub(1);
+
+        let status_text = match (&self.scan, &self.current) {
+            _ if self.complete => "Completed".to_string(),
+            (Some(scan), _) => {
+                markers.cursors = vec![scan.next.min(last)];
+                "Scanning for the smallest and largest key".to_string()
+            }
+            (None, Some((frame, pass))) => {
+                markers.digit_pass = Some((frame.level + 1, self.passes));
+                markers.cursors = vec![pass.next.min(frame.end - 1)];
+                if pass.phase != PassPhase::Counting {
+                    markers.buckets = pass.buckets();
+                }
+                format!(
+                    "Digit {}/{} of range [{}, {}): {}",
+                    frame.level + 1,
+                    self.passes,
+                    frame.begin,
+                    frame.end,
+                    pass_activity(pass)
+                )
+            }
+            (None, None) => "Processing".to_string(),
+        };
+
+        // Pending ranges, with the next one to be processed last
+        if !self.complete && markers.buckets.is_empty() {
+            markers.buckets = self.stack.iter().map(|frame| (frame.begin, frame.end - 1)).collect();
+        }
+
+        let progress_hint = if self.complete {
+            1.0
+        } else if let Some(scan) = &self.scan {
+            // The scan is one of several passes over the data
+            scan.next as f32 / self.ws.data.len().max(1) as f32 * 0.1
+        } else {
+            // Elements in pending or active ranges are not yet in their final place
+            let active = self.current.as_ref().map_or(0, |(frame, _)| frame.end - frame.begin);
+            let pending: usize = self
+                .stack
+                .iter()
+                .filter(|frame| frame.end - frame.begin > 1 && frame.level < self.passes)
+                .map(|frame| frame.end - frame.begin)
+                .sum::<usize>()
+                + active;
+            let progress = 1.0 - pending as f32 / self.ws.data.len() as f32;
+            if progress.is_finite() { progress.clamp(0.1, 1.0) } else { 0.1 }
+        };
+
+        Telemetry {
+            total_comparisons: 0,
+            total_moves: self.ws.moves,
+            total_writes: self.ws.writes,
+            parallel_depth: None,
+            memory_current: self.get_memory_usage(),
+            memory_peak: self.ws.memory_peak,
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text,
+            progress_hint,
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.ws.reset(data);
+        self.stack.clear();
+        self.current = None;
+        self.offset = 0;
+        self.passes = 0;
+        self.complete = self.ws.data.len() <= 1;
+        self.scan = if self.complete { None } else { Some(KeyScan::new(0, self.ws.data.len())) };
+    }
+
+    fn name(&self) -> &str {
+        "MSD Radix Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        self.ws.array()
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(self.ws.array_origins())
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + output buffer + active histogram + pending ranges
+        self.ws.data_bytes()
+            + self.ws.buffer_bytes()
+            + self.current.as_ref().map_or(0, |(_, pass)| pass.memory())
+            + self.stack.len() * std::mem::size_of::<StackFrame>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,30 +1,45 @@
 //! Selection Sort implementation
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
 
 /// Selection Sort algorithm implementation
 #[derive(Debug)]
-pub struct SelectionSort {
-    data: Vec<i32>,
+pub struct SelectionSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
     current_position: usize,
     search_index: usize,
     min_index: usize,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
 }
 
 impl SelectionSort {
     /// Create a new SelectionSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> SelectionSort<T> {
+    /// Create a SelectionSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
             current_position: 0,
             search_index: 1,
             min_index: 0,
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
         }
     }
@@ -36,31 +51,20 @@ impl Default for SelectionSort {
     }
 }
 
-impl Sorter for SelectionSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
-
-        let mut comparisons_used = 0;
-        let mut moves_made = 0;
-
-        while comparisons_used < budget && !self.complete {
+impl<T: Element> SelectionSort<T> {
+    /// Select and place minimums until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
             if self.current_position >= self.data.len() - 1 {
-                self.complete = true;
-                break;
+                return Some(());
             }
 
             if self.search_index < self.data.len() {
                 // Compare current element with minimum found so far
-                comparisons_used += 1;
+                budget.compare()?;
                 self.comparisons += 1;
 
-                if self.data[self.search_index] < self.data[self.min_index] {
+                if self.compare.less(&self.data[self.search_index], &self.data[self.min_index]) {
                     self.min_index = self.search_index;
                 }
 
@@ -68,9 +72,11 @@ impl Sorter for SelectionSort {
             } else {
                 // Found minimum for this pass, swap if necessary
                 if self.min_index != self.current_position {
+                    budget.shift()?;
                     self.data.swap(self.current_position, self.min_index);
-                    moves_made += 1;
+                    self.origins.swap(self.current_position, self.min_index);
                     self.moves += 1;
+                    self.writes += 2;
                 }
 
                 // Move to next position
@@ -81,12 +87,27 @@ impl Sorter for SelectionSort {
                 }
             }
         }
+    }
 
-        StepResult {
-            comparisons_used,
-            moves_made,
-            continued: !self.complete,
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for SelectionSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -111,6 +132,8 @@ impl Sorter for SelectionSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
@@ -132,13 +155,15 @@ impl Sorter for SelectionSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
+        self.origins = (0..self.data.len()).collect();
         self.current_position = 0;
         self.search_index = 1;
         self.min_index = 0;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
     }
 
@@ -146,13 +171,17 @@ impl Sorter for SelectionSort {
         "Selection Sort"
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
         // Report size of the data array in bytes
-        self.data.len() * std::mem::size_of::<i32>()
+        self.data.len() * std::mem::size_of::<T>()
     }
 
     fn as_any(&self) -> &dyn Any {
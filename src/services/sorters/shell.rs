@@ -1,47 +1,228 @@
 //! Shell Sort implementation
+//!
+//! Insertion sorts elements `gap` apart for a shrinking sequence of gaps,
+//! ending with a plain insertion sort. The gap sequence decides how much
+//! work the final passes are left with, and is configurable per lane.
 
-use crate::models::traits::{Sorter, StepResult, Telemetry, Markers};
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
 use std::any::Any;
+use std::iter::successors;
+
+/// Lane options understood by [`ShellSort::with_option`]
+pub const OPTIONS: [&str; 7] = ["knuth", "shell", "hibbard", "sedgewick", "tokuda", "ciura", "pratt"];
+
+/// Ciura's experimentally found gaps, extended by a factor of 2.25
+const CIURA_GAPS: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+
+/// Sequence of gaps used by the insertion passes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapSequence {
+    /// Shell's original halving: n/2, n/4, ..., 1
+    Shell,
+    /// Hibbard's 2^k - 1: 1, 3, 7, 15, ...
+    Hibbard,
+    /// Knuth's (3^k - 1) / 2: 1, 4, 13, 40, ...
+    #[default]
+    Knuth,
+    /// Sedgewick's 4^k + 3·2^(k-1) + 1: 1, 8, 23, 77, ...
+    Sedgewick,
+    /// Tokuda's ⌈(9^k - 4^k) / (5·4^(k-1))⌉: 1, 4, 9, 20, 46, ...
+    Tokuda,
+    /// Ciura's 1, 4, 10, 23, 57, 132, 301, 701, extended by 2.25
+    Ciura,
+    /// Pratt's 3-smooth numbers 2^p·3^q: 1, 2, 3, 4, 6, 8, 9, ...
+    Pratt,
+}
+
+impl GapSequence {
+    /// Get every sequence, in option order
+    pub fn all() -> [GapSequence; 7] {
+        [
+            GapSequence::Knuth,
+            GapSequence::Shell,
+            GapSequence::Hibbard,
+            GapSequence::Sedgewick,
+            GapSequence::Tokuda,
+            GapSequence::Ciura,
+            GapSequence::Pratt,
+        ]
+    }
+
+    /// Parse a lane option, e.g. `ciura`
+    pub fn from_key(key: &str) -> Option<GapSequence> {
+        Self::all().into_iter().find(|sequence| sequence.key() == key)
+    }
+
+    /// Lane option selecting this sequence
+    pub fn key(self) -> &'static str {
+        match self {
+            GapSequence::Shell => "shell",
+            GapSequence::Hibbard => "hibbard",
+            GapSequence::Knuth => "knuth",
+            GapSequence::Sedgewick => "sedgewick",
+            GapSequence::Tokuda => "tokuda",
+            GapSequence::Ciura => "ciura",
+            GapSequence::Pratt => "pratt",
+        }
+    }
+
+    /// Label used in lane names
+    pub fn label(self) -> &'static str {
+        match self {
+            GapSequence::Shell => "Shell",
+            GapSequence::Hibbard => "Hibbard",
+            GapSequence::Knuth => "Knuth",
+            GapSequence::Sedgewick => "Sedgewick",
+            GapSequence::Tokuda => "Tokuda",
+            GapSequence::Ciura => "Ciura",
+            GapSequence::Pratt => "Pratt",
+        }
+    }
+
+    /// Gaps for an array of `n` elements, largest first and ending with 1
+    pub fn gaps(self, n: usize) -> Vec<usize> {
+        let mut gaps: Vec<usize> = match self {
+            GapSequence::Shell => successors(Some(n / 2), |&gap| Some(gap / 2)).take_while(|&gap| gap > 0).collect(),
+            GapSequence::Knuth => {
+                // The first gap may reach n / 3, as in Knuth's description
+                let mut gap = 1;
+                while gap < n / 3 {
+                    gap = gap * 3 + 1;
+                }
+                successors(Some(gap), |&gap| (gap > 1).then(|| (gap - 1) / 3)).collect()
+            }
+            GapSequence::Hibbard => Self::below(n, (1..).map(|k: u32| 2usize.saturating_pow(k) - 1)),
+            GapSequence::Sedgewick => Self::below(
+                n,
+                std::iter::once(1).chain((1..).map(|k: u32| {
+                    4usize.saturating_pow(k).saturating_add(3 * 2usize.saturating_pow(k - 1)) + 1
+                })),
+            ),
+            GapSequence::Tokuda => Self::below(
+                n,
+                successors(Some(1.0f64), |&gap| Some(gap * 2.25 + 1.0)).map(|gap| gap.ceil() as usize),
+            ),
+            GapSequence::Ciura => Self::below(
+                n,
+                CIURA_GAPS
+                    .into_iter()
+                    .chain(successors(Some(CIURA_GAPS[7]), |&gap| Some(gap * 9 / 4)).skip(1)),
+            ),
+            GapSequence::Pratt => {
+                let mut gaps = Vec::new();
+                let mut power_of_two = 1usize;
+                while power_of_two < n {
+                    let mut gap = power_of_two;
+                    while gap < n {
+                        gaps.push(gap);
+                        gap = gap.saturating_mul(3);
+                    }
+                    power_of_two = power_of_two.saturating_mul(2);
+                }
+                gaps.sort_unstable_by(|a, b| b.cmp(a));
+                gaps
+            }
+        };
+
+        if gaps.is_empty() {
+            gaps.push(1);
+        }
+        gaps
+    }
+
+    /// Collect the gaps of an increasing sequence that are below `n`, largest first
+    fn below(n: usize, increasing: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut gaps: Vec<usize> = increasing.take_while(|&gap| gap < n).collect();
+        gaps.reverse();
+        gaps
+    }
+}
 
 /// Shell Sort algorithm implementation
 #[derive(Debug)]
-pub struct ShellSort {
-    data: Vec<i32>,
+pub struct ShellSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    sequence: GapSequence,
+    /// Gaps for the current array, largest first
+    gaps: Vec<usize>,
+    /// Index of the current gap
+    pass: usize,
     gap: usize,
     current_pos: usize,
     insertion_pos: usize,
     comparisons: u64,
     moves: u64,
+    writes: u64,
     complete: bool,
     in_insertion: bool,
+    /// The element at `insertion_pos` belongs before the one a gap back but
+    /// is not yet swapped
+    swap_due: bool,
+    name: String,
 }
 
 impl ShellSort {
     /// Create a new ShellSort instance
     pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> ShellSort<T> {
+    /// Create a ShellSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
         Self {
             data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            sequence: GapSequence::default(),
+            gaps: Vec::new(),
+            pass: 0,
             gap: 0,
             current_pos: 0,
             insertion_pos: 0,
             comparisons: 0,
             moves: 0,
+            writes: 0,
             complete: false,
             in_insertion: false,
+            swap_due: false,
+            name: "Shell Sort".to_string(),
         }
     }
 
-    /// Generate gap sequence using Knuth's sequence: 1, 4, 13, 40, 121, ...
-    fn generate_initial_gap(n: usize) -> usize {
-        let mut gap = 1;
-        while gap < n / 3 {
-            gap = gap * 3 + 1;
-        }
-        gap
+    /// Use `sequence` for the gaps
+    ///
+    /// Knuth's sequence is the default and keeps the plain `Shell Sort` name;
+    /// other sequences are named after their author, e.g. `Shell Sort (Ciura)`.
+    pub fn with_gaps(mut self, sequence: GapSequence) -> Self {
+        self.sequence = sequence;
+        self.name = if sequence == GapSequence::default() {
+            "Shell Sort".to_string()
+        } else {
+            format!("Shell Sort ({})", sequence.label())
+        };
+        self
+    }
+
+    /// Apply a lane option from [`OPTIONS`], or `None` if it is unknown
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        GapSequence::from_key(option).map(|sequence| self.with_gaps(sequence))
+    }
+
+    /// Get the gap sequence
+    pub fn gap_sequence(&self) -> GapSequence {
+        self.sequence
     }
 
-    fn next_gap(current_gap: usize) -> usize {
-        (current_gap - 1) / 3
+    /// Move on to the next gap, or 0 once the last pass is done
+    fn next_gap(&mut self) -> usize {
+        self.pass += 1;
+        self.gaps.get(self.pass).copied().unwrap_or(0)
     }
 }
 
@@ -51,31 +232,19 @@ impl Default for ShellSort {
     }
 }
 
-impl Sorter for ShellSort {
-    fn step(&mut self, budget: usize) -> StepResult {
-        if self.complete || self.data.len() <= 1 {
-            return StepResult {
-                comparisons_used: 0,
-                moves_made: 0,
-                continued: false,
-            };
-        }
-
-        let initial_comparisons = self.comparisons;
-        let initial_moves = self.moves;
-        let mut remaining_budget = budget;
-
-        while remaining_budget > 0 && !self.complete {
+impl<T: Element> ShellSort<T> {
+    /// Run the insertion passes until sorted, or `None` if the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
             if self.gap == 0 {
-                self.complete = true;
-                break;
+                return Some(());
             }
 
             if !self.in_insertion {
                 // Start new element insertion
                 if self.current_pos >= self.data.len() {
                     // Move to next gap
-                    self.gap = Self::next_gap(self.gap);
+                    self.gap = self.next_gap();
                     self.current_pos = self.gap;
                     continue;
                 }
@@ -84,35 +253,52 @@ impl Sorter for ShellSort {
                 self.in_insertion = true;
             }
 
-            if self.in_insertion {
-                if self.insertion_pos >= self.gap {
-                    // Compare with element at gap distance
-                    remaining_budget -= 1;
-                    self.comparisons += 1;
-
-                    if self.data[self.insertion_pos] < self.data[self.insertion_pos - self.gap] {
-                        // Swap elements
-                        self.data.swap(self.insertion_pos, self.insertion_pos - self.gap);
-                        self.moves += 1;
-                        self.insertion_pos -= self.gap;
-                    } else {
-                        // Found correct position
-                        self.in_insertion = false;
-                        self.current_pos += 1;
-                    }
-                } else {
-                    // Reached the beginning for this gap
+            if self.swap_due {
+                budget.shift()?;
+                self.data.swap(self.insertion_pos, self.insertion_pos - self.gap);
+                self.origins.swap(self.insertion_pos, self.insertion_pos - self.gap);
+                self.moves += 1;
+                self.writes += 2;
+                self.insertion_pos -= self.gap;
+                self.swap_due = false;
+            } else if self.insertion_pos >= self.gap {
+                // Compare with element at gap distance
+                budget.compare()?;
+                self.comparisons += 1;
+
+                self.swap_due = self.compare.less(&self.data[self.insertion_pos], &self.data[self.insertion_pos - self.gap]);
+                if !self.swap_due {
+                    // Found correct position
                     self.in_insertion = false;
                     self.current_pos += 1;
                 }
+            } else {
+                // Reached the beginning for this gap
+                self.in_insertion = false;
+                self.current_pos += 1;
             }
         }
+    }
 
-        StepResult {
-            comparisons_used: (self.comparisons - initial_comparisons) as usize,
-            moves_made: (self.moves - initial_moves) as usize,
-            continued: !self.complete,
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
         }
+        budget.result(!self.complete)
+    }
+}
+
+impl<T: Element> Sorter<T> for ShellSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
     }
 
     fn is_complete(&self) -> bool {
@@ -138,6 +324,8 @@ impl Sorter for ShellSort {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
             memory_current: self.get_memory_usage(),
             memory_peak: self.get_memory_usage(),
             highlights: markers.cursors.clone(),
@@ -145,15 +333,27 @@ impl Sorter for ShellSort {
             status_text: if self.complete {
                 "Completed".to_string()
             } else if self.in_insertion {
-                format!("Gap {}, inserting at position {}", self.gap, self.insertion_pos)
+                format!(
+                    "Gap {} ({}/{}), inserting at position {}",
+                    self.gap,
+                    self.pass + 1,
+                    self.gaps.len(),
+                    self.insertion_pos
+                )
             } else {
-                format!("Gap {}, processing position {}", self.gap, self.current_pos)
+                format!(
+                    "Gap {} ({}/{}), processing position {}",
+                    self.gap,
+                    self.pass + 1,
+                    self.gaps.len(),
+                    self.current_pos
+                )
             },
             progress_hint: if self.data.len() <= 1 {
                 1.0
             } else {
                 // Estimate progress based on gap reduction
-                let initial_gap = Self::generate_initial_gap(self.data.len()) as f32;
+                let initial_gap = self.gaps.first().copied().unwrap_or(0) as f32;
                 let current_gap = self.gap as f32;
                 if initial_gap == 0.0 {
                     1.0
@@ -165,28 +365,37 @@ impl Sorter for ShellSort {
         }
     }
 
-    fn reset(&mut self, data: Vec<i32>) {
+    fn reset(&mut self, data: Vec<T>) {
         self.data = data;
-        self.gap = Self::generate_initial_gap(self.data.len());
+        self.origins = (0..self.data.len()).collect();
+        self.gaps = self.sequence.gaps(self.data.len());
+        self.pass = 0;
+        self.gap = self.gaps[0];
         self.current_pos = self.gap;
         self.insertion_pos = 0;
         self.comparisons = 0;
         self.moves = 0;
+        self.writes = 0;
         self.complete = self.data.len() <= 1;
         self.in_insertion = false;
+        self.swap_due = false;
     }
 
     fn name(&self) -> &str {
-        "Shell Sort"
+        &self.name
     }
 
-    fn get_array(&self) -> &[i32] {
+    fn get_array(&self) -> &[T] {
         &self.data
     }
 
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
     fn get_memory_usage(&self) -> usize {
-        // Report size of the data array in bytes
-        self.data.len() * std::mem::size_of::<i32>()
+        // Data array + gap sequence
+        self.data.len() * std::mem::size_of::<T>() + self.gaps.len() * std::mem::size_of::<usize>()
     }
 
     fn as_any(&self) -> &dyn Any {
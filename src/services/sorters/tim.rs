@@ -0,0 +1,805 @@
+//! Tim Sort implementation
+//!
+//! Follows the layout of Java's `TimSort`: natural runs are detected and
+//! extended to `minrun` with binary insertion, pushed onto a run stack whose
+//! length invariants decide the next merge, and merges switch to galloping
+//! once one side keeps winning. Every search and every copy is resumable, so
+//! a step never spends more than its budget, counted in comparisons or in
+//! operations. Merges always buffer the left run and fill from the left.
+
+use crate::models::element::{Comparator, Element};
+use crate::models::traits::{Granularity, Sorter, StepResult, Telemetry, Markers};
+use crate::services::sorters::budget::Budget;
+use std::any::Any;
+
+/// Arrays shorter than this are sorted as a single binary-insertion run
+const MIN_MERGE: usize = 32;
+
+/// Consecutive wins by one run before a merge starts galloping
+const MIN_GALLOP: usize = 7;
+
+/// A sorted run on the merge stack
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+impl Run {
+    fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// Array a gallop searches
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Data,
+    Temp,
+}
+
+/// Resumable exponential search from the left of a sorted range
+///
+/// Counts the leading elements that sort before `key`, or that do not sort
+/// after it when `inclusive` is set.
+#[derive(Debug, Clone)]
+struct Gallop<T> {
+    key: T,
+    side: Side,
+    base: usize,
+    len: usize,
+    inclusive: bool,
+    /// Lower bound of the answer; the binary search's `lo`
+    last_ofs: usize,
+    /// Next probe offset, or the binary search's `hi`
+    ofs: usize,
+    binary: bool,
+}
+
+impl<T> Gallop<T> {
+    fn new(key: T, side: Side, base: usize, len: usize, inclusive: bool) -> Self {
+        Self {
+            key,
+            side,
+            base,
+            len,
+            inclusive,
+            last_ofs: 0,
+            ofs: 1,
+            binary: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MergeMode<T> {
+    /// One comparison per element
+    Linear,
+    /// Counting left-run elements that go before the next right-run element
+    GallopLeft(Option<Gallop<T>>),
+    /// Counting right-run elements that go before the next left-run element
+    GallopRight {
+        gallop: Option<Gallop<T>>,
+        left_count: usize,
+    },
+}
+
+/// Copies a merge owes for the searches that decided them
+#[derive(Debug, Clone, Copy, Default)]
+struct Owed {
+    left: usize,
+    right: usize,
+    /// Copy the left elements before the right ones
+    left_first: bool,
+}
+
+/// Merge of the runs at `at` and `at + 1`, with the left run buffered in `temp`
+#[derive(Debug, Clone)]
+struct MergeState<T> {
+    at: usize,
+    dest: usize,
+    a: usize,
+    b: usize,
+    b_end: usize,
+    wins_a: usize,
+    wins_b: usize,
+    mode: MergeMode<T>,
+    owed: Owed,
+}
+
+#[derive(Debug, Clone)]
+enum TimPhase<T> {
+    /// Scanning a natural run; `end` is one past the last element in it so far
+    FindRun {
+        start: usize,
+        end: usize,
+        descending: Option<bool>,
+    },
+    /// Reversing the strictly descending run `[start, end)`; `[lo, hi)` is
+    /// the part not yet swapped
+    Reverse {
+        start: usize,
+        end: usize,
+        lo: usize,
+        hi: usize,
+    },
+    /// Binary-inserting elements until the run reaches `target`
+    ExtendRun {
+        start: usize,
+        end: usize,
+        target: usize,
+        lo: usize,
+        hi: usize,
+        /// Element being inserted, with its origin, while the larger ones
+        /// shift up; `hi` is then the next slot to fill
+        held: Option<(T, usize)>,
+    },
+    /// Checking the run stack invariants for the next merge
+    Collapse,
+    /// Skipping the prefix of the left run that is already in place
+    TrimLeft { at: usize, gallop: Gallop<T> },
+    /// Skipping the suffix of the right run that is already in place
+    TrimRight {
+        at: usize,
+        a_start: usize,
+        a_len: usize,
+        gallop: Gallop<T>,
+    },
+    Merge(MergeState<T>),
+    Done,
+}
+
+/// Tim Sort algorithm implementation
+#[derive(Debug)]
+pub struct TimSort<T = i32> {
+    data: Vec<T>,
+    compare: Comparator<T>,
+    origins: Vec<usize>,
+    temp: Vec<T>,
+    temp_origins: Vec<usize>,
+    stack: Vec<Run>,
+    runs: Vec<(usize, usize)>,
+    phase: TimPhase<T>,
+    min_run: usize,
+    min_gallop: usize,
+    scanned: usize,
+    comparisons: u64,
+    moves: u64,
+    writes: u64,
+    complete: bool,
+    peak_memory: usize,
+}
+
+impl TimSort {
+    /// Create a new TimSort instance
+    pub fn new() -> Self {
+        Self::with_comparator(Comparator::natural())
+    }
+}
+
+impl<T> TimSort<T> {
+    /// Create a TimSort instance ordering elements with `compare`
+    pub fn with_comparator(compare: Comparator<T>) -> Self {
+        Self {
+            data: Vec::new(),
+            compare,
+            origins: Vec::new(),
+            temp: Vec::new(),
+            temp_origins: Vec::new(),
+            stack: Vec::new(),
+            runs: Vec::new(),
+            phase: TimPhase::Done,
+            min_run: 0,
+            min_gallop: MIN_GALLOP,
+            scanned: 0,
+            comparisons: 0,
+            moves: 0,
+            writes: 0,
+            complete: false,
+            peak_memory: 0,
+        }
+    }
+
+    /// Shortest run worth merging for an array of `n` elements
+    fn min_run_length(mut n: usize) -> usize {
+        let mut remainder = 0;
+        while n >= MIN_MERGE {
+            remainder |= n & 1;
+            n >>= 1;
+        }
+        n + remainder
+    }
+
+    /// Stack index of the next merge, if the invariants call for one
+    fn next_merge(&self) -> Option<usize> {
+        let runs = &self.stack;
+        if runs.len() < 2 {
+            return None;
+        }
+
+        let mut n = runs.len() - 2;
+        if self.scanned < self.data.len() {
+            if (n > 0 && runs[n - 1].len <= runs[n].len + runs[n + 1].len)
+                || (n > 1 && runs[n - 2].len <= runs[n - 1].len + runs[n].len)
+            {
+                if runs[n - 1].len < runs[n + 1].len {
+                    n -= 1;
+                }
+            } else if runs[n].len > runs[n + 1].len {
+                return None;
+            }
+        } else if n > 0 && runs[n - 1].len < runs[n + 1].len {
+            // Input exhausted: force-collapse the whole stack
+            n -= 1;
+        }
+
+        Some(n)
+    }
+
+    fn auxiliary_memory(&self) -> usize {
+        self.temp.capacity() * std::mem::size_of::<T>()
+            + self.temp_origins.capacity() * std::mem::size_of::<usize>()
+            + self.stack.capacity() * std::mem::size_of::<Run>()
+    }
+
+    fn is_galloping(&self) -> bool {
+        matches!(
+            &self.phase,
+            TimPhase::Merge(MergeState { mode: MergeMode::GallopLeft(_) | MergeMode::GallopRight { .. }, .. })
+        )
+    }
+}
+
+impl<T: Clone> TimSort<T> {
+    /// Advance `gallop` within the budget, returning its count once found
+    fn gallop(&mut self, gallop: &mut Gallop<T>, budget: &mut Budget) -> Option<usize> {
+        loop {
+            if gallop.binary {
+                if gallop.last_ofs >= gallop.ofs {
+                    return Some(gallop.last_ofs);
+                }
+            } else if gallop.ofs > gallop.len {
+                gallop.binary = true;
+                gallop.ofs = gallop.len;
+                continue;
+            }
+
+            budget.compare()?;
+            self.comparisons += 1;
+
+            let probe = if gallop.binary {
+                gallop.last_ofs + (gallop.ofs - gallop.last_ofs) / 2
+            } else {
+                gallop.ofs - 1
+            };
+            let source = match gallop.side {
+                Side::Data => &self.data,
+                Side::Temp => &self.temp,
+            };
+            let element = &source[gallop.base + probe];
+            let before_key = if gallop.inclusive {
+                !self.compare.less(&gallop.key, element)
+            } else {
+                self.compare.less(element, &gallop.key)
+            };
+
+            match (gallop.binary, before_key) {
+                (true, true) => gallop.last_ofs = probe + 1,
+                (true, false) => gallop.ofs = probe,
+                (false, true) => {
+                    gallop.last_ofs = gallop.ofs;
+                    gallop.ofs = gallop.ofs * 2 + 1;
+                }
+                (false, false) => {
+                    gallop.binary = true;
+                    gallop.ofs = probe;
+                }
+            }
+        }
+    }
+
+    fn find_run(&mut self, start: usize, mut end: usize, mut descending: Option<bool>, budget: &mut Budget) -> Option<()> {
+        while end < self.data.len() {
+            if budget.compare().is_none() {
+                self.phase = TimPhase::FindRun { start, end, descending };
+                return None;
+            }
+            self.comparisons += 1;
+
+            // Only strictly descending runs are reversed, which keeps ties in order
+            let falling = self.compare.less(&self.data[end], &self.data[end - 1]);
+            match descending {
+                None => descending = Some(falling),
+                Some(direction) if direction != falling => break,
+                Some(_) => {}
+            }
+            end += 1;
+        }
+
+        if descending == Some(true) {
+            self.phase = TimPhase::Reverse { start, end, lo: start, hi: end };
+        } else {
+            self.found_run(start, end);
+        }
+        Some(())
+    }
+
+    fn reverse_run(&mut self, start: usize, end: usize, mut lo: usize, mut hi: usize, budget: &mut Budget) -> Option<()> {
+        while lo + 1 < hi {
+            if budget.shift().is_none() {
+                self.phase = TimPhase::Reverse { start, end, lo, hi };
+                return None;
+            }
+            self.data.swap(lo, hi - 1);
+            self.origins.swap(lo, hi - 1);
+            self.moves += 1;
+            self.writes += 2;
+            lo += 1;
+            hi -= 1;
+        }
+
+        self.found_run(start, end);
+        Some(())
+    }
+
+    /// Record the ascending run `[start, end)`, extending it if it is short
+    fn found_run(&mut self, start: usize, end: usize) {
+        self.runs.push((start, end - 1));
+
+        let target = (start + self.min_run).min(self.data.len());
+        if end < target {
+            self.phase = TimPhase::ExtendRun { start, end, target, lo: start, hi: end, held: None };
+        } else {
+            self.push_run(start, end);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn extend_run(
+        &mut self,
+        start: usize,
+        mut end: usize,
+        target: usize,
+        mut lo: usize,
+        mut hi: usize,
+        mut held: Option<(T, usize)>,
+        budget: &mut Budget,
+    ) -> Option<()> {
+        while end < target {
+            match held.take() {
+                None if lo < hi => {
+                    if budget.compare().is_none() {
+                        self.phase = TimPhase::ExtendRun { start, end, target, lo, hi, held };
+                        return None;
+                    }
+                    self.comparisons += 1;
+
+                    // Insert after equal elements to stay stable
+                    let mid = lo + (hi - lo) / 2;
+                    if self.compare.less(&self.data[end], &self.data[mid]) {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                None => {
+                    // Found the slot: lift the element out, then shift the larger ones up
+                    held = Some((self.data[end].clone(), self.origins[end]));
+                    hi = end;
+                }
+                Some(item) if hi > lo => {
+                    held = Some(item);
+                    if budget.shift().is_none() {
+                        self.phase = TimPhase::ExtendRun { start, end, target, lo, hi, held };
+                        return None;
+                    }
+                    self.data[hi] = self.data[hi - 1].clone();
+                    self.origins[hi] = self.origins[hi - 1];
+                    self.moves += 1;
+                    self.writes += 1;
+                    hi -= 1;
+                }
+                Some((element, origin)) => {
+                    self.data[lo] = element;
+                    self.origins[lo] = origin;
+                    self.writes += 1;
+                    end += 1;
+                    lo = start;
+                    hi = end;
+                }
+            }
+        }
+
+        self.push_run(start, end);
+        Some(())
+    }
+
+    fn push_run(&mut self, start: usize, end: usize) {
+        self.stack.push(Run { start, len: end - start });
+        self.scanned = end;
+        self.phase = TimPhase::Collapse;
+    }
+
+    fn collapse(&mut self) {
+        if let Some(at) = self.next_merge() {
+            let key = self.data[self.stack[at + 1].start].clone();
+            let left = self.stack[at];
+            self.phase = TimPhase::TrimLeft {
+                at,
+                gallop: Gallop::new(key, Side::Data, left.start, left.len, true),
+            };
+        } else if self.scanned < self.data.len() {
+            self.phase = TimPhase::FindRun {
+                start: self.scanned,
+                end: self.scanned + 1,
+                descending: None,
+            };
+        } else {
+            self.phase = TimPhase::Done;
+            self.complete = true;
+        }
+    }
+
+    fn trim_left(&mut self, at: usize, mut gallop: Gallop<T>, budget: &mut Budget) -> Option<()> {
+        let Some(in_place) = self.gallop(&mut gallop, budget) else {
+            self.phase = TimPhase::TrimLeft { at, gallop };
+            return None;
+        };
+
+        let left = self.stack[at];
+        let right = self.stack[at + 1];
+        let a_start = left.start + in_place;
+        let a_len = left.len - in_place;
+        if a_len == 0 {
+            self.finish_merge(at);
+            return Some(());
+        }
+
+        let key = self.data[a_start + a_len - 1].clone();
+        self.phase = TimPhase::TrimRight {
+            at,
+            a_start,
+            a_len,
+            gallop: Gallop::new(key, Side::Data, right.start, right.len, false),
+        };
+        Some(())
+    }
+
+    fn trim_right(&mut self, at: usize, a_start: usize, a_len: usize, mut gallop: Gallop<T>, budget: &mut Budget) -> Option<()> {
+        let Some(b_len) = self.gallop(&mut gallop, budget) else {
+            self.phase = TimPhase::TrimRight { at, a_start, a_len, gallop };
+            return None;
+        };
+        if b_len == 0 {
+            self.finish_merge(at);
+            return Some(());
+        }
+
+        self.temp.clear();
+        self.temp.extend_from_slice(&self.data[a_start..a_start + a_len]);
+        self.temp_origins.clear();
+        self.temp_origins.extend_from_slice(&self.origins[a_start..a_start + a_len]);
+        self.writes += a_len as u64;
+        self.peak_memory = self.peak_memory.max(self.auxiliary_memory());
+
+        let b = self.stack[at + 1].start;
+        self.phase = TimPhase::Merge(MergeState {
+            at,
+            dest: a_start,
+            a: 0,
+            b,
+            b_end: b + b_len,
+            wins_a: 0,
+            wins_b: 0,
+            mode: MergeMode::Linear,
+            owed: Owed::default(),
+        });
+        Some(())
+    }
+
+    fn take_left(&mut self, merge: &mut MergeState<T>) {
+        self.data[merge.dest] = self.temp[merge.a].clone();
+        self.origins[merge.dest] = self.temp_origins[merge.a];
+        merge.dest += 1;
+        merge.a += 1;
+        self.moves += 1;
+        self.writes += 1;
+    }
+
+    fn take_right(&mut self, merge: &mut MergeState<T>) {
+        self.data[merge.dest] = self.data[merge.b].clone();
+        self.origins[merge.dest] = self.origins[merge.b];
+        merge.dest += 1;
+        merge.b += 1;
+        self.moves += 1;
+        self.writes += 1;
+    }
+
+    fn merge(&mut self, mut merge: MergeState<T>, budget: &mut Budget) -> Option<()> {
+        loop {
+            // Copy what the last search decided, one element per move
+            while merge.owed.left + merge.owed.right > 0 {
+                if budget.shift().is_none() {
+                    self.phase = TimPhase::Merge(merge);
+                    return None;
+                }
+                if merge.owed.left > 0 && (merge.owed.left_first || merge.owed.right == 0) {
+                    merge.owed.left -= 1;
+                    self.take_left(&mut merge);
+                } else {
+                    merge.owed.right -= 1;
+                    self.take_right(&mut merge);
+                }
+            }
+
+            if merge.a == self.temp.len() {
+                // The rest of the right run is already in place
+                self.finish_merge(merge.at);
+                return Some(());
+            }
+            if merge.b == merge.b_end {
+                merge.owed.left = self.temp.len() - merge.a;
+                continue;
+            }
+
+            match std::mem::replace(&mut merge.mode, MergeMode::Linear) {
+                MergeMode::Linear => {
+                    if budget.compare().is_none() {
+                        break;
+                    }
+                    self.comparisons += 1;
+
+                    // Ties take from the left run, which keeps the sort stable
+                    if self.compare.less(&self.data[merge.b], &self.temp[merge.a]) {
+                        merge.owed.right = 1;
+                        merge.wins_b += 1;
+                        merge.wins_a = 0;
+                    } else {
+                        merge.owed.left = 1;
+                        merge.wins_a += 1;
+                        merge.wins_b = 0;
+                    }
+
+                    if merge.wins_a.max(merge.wins_b) >= self.min_gallop {
+                        merge.wins_a = 0;
+                        merge.wins_b = 0;
+                        merge.mode = MergeMode::GallopLeft(None);
+                    }
+                }
+                MergeMode::GallopLeft(gallop) => {
+                    let mut gallop = gallop.unwrap_or_else(|| {
+                        let len = self.temp.len() - merge.a;
+                        Gallop::new(self.data[merge.b].clone(), Side::Temp, merge.a, len, true)
+                    });
+                    let Some(left_count) = self.gallop(&mut gallop, budget) else {
+                        merge.mode = MergeMode::GallopLeft(Some(gallop));
+                        break;
+                    };
+
+                    // Every counted left element goes first, then the key itself
+                    merge.owed = Owed {
+                        left: left_count,
+                        right: usize::from(merge.a + left_count < self.temp.len()),
+                        left_first: true,
+                    };
+                    merge.mode = MergeMode::GallopRight { gallop: None, left_count };
+                }
+                MergeMode::GallopRight { gallop, left_count } => {
+                    let mut gallop = gallop.unwrap_or_else(|| {
+                        let len = merge.b_end - merge.b;
+                        Gallop::new(self.temp[merge.a].clone(), Side::Data, merge.b, len, false)
+                    });
+                    let Some(right_count) = self.gallop(&mut gallop, budget) else {
+                        merge.mode = MergeMode::GallopRight { gallop: Some(gallop), left_count };
+                        break;
+                    };
+
+                    merge.owed = Owed { left: 1, right: right_count, left_first: false };
+
+                    if left_count < MIN_GALLOP && right_count < MIN_GALLOP {
+                        // Galloping stopped paying off; make it harder to re-enter
+                        self.min_gallop += 1;
+                        merge.mode = MergeMode::Linear;
+                    } else {
+                        self.min_gallop = self.min_gallop.saturating_sub(1).max(1);
+                        merge.mode = MergeMode::GallopLeft(None);
+                    }
+                }
+            }
+        }
+
+        self.phase = TimPhase::Merge(merge);
+        None
+    }
+
+    /// Work through the phases until sorted or the budget runs out
+    fn run(&mut self, budget: &mut Budget) -> Option<()> {
+        loop {
+            match std::mem::replace(&mut self.phase, TimPhase::Done) {
+                TimPhase::FindRun { start, end, descending } => self.find_run(start, end, descending, budget)?,
+                TimPhase::Reverse { start, end, lo, hi } => self.reverse_run(start, end, lo, hi, budget)?,
+                TimPhase::ExtendRun { start, end, target, lo, hi, held } => {
+                    self.extend_run(start, end, target, lo, hi, held, budget)?
+                }
+                TimPhase::Collapse => self.collapse(),
+                TimPhase::TrimLeft { at, gallop } => self.trim_left(at, gallop, budget)?,
+                TimPhase::TrimRight { at, a_start, a_len, gallop } => {
+                    self.trim_right(at, a_start, a_len, gallop, budget)?
+                }
+                TimPhase::Merge(merge) => self.merge(merge, budget)?,
+                TimPhase::Done => return Some(()),
+            }
+        }
+    }
+
+    fn step_with(&mut self, mut budget: Budget) -> StepResult {
+        if !self.complete && self.data.len() > 1 {
+            self.complete = self.run(&mut budget).is_some();
+        }
+        budget.result(!self.complete)
+    }
+
+    fn finish_merge(&mut self, at: usize) {
+        let right = self.stack.remove(at + 1);
+        self.stack[at].len += right.len;
+        self.temp.clear();
+        self.temp_origins.clear();
+        self.phase = TimPhase::Collapse;
+    }
+}
+
+impl Default for TimSort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Element> Sorter<T> for TimSort<T> {
+    fn step(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::comparisons(budget))
+    }
+
+    fn step_operations(&mut self, budget: usize) -> StepResult {
+        self.step_with(Budget::operations(budget))
+    }
+
+    fn granularity(&self) -> Granularity {
+        Granularity::Operation
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn get_telemetry(&self) -> Telemetry {
+        let mut markers = Markers {
+            runs: self.runs.clone(),
+            galloping: self.is_galloping(),
+            ..Default::default()
+        };
+
+        if !self.complete {
+            markers.run_stack = self
+                .stack
+                .iter()
+                .map(|run| (run.start, run.end() - 1))
+                .collect();
+
+            match &self.phase {
+                TimPhase::FindRun { end, .. } if *end < self.data.len() => {
+                    markers.cursors = vec![end - 1, *end];
+                }
+                TimPhase::Reverse { lo, hi, .. } if lo + 1 < *hi => {
+                    markers.cursors = vec![*lo, hi - 1];
+                }
+                TimPhase::ExtendRun { end, lo, hi, .. } if *end < self.data.len() => {
+                    markers.cursors = vec![lo + (hi - lo) / 2, *end];
+                }
+                TimPhase::TrimLeft { at, .. } | TimPhase::TrimRight { at, .. } => {
+                    let (left, right) = (self.stack[*at], self.stack[at + 1]);
+                    markers.merge_runs = vec![(left.start, left.end() - 1), (right.start, right.end() - 1)];
+                }
+                TimPhase::Merge(merge) => {
+                    let (left, right) = (self.stack[merge.at], self.stack[merge.at + 1]);
+                    markers.merge_runs = vec![(left.start, left.end() - 1), (right.start, right.end() - 1)];
+                    markers.cursors.push(merge.dest);
+                    if merge.b < merge.b_end {
+                        markers.cursors.push(merge.b);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Telemetry {
+            total_comparisons: self.comparisons,
+            total_moves: self.moves,
+            total_writes: self.writes,
+            parallel_depth: None,
+            memory_current: self.auxiliary_memory(),
+            memory_peak: self.peak_memory.max(self.auxiliary_memory()),
+            highlights: markers.cursors.clone(),
+            markers,
+            status_text: match &self.phase {
+                _ if self.complete => "Completed".to_string(),
+                TimPhase::FindRun { start, .. } => format!("Scanning natural run from {}", start),
+                TimPhase::Reverse { start, end, .. } => format!("Reversing descending run [{}, {})", start, end),
+                TimPhase::ExtendRun { start, target, .. } => {
+                    format!("Extending run [{}, {}) to minrun {}", start, target, self.min_run)
+                }
+                TimPhase::Collapse => format!("Checking run stack ({} runs)", self.stack.len()),
+                TimPhase::TrimLeft { at, .. } | TimPhase::TrimRight { at, .. } => {
+                    format!("Trimming merge of runs {} and {}", at, at + 1)
+                }
+                TimPhase::Merge(merge) => {
+                    let (left, right) = (self.stack[merge.at], self.stack[merge.at + 1]);
+                    format!(
+                        "Merging runs [{}, {}) and [{}, {}){}",
+                        left.start,
+                        left.end(),
+                        right.start,
+                        right.end(),
+                        if self.is_galloping() { ", galloping" } else { "" }
+                    )
+                }
+                TimPhase::Done => "Completed".to_string(),
+            },
+            progress_hint: if self.complete || self.data.len() <= 1 {
+                1.0
+            } else {
+                // Half for scanning runs, half for merging them back down to one
+                let scanned = self.scanned as f32 / self.data.len() as f32;
+                let pending = self.stack.len().saturating_sub(1) as f32 / self.runs.len().max(1) as f32;
+                let progress = scanned * 0.5 + (1.0 - pending) * scanned * 0.5;
+                if progress.is_finite() { progress.min(1.0).max(0.0) } else { 0.0 }
+            },
+        }
+    }
+
+    fn reset(&mut self, data: Vec<T>) {
+        self.data = data;
+        self.origins = (0..self.data.len()).collect();
+        self.temp = Vec::with_capacity(self.data.len() / 2);
+        self.temp_origins = Vec::with_capacity(self.data.len() / 2);
+        self.stack.clear();
+        self.runs.clear();
+        self.min_run = Self::min_run_length(self.data.len());
+        self.min_gallop = MIN_GALLOP;
+        self.scanned = 0;
+        self.comparisons = 0;
+        self.moves = 0;
+        self.writes = 0;
+        self.complete = self.data.len() <= 1;
+        self.phase = if self.complete {
+            TimPhase::Done
+        } else {
+            TimPhase::FindRun { start: 0, end: 1, descending: None }
+        };
+        self.peak_memory = self.auxiliary_memory();
+    }
+
+    fn name(&self) -> &str {
+        "Tim Sort"
+    }
+
+    fn get_array(&self) -> &[T] {
+        &self.data
+    }
+
+    fn get_origins(&self) -> Option<&[usize]> {
+        Some(&self.origins)
+    }
+
+    fn get_memory_usage(&self) -> usize {
+        // Data array + merge buffer + run stack
+        self.data.len() * std::mem::size_of::<T>() + self.auxiliary_memory()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,176 @@
+//! Input arrays read from files or stdin instead of generated
+//!
+//! Races real data, such as a dump of production keys, in place of an
+//! [`ArrayGenerator`](crate::services::generator::ArrayGenerator) array.
+
+use crate::models::config::MAX_ARRAY_SIZE;
+use anyhow::{Context, Result, anyhow, bail};
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// How an input file lays out its values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum InputFormat {
+    /// Integers separated by whitespace
+    Text,
+    /// The first column of each row; a header row is skipped
+    Csv,
+    /// A JSON array of integers
+    Json,
+}
+
+impl InputFormat {
+    /// Format implied by a file extension, plain text for anything unknown
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("csv") => InputFormat::Csv,
+            Some("json") => InputFormat::Json,
+            _ => InputFormat::Text,
+        }
+    }
+
+    /// Format of `content` guessed from its first character: JSON if it
+    /// opens an array, plain text otherwise
+    pub fn detect(content: &str) -> Self {
+        if content.trim_start().starts_with('[') {
+            InputFormat::Json
+        } else {
+            InputFormat::Text
+        }
+    }
+}
+
+/// Where an input array comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file on disk
+    File(PathBuf),
+    /// Standard input, written `-` on the command line
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse a command line argument: `-` for stdin, anything else a path
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "" => Err("Input path cannot be empty".to_string()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+
+    /// Read the array, in `format` or else the one the file extension or
+    /// content suggests
+    ///
+    /// Fails if the source cannot be read, a value is not an `i32`, or there
+    /// are no values or more than [`MAX_ARRAY_SIZE`].
+    pub fn load(&self, format: Option<InputFormat>) -> Result<Vec<i32>> {
+        let content = match self {
+            InputSource::File(path) => {
+                std::fs::read_to_string(path).with_context(|| format!("Cannot read input file {}", path.display()))?
+            }
+            InputSource::Stdin => {
+                let mut content = String::new();
+                std::io::stdin().read_to_string(&mut content).context("Cannot read input from stdin")?;
+                content
+            }
+        };
+        let format = format.unwrap_or_else(|| match self {
+            InputSource::File(path) => InputFormat::from_path(path),
+            InputSource::Stdin => InputFormat::detect(&content),
+        });
+
+        parse_array(&content, format).with_context(|| format!("Invalid input from {}", self))
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// Parse `content` laid out as `format` into an array, checking its size
+pub fn parse_array(content: &str, format: InputFormat) -> Result<Vec<i32>> {
+    let values = match format {
+        InputFormat::Text => content
+            .split_whitespace()
+            .map(|token| token.parse().map_err(|_| anyhow!("'{}' is not an integer", token)))
+            .collect::<Result<Vec<i32>>>()?,
+        InputFormat::Csv => parse_csv(content)?,
+        InputFormat::Json => serde_json::from_str(content).context("Expected a JSON array of integers")?,
+    };
+
+    if values.is_empty() {
+        bail!("Input contains no values");
+    }
+    if values.len() > MAX_ARRAY_SIZE {
+        bail!("Input has {} values, more than the maximum of {}", values.len(), MAX_ARRAY_SIZE);
+    }
+    Ok(values)
+}
+
+/// Values in the first column of CSV rows, skipping blank lines and a
+/// header row that is not a number
+fn parse_csv(content: &str) -> Result<Vec<i32>> {
+    let mut values = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let field = line.split(',').next().unwrap_or_default().trim().trim_matches('"');
+        match field.parse() {
+            Ok(value) => values.push(value),
+            Err(_) if index == 0 => {} // Header row
+            Err(_) => bail!("line {}: '{}' is not an integer", index + 1, field),
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_formats() {
+        assert_eq!(parse_array("3 1\n2\t5\n", InputFormat::Text).unwrap(), vec![3, 1, 2, 5]);
+        assert_eq!(parse_array("key,name\n3,c\n\n-1,a\n\"2\",b\n", InputFormat::Csv).unwrap(), vec![3, -1, 2]);
+        assert_eq!(parse_array(" [4, 2, 9] ", InputFormat::Json).unwrap(), vec![4, 2, 9]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_array("1 two 3", InputFormat::Text).is_err());
+        assert!(parse_array("1\nx\n", InputFormat::Csv).is_err());
+        assert!(parse_array("[1, 2.5]", InputFormat::Json).is_err());
+        assert!(parse_array("99999999999", InputFormat::Text).is_err());
+        assert!(parse_array("  \n", InputFormat::Text).is_err());
+        assert!(parse_array("[]", InputFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let at_limit = "1 ".repeat(MAX_ARRAY_SIZE);
+        assert_eq!(parse_array(&at_limit, InputFormat::Text).unwrap().len(), MAX_ARRAY_SIZE);
+
+        let over_limit = "1 ".repeat(MAX_ARRAY_SIZE + 1);
+        assert!(parse_array(&over_limit, InputFormat::Text).is_err());
+    }
+
+    #[test]
+    fn test_format_detection() {
+        assert_eq!(InputFormat::from_path(Path::new("keys.CSV")), InputFormat::Csv);
+        assert_eq!(InputFormat::from_path(Path::new("keys.json")), InputFormat::Json);
+        assert_eq!(InputFormat::from_path(Path::new("keys")), InputFormat::Text);
+        assert_eq!(InputFormat::detect("\n [1]"), InputFormat::Json);
+        assert_eq!(InputFormat::detect("1 2"), InputFormat::Text);
+
+        assert_eq!(InputSource::parse("-").unwrap(), InputSource::Stdin);
+        assert_eq!(InputSource::parse("keys.txt").unwrap(), InputSource::File(PathBuf::from("keys.txt")));
+    }
+}
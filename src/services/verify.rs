@@ -0,0 +1,143 @@
+//! Output verification for completed sorters
+
+use crate::models::element::Comparator;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+
+/// Whether a sorter kept equal keys in their input order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stability {
+    /// Every run of equal keys kept its input order
+    Stable,
+    /// At least one pair of equal keys was reordered
+    Unstable,
+    /// The input had no equal keys or the sorter does not track origins
+    Unknown,
+}
+
+impl fmt::Display for Stability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stability::Stable => write!(f, "stable"),
+            Stability::Unstable => write!(f, "unstable"),
+            Stability::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Verification outcome for a single lane
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaneVerification {
+    /// Why the lane's output failed verification, if it did
+    pub error: Option<String>,
+    /// Stability verdict, when stability checking is enabled
+    pub stability: Option<Stability>,
+}
+
+/// Check that `output` is non-decreasing and a permutation of the race input
+///
+/// `sorted_input` is the race input sorted ascending, so a correct output
+/// must compare equal to it element for element under `compare`.
+pub fn verify_sorted_permutation<T: Debug>(
+    sorted_input: &[T],
+    output: &[T],
+    compare: &Comparator<T>,
+) -> Result<(), String> {
+    if output.len() != sorted_input.len() {
+        return Err(format!(
+            "Output has {} elements but the input had {}",
+            output.len(),
+            sorted_input.len()
+        ));
+    }
+
+    if let Some(index) = output.windows(2).position(|pair| compare.greater(&pair[0], &pair[1])) {
+        return Err(format!(
+            "Output is not sorted at index {} ({:?} > {:?})",
+            index,
+            output[index],
+            output[index + 1]
+        ));
+    }
+
+    let matches_input = output
+        .iter()
+        .zip(sorted_input)
+        .all(|(actual, expected)| compare.compare(actual, expected) == Ordering::Equal);
+    if !matches_input {
+        return Err("Output is not a permutation of the input".to_string());
+    }
+
+    Ok(())
+}
+
+/// Check whether equal keys in a sorted `output` kept their input order
+///
+/// `origins` holds the original input index of each output element, so a
+/// stable sort leaves the origins of every run of equal keys increasing.
+pub fn check_stability<T>(output: &[T], origins: Option<&[usize]>, compare: &Comparator<T>) -> Stability {
+    let Some(origins) = origins.filter(|origins| origins.len() == output.len()) else {
+        return Stability::Unknown;
+    };
+
+    let mut has_ties = false;
+    for index in 1..output.len() {
+        if compare.compare(&output[index - 1], &output[index]) == Ordering::Equal {
+            has_ties = true;
+            if origins[index - 1] > origins[index] {
+                return Stability::Unstable;
+            }
+        }
+    }
+
+    if has_ties {
+        Stability::Stable
+    } else {
+        Stability::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn natural() -> Comparator<i32> {
+        Comparator::natural()
+    }
+
+    #[test]
+    fn test_accepts_sorted_permutation() {
+        assert!(verify_sorted_permutation(&[1, 2, 2, 3], &[1, 2, 2, 3], &natural()).is_ok());
+        assert!(verify_sorted_permutation(&[], &[], &natural()).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unsorted_output() {
+        let error = verify_sorted_permutation(&[1, 2, 3], &[1, 3, 2], &natural()).unwrap_err();
+        assert!(error.contains("index 1"));
+    }
+
+    #[test]
+    fn test_rejects_sorted_output_with_changed_elements() {
+        // Sorted, but a duplicate replaced one of the original values
+        let error = verify_sorted_permutation(&[1, 2, 3], &[1, 1, 3], &natural()).unwrap_err();
+        assert!(error.contains("permutation"));
+
+        assert!(verify_sorted_permutation(&[1, 2, 3], &[1, 2], &natural()).is_err());
+    }
+
+    #[test]
+    fn test_stability_follows_origins_of_equal_keys() {
+        let output = [1, 2, 2, 3];
+        assert_eq!(check_stability(&output, Some(&[3, 0, 2, 1]), &natural()), Stability::Stable);
+        assert_eq!(check_stability(&output, Some(&[3, 2, 0, 1]), &natural()), Stability::Unstable);
+    }
+
+    #[test]
+    fn test_stability_unknown_without_ties_or_origins() {
+        assert_eq!(check_stability(&[1, 2, 3], Some(&[2, 1, 0]), &natural()), Stability::Unknown);
+        assert_eq!(check_stability(&[1, 1], None, &natural()), Stability::Unknown);
+    }
+}
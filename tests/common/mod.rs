@@ -0,0 +1,87 @@
+//! Helpers shared by the sorter integration tests
+
+// Each test crate uses only some of these
+#![allow(dead_code)]
+
+use sorting_race::models::config::Distribution;
+use sorting_race::models::element::Element;
+use sorting_race::models::traits::{BudgetUnit, Sorter, StepResult, Telemetry};
+use sorting_race::services::generator::ArrayGenerator;
+
+/// Budgets every sweep steps its sorters with
+pub const BUDGETS: [usize; 3] = [1, 5, 16];
+
+/// Work a step spent, in the sorter's budget unit
+pub fn spent<T: 'static>(sorter: &dyn Sorter<T>, result: &StepResult) -> usize {
+    match sorter.budget_unit() {
+        BudgetUnit::Comparisons => result.comparisons_used,
+        BudgetUnit::Operations => result.comparisons_used + result.moves_made,
+    }
+}
+
+/// Step `sorter` to completion, checking each step stays within `budget`
+///
+/// Returns the telemetry seen after every step.
+pub fn run<T: Element>(sorter: &mut dyn Sorter<T>, data: Vec<T>, budget: usize) -> Vec<Telemetry> {
+    sorter.reset(data);
+    let mut seen = Vec::new();
+    while !sorter.is_complete() && seen.len() < 500_000 {
+        let result = sorter.step(budget);
+        let spent = spent(sorter, &result);
+        assert!(spent <= budget, "{} spent {} of a budget of {}", sorter.name(), spent, budget);
+        seen.push(sorter.get_telemetry());
+    }
+    assert!(sorter.is_complete(), "{} did not finish", sorter.name());
+    seen
+}
+
+/// Check `sorter` ended with `data` sorted, every element traced back to
+/// its input position
+pub fn assert_sorted(sorter: &dyn Sorter, data: &[i32]) {
+    let mut expected = data.to_vec();
+    expected.sort();
+    assert_eq!(sorter.get_array(), &expected, "{}", sorter.name());
+
+    if let Some(origins) = sorter.get_origins() {
+        assert!(
+            origins.iter().zip(sorter.get_array()).all(|(&origin, value)| data[origin] == *value),
+            "{} lost track of its elements",
+            sorter.name()
+        );
+    }
+}
+
+/// Check equal keys in `sorter`'s array kept their input order
+pub fn assert_stable(sorter: &dyn Sorter) {
+    let origins = sorter.get_origins().expect("sorter tracks origins");
+    for (index, pair) in sorter.get_array().windows(2).enumerate() {
+        if pair[0] == pair[1] {
+            assert!(origins[index] < origins[index + 1], "{} reordered a tie at {}", sorter.name(), index);
+        }
+    }
+}
+
+/// Sort every distribution at each of `sizes` with each of [`BUDGETS`],
+/// checking every sorter `sorters` builds ends with its input sorted
+pub fn sweep(seed: u64, sizes: &[usize], sorters: impl Fn() -> Vec<Box<dyn Sorter>>) {
+    let generator = ArrayGenerator::new(seed);
+    for distribution in Distribution::all() {
+        for &size in sizes {
+            let data = generator.generate(size, &distribution);
+            for budget in BUDGETS {
+                for mut sorter in sorters() {
+                    run(sorter.as_mut(), data.clone(), budget);
+                    assert!(
+                        sorter.get_array().windows(2).all(|pair| pair[0] <= pair[1]),
+                        "{} left {:?} of size {} unsorted with budget {}",
+                        sorter.name(),
+                        distribution,
+                        size,
+                        budget
+                    );
+                    assert_sorted(sorter.as_ref(), &data);
+                }
+            }
+        }
+    }
+}
@@ -230,7 +230,7 @@ mod pause_resume_tests {
     #[test]
     fn test_state_preserved_during_pause_resume() {
         let generator = ArrayGenerator::new(98765);
-        let array = generator.generate(40, &Distribution::NearlySorted);
+        let array = generator.generate(40, &Distribution::NearlySorted { swap_percent: 10.0 });
         let algorithms = create_test_algorithms();
         let fairness_model = Box::new(ComparisonBudget::new(12));
         
@@ -118,7 +118,7 @@ mod tests {
         
         for distribution in [
             Distribution::Shuffled,
-            Distribution::NearlySorted,
+            Distribution::NearlySorted { swap_percent: 10.0 },
             Distribution::Reversed,
             Distribution::FewUnique,
         ] {
@@ -263,7 +263,7 @@ mod fairness_integration_tests {
     #[test]
     fn test_budget_allocation_consistency() {
         let generator = ArrayGenerator::new(77777);
-        let array = generator.generate(40, &Distribution::NearlySorted);
+        let array = generator.generate(40, &Distribution::NearlySorted { swap_percent: 10.0 });
         let mut algorithms = create_test_algorithms();
         initialize_algorithms_with_array(&mut algorithms, array);
 
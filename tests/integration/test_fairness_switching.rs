@@ -294,7 +294,7 @@ mod fairness_switching_tests {
     #[test]
     fn test_fairness_model_consistency_across_switches() {
         let generator = ArrayGenerator::new(99999);
-        let array = generator.generate(25, &Distribution::NearlySorted);
+        let array = generator.generate(25, &Distribution::NearlySorted { swap_percent: 10.0 });
         
         // Create multiple copies of algorithms for consistency testing
         let mut algorithms1 = create_test_algorithms();
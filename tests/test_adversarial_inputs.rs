@@ -0,0 +1,72 @@
+//! Adversarial distribution tests: median-of-three killer and antiquicksort
+
+use sorting_race::models::config::Distribution;
+use sorting_race::models::lane::LaneSpec;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::lanes::create_lanes;
+
+/// Sort `data` with the lane `spec` and return the comparisons it made
+fn comparisons(spec: &str, data: &[i32]) -> u64 {
+    let mut sorter = create_lanes(&[LaneSpec::parse(spec).unwrap()]).remove(0);
+    sorter.reset(data.to_vec());
+    while !sorter.is_complete() {
+        sorter.step(64);
+    }
+
+    let mut expected = data.to_vec();
+    expected.sort();
+    assert_eq!(sorter.get_array(), &expected, "{}", spec);
+    sorter.get_telemetry().total_comparisons
+}
+
+/// `n log2 n`, the comparisons an efficient sort makes up to a constant
+fn n_log_n(size: usize) -> u64 {
+    (size * size.ilog2() as usize) as u64
+}
+
+#[test]
+fn test_median_of_three_killer_makes_median3_quick_sort_quadratic() {
+    let size = 400;
+    let generator = ArrayGenerator::new(1);
+    let killer = generator.generate(size, &Distribution::MedianOfThreeKiller);
+    let shuffled = generator.generate(size, &Distribution::Shuffled);
+
+    let quadratic = (size * size / 8) as u64;
+    assert!(comparisons("quick+median3", &killer) > quadratic);
+    assert!(comparisons("quick+median3", &shuffled) < quadratic / 4);
+
+    // Intro Sort falls back to heap sort and Pdq Sort breaks the pattern up
+    assert!(comparisons("intro", &killer) < 5 * n_log_n(size));
+    assert!(comparisons("pdq", &killer) < 5 * n_log_n(size));
+}
+
+#[test]
+fn test_antiqsort_makes_every_quick_sort_quadratic() {
+    let size = 300;
+    let quadratic = (size * size / 16) as u64;
+    for spec in ["quick", "quick+first", "quick+median3", "quick+random", "quick+median3+hoare", "quick+ninther"] {
+        let distribution = Distribution::antiqsort(LaneSpec::parse(spec).unwrap()).unwrap();
+        let data = ArrayGenerator::new(1).generate(size, &distribution);
+        assert!(comparisons(spec, &data) > quadratic, "{}", spec);
+    }
+}
+
+#[test]
+fn test_introspective_sorts_survive_their_antiqsort() {
+    let size = 300;
+    for spec in ["intro", "pdq"] {
+        let distribution = Distribution::antiqsort(LaneSpec::parse(spec).unwrap()).unwrap();
+        let data = ArrayGenerator::new(1).generate(size, &distribution);
+        assert!(comparisons(spec, &data) < 5 * n_log_n(size), "{}", spec);
+    }
+}
+
+#[test]
+fn test_antiqsort_input_only_targets_its_lane() {
+    // The worst case for Lomuto quicksort is harmless to other sorts
+    let size = 300;
+    let distribution = Distribution::antiqsort(LaneSpec::parse("quick").unwrap()).unwrap();
+    let data = ArrayGenerator::new(1).generate(size, &distribution);
+    assert!(comparisons("merge", &data) < 2 * n_log_n(size));
+    assert!(comparisons("heap", &data) < 3 * n_log_n(size));
+}
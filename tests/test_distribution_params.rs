@@ -0,0 +1,68 @@
+//! Parameterized distribution tests: sweeping presortedness and cardinality
+
+use sorting_race::models::config::{Distribution, DistributionParams};
+use sorting_race::models::lane::LaneSpec;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::lanes::create_lanes;
+
+/// Sort `data` with the lane `spec` and return the comparisons it made
+fn comparisons(spec: &str, data: &[i32]) -> u64 {
+    let mut sorter = create_lanes(&[LaneSpec::parse(spec).unwrap()]).remove(0);
+    sorter.reset(data.to_vec());
+    while !sorter.is_complete() {
+        sorter.step(64);
+    }
+
+    let mut expected = data.to_vec();
+    expected.sort();
+    assert_eq!(sorter.get_array(), &expected, "{}", spec);
+    sorter.get_telemetry().total_comparisons
+}
+
+#[test]
+fn test_swap_percent_sweeps_presortedness() {
+    let generator = ArrayGenerator::new(7);
+    let costs: Vec<u64> = [0.0, 2.0, 10.0, 50.0]
+        .into_iter()
+        .map(|swap_percent| comparisons("insertion", &generator.generate(200, &Distribution::NearlySorted { swap_percent })))
+        .collect();
+
+    // Sorted input costs Insertion Sort one pass; every extra swap adds inversions
+    assert_eq!(costs[0], 199);
+    assert!(costs.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", costs);
+}
+
+#[test]
+fn test_value_range_sweeps_cardinality() {
+    let generator = ArrayGenerator::new(7);
+    for max in [1, 4, 16, 64] {
+        let mut array = generator.generate(500, &Distribution::Uniform { min: 1, max });
+        array.sort();
+        array.dedup();
+        assert_eq!(array, (1..=max).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_runs_are_merged_cheaply() {
+    let generator = ArrayGenerator::new(7);
+    let few_runs = comparisons("tim", &generator.generate(256, &Distribution::Runs { count: 2 }));
+    let many_runs = comparisons("tim", &generator.generate(256, &Distribution::Runs { count: 64 }));
+    assert!(few_runs < many_runs, "{} vs {}", few_runs, many_runs);
+}
+
+#[test]
+fn test_invalid_parameters_are_rejected() {
+    assert!(Distribution::Gaussian { stddev: 0.0 }.validate().is_err());
+    assert!(Distribution::Gaussian { stddev: f64::NAN }.validate().is_err());
+    assert!(Distribution::Zipf { exponent: -1.0 }.validate().is_err());
+    assert!(Distribution::Uniform { min: 5, max: 4 }.validate().is_err());
+    assert!(Distribution::Runs { count: 0 }.validate().is_err());
+    assert!(Distribution::NearlySorted { swap_percent: 101.0 }.validate().is_err());
+
+    assert!(DistributionParams::default().gaussian().validate().is_ok());
+    assert!(DistributionParams::default().zipf().validate().is_ok());
+    assert!(DistributionParams::default().uniform().validate().is_ok());
+    assert!(DistributionParams::default().runs().validate().is_ok());
+    assert_eq!(DistributionParams::default().nearly_sorted(), Distribution::NearlySorted { swap_percent: 10.0 });
+}
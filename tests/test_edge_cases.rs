@@ -3,14 +3,17 @@
 use sorting_race::models::traits::Sorter;
 use sorting_race::services::sorters::{
     bubble::BubbleSort, heap::HeapSort, insertion::InsertionSort, merge::MergeSort,
-    quick::QuickSort, selection::SelectionSort, shell::ShellSort,
+    quick::QuickSort, selection::SelectionSort, shell::ShellSort, tim::TimSort,
+    intro::IntroSort, pdq::PdqSort, radix::{LsdRadixSort, MsdRadixSort}, counting::CountingSort,
+    bucket::BucketSort, cocktail::CocktailSort, comb::CombSort, gnome::GnomeSort, odd_even::OddEvenSort,
+    cycle::CycleSort, network::NetworkSort,
 };
 
 fn test_sorter_with_edge_cases(mut sorter: Box<dyn Sorter>) {
     // Test empty array
     sorter.reset(vec![]);
     assert!(sorter.is_complete());
-    assert_eq!(sorter.get_array(), &[]);
+    assert_eq!(sorter.get_array(), &[] as &[i32]);
 
     // Test single element
     sorter.reset(vec![42]);
@@ -108,6 +111,56 @@ fn test_shell_sort_edge_cases() {
     test_sorter_with_edge_cases(Box::new(ShellSort::new()));
 }
 
+#[test]
+fn test_tim_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(TimSort::new()));
+}
+
+#[test]
+fn test_intro_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(IntroSort::new()));
+}
+
+#[test]
+fn test_pdq_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(PdqSort::new()));
+}
+
+#[test]
+fn test_radix_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(LsdRadixSort::new()));
+    test_sorter_with_edge_cases(Box::new(MsdRadixSort::new()));
+}
+
+#[test]
+fn test_counting_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(CountingSort::new()));
+}
+
+#[test]
+fn test_bucket_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(BucketSort::new()));
+}
+
+#[test]
+fn test_exchange_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(CocktailSort::new()));
+    test_sorter_with_edge_cases(Box::new(CombSort::new()));
+    test_sorter_with_edge_cases(Box::new(GnomeSort::new()));
+    test_sorter_with_edge_cases(Box::new(OddEvenSort::new()));
+}
+
+#[test]
+fn test_cycle_sort_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(CycleSort::new()));
+}
+
+#[test]
+fn test_sorting_network_edge_cases() {
+    test_sorter_with_edge_cases(Box::new(NetworkSort::bitonic()));
+    test_sorter_with_edge_cases(Box::new(NetworkSort::odd_even_merge()));
+}
+
 #[test]
 fn test_edge_case_with_minimal_budget() {
     let mut sorter = QuickSort::new();
@@ -124,3 +177,37 @@ fn test_edge_case_with_minimal_budget() {
     assert!(sorter.is_complete());
     assert_eq!(sorter.get_array(), &[1, 2, 5, 8, 9]);
 }
+
+#[test]
+fn test_heap_sort_completes_with_budget_below_sift_depth() {
+    let mut sorter = HeapSort::new();
+    let data: Vec<i32> = (0..1000).rev().collect();
+    sorter.reset(data);
+
+    // A budget of 4 is smaller than a worst-case sift on 1000 elements
+    let mut steps = 0;
+    while !sorter.is_complete() && steps < 100_000 {
+        let result = sorter.step(4);
+        assert!(result.comparisons_used <= 4);
+        steps += 1;
+    }
+
+    assert!(sorter.is_complete());
+    assert!(sorter.get_array().windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_merge_sort_keeps_elements_when_merges_resume() {
+    let mut sorter = MergeSort::new();
+    sorter.reset((1..=20).rev().collect());
+
+    // Small budgets pause merges after taking from the right half first
+    let mut steps = 0;
+    while !sorter.is_complete() && steps < 1000 {
+        sorter.step(4);
+        steps += 1;
+    }
+
+    assert!(sorter.is_complete());
+    assert_eq!(sorter.get_array(), (1..=20).collect::<Vec<i32>>().as_slice());
+}
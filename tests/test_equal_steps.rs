@@ -0,0 +1,84 @@
+//! Equal-steps fairness tests: lanes advance one operation at a time
+
+mod common;
+
+use common::assert_sorted;
+use sorting_race::lib::controller::RaceController;
+use sorting_race::models::config::{Distribution, FairnessMode, RunConfiguration};
+use sorting_race::models::lane::LaneSpec;
+use sorting_race::models::traits::{BudgetUnit, Granularity};
+use sorting_race::services::fairness::factory::create_fairness_model;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::lanes::create_lanes;
+use sorting_race::services::registry::registered_sorters;
+
+/// Every registered algorithm, then every variant option on its own
+fn every_lane() -> Vec<LaneSpec> {
+    let infos = registered_sorters();
+    let keys = infos.iter().map(|info| info.key.to_string());
+    let variants = infos
+        .iter()
+        .flat_map(|info| info.options.iter().map(move |option| format!("{}+{}", info.key, option)));
+    keys.chain(variants).map(|lane| LaneSpec::parse(&lane).unwrap()).collect()
+}
+
+#[test]
+fn test_every_lane_takes_one_operation_per_step() {
+    let lanes = every_lane();
+    for distribution in [Distribution::Shuffled, Distribution::FewUnique, Distribution::Reversed] {
+        let data = ArrayGenerator::new(12).generate(60, &distribution);
+        let config = RunConfiguration::new().with_array_size(data.len()).with_algorithms(lanes.clone());
+        let mut controller = RaceController::new(create_lanes(&lanes), create_fairness_model(&FairnessMode::EqualSteps), 1);
+        controller.start_race(&config, data.clone()).unwrap();
+
+        let operations = |controller: &RaceController| -> Vec<u64> {
+            controller
+                .get_algorithms()
+                .iter()
+                .map(|algorithm| BudgetUnit::Operations.spent(&algorithm.get_telemetry()))
+                .collect()
+        };
+
+        let mut before = operations(&controller);
+        while controller.step() {
+            let after = operations(&controller);
+            for (index, algorithm) in controller.get_algorithms().iter().enumerate() {
+                assert_eq!(algorithm.granularity(), Granularity::Operation, "{}", algorithm.name());
+                let gained = after[index] - before[index];
+                // A lane that was already done gains nothing
+                assert!(gained <= 1, "{} took {} operations in one step on {:?}", algorithm.name(), gained, distribution);
+            }
+            before = after;
+        }
+
+        for algorithm in controller.get_algorithms() {
+            assert!(algorithm.is_complete(), "{}", algorithm.name());
+            assert_sorted(algorithm.as_ref(), &data);
+        }
+    }
+}
+
+#[test]
+fn test_operation_steps_sort_every_distribution() {
+    let generator = ArrayGenerator::new(5);
+    for distribution in Distribution::all() {
+        for size in [2, 17, 40] {
+            let data = generator.generate(size, &distribution);
+            for budget in [1, 3] {
+                for mut sorter in create_lanes(&every_lane()) {
+                    sorter.reset(data.clone());
+                    let mut steps = 0;
+                    while !sorter.is_complete() && steps < 100_000 {
+                        let result = sorter.step_operations(budget);
+                        let spent = result.comparisons_used + result.moves_made;
+                        assert!(spent <= budget, "{} spent {} of {} operations", sorter.name(), spent, budget);
+                        assert!(spent > 0 || sorter.is_complete(), "{} stalled on {:?}", sorter.name(), distribution);
+                        steps += 1;
+                    }
+                    assert!(sorter.is_complete(), "{} did not finish {:?}", sorter.name(), distribution);
+                    assert_sorted(sorter.as_ref(), &data);
+                }
+            }
+        }
+    }
+}
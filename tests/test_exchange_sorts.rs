@@ -0,0 +1,100 @@
+//! Cocktail Shaker, Comb, Gnome and Odd-Even Sort specific tests
+
+mod common;
+
+use common::{assert_stable, run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::{
+    bubble::BubbleSort, cocktail::CocktailSort, comb::CombSort, gnome::GnomeSort,
+    insertion::InsertionSort, odd_even::OddEvenSort,
+};
+
+fn sorters() -> Vec<Box<dyn Sorter>> {
+    vec![
+        Box::new(CocktailSort::new()),
+        Box::new(CombSort::new()),
+        Box::new(GnomeSort::new()),
+        Box::new(OddEvenSort::new()),
+    ]
+}
+
+#[test]
+fn test_exchange_sorts_sort_every_distribution() {
+    // Odd sizes leave an element unpaired in every other odd-even phase and
+    // Comb Sort's shrinking gaps land differently on each size
+    sweep(23, &[2, 3, 17, 64, 101], sorters);
+}
+
+#[test]
+fn test_adjacent_exchange_sorts_are_stable() {
+    let data = ArrayGenerator::new(4).generate(120, &Distribution::FewUnique);
+    let sorters: [Box<dyn Sorter>; 3] = [Box::new(CocktailSort::new()), Box::new(GnomeSort::new()), Box::new(OddEvenSort::new())];
+    for mut sorter in sorters {
+        run(sorter.as_mut(), data.clone(), 8);
+        assert_stable(sorter.as_ref());
+    }
+}
+
+#[test]
+fn test_exchange_sorts_show_cursors_and_progress() {
+    let data = ArrayGenerator::new(8).generate(50, &Distribution::Shuffled);
+    for mut sorter in sorters() {
+        let seen = run(sorter.as_mut(), data.clone(), 4);
+        assert!(seen.iter().any(|t| t.markers.cursors.len() == 2), "{}", sorter.name());
+        assert!(seen.windows(2).any(|w| w[1].progress_hint > w[0].progress_hint), "{}", sorter.name());
+        assert!(seen.iter().all(|t| (0.0..=1.0).contains(&t.progress_hint)));
+        assert_eq!(seen.last().unwrap().progress_hint, 1.0);
+    }
+}
+
+#[test]
+fn test_exchange_sorts_stop_early_on_sorted_input() {
+    let data = ArrayGenerator::new(1).generate(100, &Distribution::Sorted);
+    for mut sorter in [Box::new(CocktailSort::new()) as Box<dyn Sorter>, Box::new(GnomeSort::new())] {
+        run(sorter.as_mut(), data.clone(), 16);
+        assert_eq!(sorter.get_telemetry().total_comparisons, 99, "{}", sorter.name());
+    }
+
+    // One even and one odd phase confirm every pair is in order
+    let mut odd_even = OddEvenSort::new();
+    run(&mut odd_even, data, 16);
+    assert_eq!(odd_even.get_telemetry().total_comparisons, 99);
+}
+
+#[test]
+fn test_cocktail_sort_moves_turtles_in_one_pass() {
+    // Bubble sort needs a pass per position the 0 has to travel
+    let data: Vec<i32> = (1..100).chain([0]).collect();
+    let mut cocktail = CocktailSort::new();
+    run(&mut cocktail, data.clone(), 16);
+    let mut bubble = BubbleSort::new();
+    run(&mut bubble, data, 16);
+
+    assert!(cocktail.get_telemetry().total_comparisons * 10 < bubble.get_telemetry().total_comparisons);
+}
+
+#[test]
+fn test_comb_sort_beats_quadratic_sorts_on_shuffled_input() {
+    let data = ArrayGenerator::new(12).generate(400, &Distribution::Shuffled);
+    let mut comb = CombSort::new();
+    let seen = run(&mut comb, data.clone(), 16);
+    assert!(seen.iter().any(|t| t.markers.gap.is_some_and(|gap| gap > 100)));
+
+    let mut insertion = InsertionSort::new();
+    run(&mut insertion, data, 16);
+    assert!(comb.get_telemetry().total_comparisons * 4 < insertion.get_telemetry().total_comparisons);
+}
+
+#[test]
+fn test_gnome_sort_swaps_like_insertion_sort() {
+    let data = ArrayGenerator::new(6).generate(80, &Distribution::Shuffled);
+    let mut gnome = GnomeSort::new();
+    run(&mut gnome, data.clone(), 16);
+    let mut insertion = InsertionSort::new();
+    run(&mut insertion, data, 16);
+
+    // Both swap each inversion away exactly once
+    assert_eq!(gnome.get_telemetry().total_moves, insertion.get_telemetry().total_moves);
+}
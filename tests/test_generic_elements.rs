@@ -0,0 +1,113 @@
+//! Races on element types other than i32
+
+use sorting_race::lib::{controller::RaceController, report::RaceReport};
+use sorting_race::models::{
+    config::RunConfiguration,
+    element::{Comparator, Element},
+    lane::LaneSpec,
+    session::AlgorithmType,
+};
+use sorting_race::services::{
+    fairness::factory::create_fairness_model, lanes::create_lanes_with, registry::sorter_info,
+    verify::Stability,
+};
+
+fn race<T: Element>(data: Vec<T>, compare: Comparator<T>) -> RaceController<T> {
+    // Radix-style sorters need integer keys, so only comparison sorts race here
+    let lanes: Vec<LaneSpec> = AlgorithmType::all()
+        .into_iter()
+        .filter(|algorithm| sorter_info(algorithm.key()).is_some_and(|info| info.comparison_based))
+        .map(LaneSpec::new)
+        .collect();
+    let config = RunConfiguration::new()
+        .with_array_size(data.len())
+        .with_algorithms(lanes.clone())
+        .with_stability_check(true);
+
+    let algorithms = create_lanes_with(&lanes, compare.clone()).unwrap();
+    let mut controller = RaceController::with_comparator(
+        algorithms,
+        create_fairness_model(&config.fairness_mode),
+        10,
+        compare,
+    );
+    controller.start_race(&config, data).unwrap();
+    controller.run_to_completion(None);
+    controller
+}
+
+fn assert_all_sorted<T: Element>(controller: &RaceController<T>, expected: &[T], compare: &Comparator<T>) {
+    assert!(controller.get_lane_stats().iter().all(|lane| lane.error().is_none()));
+    for algorithm in controller.get_algorithms() {
+        let sorted = algorithm
+            .get_array()
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| compare.compare(a, b).is_eq());
+        assert!(sorted, "{}", algorithm.name());
+    }
+}
+
+#[test]
+fn test_race_u64() {
+    let data: Vec<u64> = (0..40).map(|i| (i * 7919) % 97 + u64::MAX / 2).collect();
+    let mut expected = data.clone();
+    expected.sort();
+
+    let controller = race(data, Comparator::natural());
+    assert_all_sorted(&controller, &expected, &Comparator::natural());
+}
+
+#[test]
+fn test_race_f64_with_total_ordering() {
+    let data = vec![3.5, -0.0, f64::NAN, 0.0, -2.25, f64::INFINITY, 1e-9, -7.0];
+    let mut expected = data.clone();
+    expected.sort_by(f64::total_cmp);
+
+    let controller = race(data, Comparator::<f64>::total());
+    assert_all_sorted(&controller, &expected, &Comparator::<f64>::total());
+}
+
+#[test]
+fn test_race_strings_reports_ranks_as_bars() {
+    let data: Vec<String> = ["kiwi", "apple", "mango", "fig", "banana", "cherry"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    let controller = race(data, Comparator::natural());
+    let snapshot = controller.get_snapshot_service().get_latest_snapshot().unwrap();
+    for lane in &snapshot.algorithm_snapshots {
+        assert_eq!(lane.array_state, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    let report = RaceReport::from_controller(&controller, &RunConfiguration::new());
+    assert_eq!(report.finish_order.len(), report.results.len());
+}
+
+#[derive(Debug, Clone)]
+struct Order {
+    customer: u32,
+    id: u32,
+}
+
+impl Element for Order {}
+
+#[test]
+fn test_race_structs_with_key_comparator() {
+    let data: Vec<Order> = (0..30).map(|id| Order { customer: id % 3, id }).collect();
+    let compare = Comparator::by_key(|order: &Order| order.customer);
+
+    let controller = race(data, compare);
+    for (algorithm, lane) in controller.get_algorithms().iter().zip(controller.get_lane_stats()) {
+        assert!(lane.error().is_none(), "{}", algorithm.name());
+        assert!(algorithm.get_array().windows(2).all(|w| w[0].customer <= w[1].customer));
+
+        // Orders are created in id order, so a stable sort keeps ids ascending per customer
+        let ids_ascending = algorithm
+            .get_array()
+            .windows(2)
+            .all(|w| w[0].customer != w[1].customer || w[0].id < w[1].id);
+        assert_eq!(lane.stability == Some(Stability::Stable), ids_ascending, "{}", algorithm.name());
+    }
+}
@@ -0,0 +1,104 @@
+//! Heap Sort arity and sift-down variant tests
+
+mod common;
+
+use common::{run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::heap::{HeapSort, SiftDown};
+
+fn variants() -> Vec<HeapSort> {
+    [2, 3, 4]
+        .into_iter()
+        .flat_map(|arity| {
+            [SiftDown::TopDown, SiftDown::BottomUp]
+                .into_iter()
+                .map(move |sift| HeapSort::new().with_arity(arity).with_sift(sift))
+        })
+        .collect()
+}
+
+#[test]
+fn test_heap_variants_sort_every_distribution() {
+    // Sizes just past a full level of a binary, ternary and quaternary heap
+    sweep(8, &[2, 3, 8, 14, 22, 130], || {
+        variants().into_iter().map(|sorter| Box::new(sorter) as Box<dyn Sorter>).collect()
+    });
+}
+
+#[test]
+fn test_sifts_resume_where_the_budget_ran_out() {
+    // A budget of one splits every sift across steps, yet each sift still
+    // makes the same comparisons and moves as one run in a single step
+    let data = ArrayGenerator::new(4).generate(300, &Distribution::Shuffled);
+    for mut sorter in variants() {
+        let whole = run(&mut sorter, data.clone(), usize::MAX).pop().unwrap();
+        for budget in [1, 2, 7] {
+            let split = run(&mut sorter, data.clone(), budget).pop().unwrap();
+            assert_eq!(split.total_comparisons, whole.total_comparisons, "{} budget {}", sorter.name(), budget);
+            assert_eq!(split.total_moves, whole.total_moves, "{} budget {}", sorter.name(), budget);
+        }
+    }
+}
+
+#[test]
+fn test_heap_boundary_shrinks_for_every_variant() {
+    let data = ArrayGenerator::new(3).generate(100, &Distribution::Shuffled);
+    for mut sorter in variants() {
+        let seen = run(&mut sorter, data.clone(), 16);
+        let boundaries: Vec<usize> = seen.iter().filter_map(|t| t.markers.heap_boundary).collect();
+
+        assert!(boundaries.len() > 10, "{}", sorter.name());
+        assert!(boundaries.windows(2).all(|pair| pair[0] >= pair[1]), "{}", sorter.name());
+        assert_eq!(boundaries[0], 100);
+        assert!(*boundaries.last().unwrap() < 100);
+        assert_eq!(seen.last().unwrap().markers.heap_boundary, None);
+    }
+}
+
+#[test]
+fn test_bottom_up_sift_saves_comparisons() {
+    let data = ArrayGenerator::new(5).generate(500, &Distribution::Shuffled);
+    for arity in [2, 3, 4] {
+        let mut top_down = HeapSort::new().with_arity(arity);
+        let mut bottom_up = HeapSort::new().with_arity(arity).with_sift(SiftDown::BottomUp);
+        let top_down = run(&mut top_down, data.clone(), 16).pop().unwrap();
+        let bottom_up = run(&mut bottom_up, data.clone(), 16).pop().unwrap();
+
+        assert!(bottom_up.total_comparisons < top_down.total_comparisons, "{}-ary", arity);
+    }
+
+    // Both sifts move the same elements
+    let top_down = run(&mut HeapSort::new(), data.clone(), 16).pop().unwrap();
+    let bottom_up = run(&mut HeapSort::new().with_sift(SiftDown::BottomUp), data, 16).pop().unwrap();
+    assert_eq!(bottom_up.total_moves, top_down.total_moves);
+}
+
+#[test]
+fn test_wider_heaps_trade_comparisons_for_moves() {
+    let data = ArrayGenerator::new(6).generate(500, &Distribution::Shuffled);
+    let binary = run(&mut HeapSort::new(), data.clone(), 16).pop().unwrap();
+    let quaternary = run(&mut HeapSort::new().with_arity(4), data, 16).pop().unwrap();
+
+    assert!(quaternary.total_moves < binary.total_moves);
+    assert!(quaternary.total_comparisons > binary.total_comparisons);
+}
+
+#[test]
+fn test_heap_variant_names() {
+    let names: Vec<String> = variants().iter().map(|sorter| sorter.name().to_string()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Heap Sort",
+            "Heap Sort (bottom-up)",
+            "Heap Sort (3-ary)",
+            "Heap Sort (3-ary, bottom-up)",
+            "Heap Sort (4-ary)",
+            "Heap Sort (4-ary, bottom-up)",
+        ]
+    );
+    assert!(HeapSort::new().with_option("5ary").is_none());
+}
+
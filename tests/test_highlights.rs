@@ -467,6 +467,8 @@ mod tests {
         let telemetry = Telemetry {
             total_comparisons: 10,
             total_moves: 5,
+            total_writes: 10,
+            parallel_depth: None,
             memory_current: 1024,
             memory_peak: 2048,
             highlights: vec![2, 5, 8], // Telemetry highlights
@@ -476,6 +478,7 @@ mod tests {
                 merge_runs: vec![(6, 9)],
                 cursors: vec![1, 7],
                 gap: None,
+                ..Default::default()
             },
             status_text: "comparing elements".to_string(),
             progress_hint: 0.5,
@@ -512,6 +515,8 @@ mod tests {
         let swap_telemetry = Telemetry {
             total_comparisons: 5,
             total_moves: 3,
+            total_writes: 6,
+            parallel_depth: None,
             memory_current: 512,
             memory_peak: 1024,
             highlights: vec![4, 7],
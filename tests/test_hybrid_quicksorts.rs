@@ -0,0 +1,112 @@
+//! Intro Sort and Pdq Sort specific tests
+
+mod common;
+
+use common::{run, sweep};
+use sorting_race::models::element::Comparator;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::sorters::{intro::IntroSort, pdq::PdqSort, quick::QuickSort};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_hybrid_quicksorts_sort_across_their_thresholds() {
+    // Intro Sort insertion sorts ranges of up to 16 elements and Pdq Sort up
+    // to 24; Pdq Sort picks a ninther pivot from 128 elements
+    sweep(11, &[2, 16, 17, 24, 25, 128, 129, 300], || {
+        vec![Box::new(IntroSort::new()), Box::new(PdqSort::new())]
+    });
+}
+
+#[test]
+fn test_intro_sort_insertion_sorts_small_ranges() {
+    let data: Vec<i32> = (0..200).rev().collect();
+    let mut sorter = IntroSort::new();
+    let seen = run(&mut sorter, data, 8);
+
+    let ranges: Vec<(usize, usize)> = seen.iter().filter_map(|t| t.markers.insertion_range).collect();
+    assert!(!ranges.is_empty());
+    assert!(ranges.iter().all(|&(begin, end)| end - begin < 16), "{:?}", ranges);
+}
+
+#[test]
+fn test_pdq_sort_finishes_sorted_input_in_linear_time() {
+    let data: Vec<i32> = (0..500).collect();
+    let mut sorter = PdqSort::new();
+    let seen = run(&mut sorter, data, 8);
+
+    assert!(seen.iter().any(|t| t.markers.pattern.as_deref() == Some("partial insertion sort")));
+    assert!(sorter.get_telemetry().total_comparisons < 3 * 500);
+}
+
+#[test]
+fn test_pdq_sort_splits_off_equal_keys() {
+    let data: Vec<i32> = (0..400).map(|i| i % 3).collect();
+    let mut pdq = PdqSort::new();
+    let seen = run(&mut pdq, data.clone(), 8);
+    assert!(seen.iter().any(|t| t.markers.pattern.as_deref() == Some("equal keys")));
+
+    let mut quick = QuickSort::new();
+    run(&mut quick, data, 8);
+    assert!(pdq.get_telemetry().total_comparisons * 10 < quick.get_telemetry().total_comparisons);
+}
+
+/// McIlroy's adversary: values are decided lazily so every pivot is bad
+#[derive(Debug)]
+struct Adversary {
+    values: Vec<usize>,
+    gas: usize,
+    solid: usize,
+    candidate: Option<usize>,
+}
+
+impl Adversary {
+    fn comparator(size: usize) -> Comparator<usize> {
+        let state = Arc::new(Mutex::new(Adversary {
+            values: vec![size; size],
+            gas: size,
+            solid: 0,
+            candidate: None,
+        }));
+        Comparator::new(move |&x, &y| {
+            let mut adversary = state.lock().unwrap();
+            let gas = adversary.gas;
+            if adversary.values[x] == gas && adversary.values[y] == gas {
+                let frozen = if adversary.candidate == Some(x) { x } else { y };
+                adversary.values[frozen] = adversary.solid;
+                adversary.solid += 1;
+            }
+            if adversary.values[x] == gas {
+                adversary.candidate = Some(x);
+            } else if adversary.values[y] == gas {
+                adversary.candidate = Some(y);
+            }
+            adversary.values[x].cmp(&adversary.values[y])
+        })
+    }
+}
+
+#[test]
+fn test_intro_sort_falls_back_to_heap_sort_against_an_adversary() {
+    let size = 200;
+    let compare = Adversary::comparator(size);
+    let mut sorter = IntroSort::with_comparator(compare.clone());
+    let seen = run(&mut sorter, (0..size).collect(), 16);
+
+    let fallback = seen.iter().find(|t| t.markers.heapsort_range.is_some()).expect("heapsort fallback");
+    assert_eq!(fallback.markers.mode_label().as_deref(), Some("heapsort fallback"));
+    assert!(sorter.get_array().windows(2).all(|w| !compare.greater(&w[0], &w[1])));
+
+    // Without the fallback the same adversary drives quicksort quadratic
+    let compare = Adversary::comparator(size);
+    let mut quick = QuickSort::with_comparator(compare);
+    run(&mut quick, (0..size).collect(), 16);
+    assert!(sorter.get_telemetry().total_comparisons < quick.get_telemetry().total_comparisons);
+
+    // Pdq Sort breaks the patterns up and stays well below quadratic as well
+    let compare = Adversary::comparator(size);
+    let mut pdq = PdqSort::with_comparator(compare.clone());
+    let seen = run(&mut pdq, (0..size).collect(), 16);
+    assert!(seen.iter().any(|t| t.markers.pattern.is_some()));
+    assert!(pdq.get_array().windows(2).all(|w| !compare.greater(&w[0], &w[1])));
+    assert!(pdq.get_telemetry().total_comparisons < quick.get_telemetry().total_comparisons);
+}
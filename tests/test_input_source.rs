@@ -0,0 +1,62 @@
+//! Tests for racing arrays loaded from files
+
+use sorting_race::lib::controller::RaceController;
+use sorting_race::lib::report::RaceReport;
+use sorting_race::models::{config::RunConfiguration, lane::LaneSpec};
+use sorting_race::services::{
+    fairness::comparison::ComparisonFairness,
+    lanes::create_lanes,
+    source::{InputFormat, InputSource},
+};
+use std::io::Write;
+
+/// Write `content` to a temporary file with the given extension
+fn input_file(content: &str, extension: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(extension).tempfile().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_format_follows_extension() {
+    let csv = input_file("user_id,name\n42,a\n7,b\n19,c\n", ".csv");
+    let source = InputSource::File(csv.path().to_path_buf());
+    assert_eq!(source.load(None).unwrap(), vec![42, 7, 19]);
+
+    let json = input_file("[5, 3, 8]", ".json");
+    assert_eq!(InputSource::File(json.path().to_path_buf()).load(None).unwrap(), vec![5, 3, 8]);
+
+    // An explicit format wins over the extension
+    let text = input_file("5 3 8", ".json");
+    let source = InputSource::File(text.path().to_path_buf());
+    assert!(source.load(None).is_err());
+    assert_eq!(source.load(Some(InputFormat::Text)).unwrap(), vec![5, 3, 8]);
+}
+
+#[test]
+fn test_missing_file_is_an_error() {
+    let source = InputSource::parse("/nonexistent/keys.txt").unwrap();
+    let error = source.load(None).unwrap_err();
+    assert!(format!("{:#}", error).contains("/nonexistent/keys.txt"));
+}
+
+#[test]
+fn test_loaded_input_races() {
+    let file = input_file("9 -4 7 7 0 12 3 -4 1", ".txt");
+    let source = InputSource::File(file.path().to_path_buf());
+    let data = source.load(None).unwrap();
+
+    let lanes = LaneSpec::parse_list("insertion,merge").unwrap();
+    let config = RunConfiguration::new().with_array_size(data.len()).with_algorithms(lanes.clone());
+    let mut controller = RaceController::new(create_lanes(&lanes), Box::new(ComparisonFairness::new(8)), 1);
+    controller.start_race(&config, data).unwrap();
+    controller.run_to_completion(None);
+
+    for algorithm in controller.get_algorithms() {
+        assert_eq!(algorithm.get_array(), &[-4, -4, 0, 1, 3, 7, 7, 9, 12]);
+    }
+
+    let report = RaceReport::from_controller(&controller, &config).with_input(&source);
+    assert_eq!(report.array_size, 9);
+    assert!(report.distribution.starts_with("Input("));
+}
@@ -0,0 +1,111 @@
+//! Merge Sort strategy tests
+
+mod common;
+
+use common::{assert_stable, run, sweep, BUDGETS};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::merge::{MergeSort, MergeStrategy};
+
+const STRATEGIES: [MergeStrategy; 4] = [
+    MergeStrategy::TopDown,
+    MergeStrategy::BottomUp,
+    MergeStrategy::Natural,
+    MergeStrategy::InPlace,
+];
+
+fn strategies() -> Vec<Box<dyn Sorter>> {
+    STRATEGIES
+        .iter()
+        .map(|&strategy| Box::new(MergeSort::new().with_strategy(strategy)) as Box<dyn Sorter>)
+        .collect()
+}
+
+#[test]
+fn test_merge_strategies_sort_every_distribution() {
+    // Powers of two split evenly; odd sizes leave bottom-up a short last run
+    sweep(19, &[2, 3, 17, 64, 130], strategies);
+}
+
+#[test]
+fn test_merge_strategies_are_stable() {
+    // Rotations in the in-place merge must not swap equal keys either
+    let generator = ArrayGenerator::new(19);
+    for distribution in [Distribution::FewUnique, Distribution::WithDuplicates, Distribution::Sawtooth] {
+        let data = generator.generate(130, &distribution);
+        for budget in BUDGETS {
+            for mut sorter in strategies() {
+                run(sorter.as_mut(), data.clone(), budget);
+                assert_stable(sorter.as_ref());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_in_place_merge_needs_no_buffer() {
+    let data = ArrayGenerator::new(4).generate(256, &Distribution::Shuffled);
+    let data_bytes = data.len() * std::mem::size_of::<i32>();
+
+    let mut in_place = MergeSort::new().with_strategy(MergeStrategy::InPlace);
+    let seen = run(&mut in_place, data.clone(), 16);
+    let in_place_peak = seen.iter().map(|t| t.memory_current).max().unwrap();
+    assert!(in_place_peak < data_bytes / 4, "in-place peaked at {} bytes", in_place_peak);
+    assert!(in_place.get_memory_usage() < data_bytes + data_bytes / 4);
+
+    for strategy in [MergeStrategy::TopDown, MergeStrategy::BottomUp, MergeStrategy::Natural] {
+        let mut sorter = MergeSort::new().with_strategy(strategy);
+        run(&mut sorter, data.clone(), 16);
+        assert!(sorter.get_telemetry().memory_peak >= data_bytes, "{}", sorter.name());
+        assert!(sorter.get_memory_usage() >= 2 * data_bytes, "{}", sorter.name());
+    }
+
+    // Rotations pay for the missing buffer with extra moves
+    let mut top_down = MergeSort::new();
+    run(&mut top_down, data, 16);
+    assert!(in_place.get_telemetry().total_moves > top_down.get_telemetry().total_moves);
+}
+
+#[test]
+fn test_natural_merge_exploits_existing_runs() {
+    let generator = ArrayGenerator::new(6);
+    let sorted = generator.generate(300, &Distribution::Sorted);
+    let mut natural = MergeSort::new().with_strategy(MergeStrategy::Natural);
+    run(&mut natural, sorted.clone(), 16);
+    assert_eq!(natural.get_telemetry().total_comparisons, 299);
+    assert_eq!(natural.get_telemetry().total_moves, 0);
+
+    let nearly_sorted = generator.generate(300, &Distribution::NearlySorted { swap_percent: 10.0 });
+    for strategy in [MergeStrategy::TopDown, MergeStrategy::BottomUp] {
+        let mut sorter = MergeSort::new().with_strategy(strategy);
+        run(&mut sorter, nearly_sorted.clone(), 16);
+        run(&mut natural, nearly_sorted.clone(), 16);
+        assert!(
+            natural.get_telemetry().total_comparisons < sorter.get_telemetry().total_comparisons,
+            "{}",
+            sorter.name()
+        );
+    }
+}
+
+#[test]
+fn test_natural_merge_marks_runs() {
+    let data = vec![1, 2, 3, 0, 5, 6, -1, 4];
+    let mut sorter = MergeSort::new().with_strategy(MergeStrategy::Natural);
+    let seen = run(&mut sorter, data, 1);
+
+    assert!(seen.iter().any(|t| t.markers.runs == vec![(0, 2), (3, 5), (6, 7)]));
+    assert!(seen.iter().any(|t| t.status_text.starts_with("Scanning for runs")));
+}
+
+#[test]
+fn test_strategies_have_distinct_names() {
+    let mut names: Vec<String> = STRATEGIES
+        .iter()
+        .map(|&strategy| MergeSort::new().with_strategy(strategy).name().to_string())
+        .collect();
+    assert_eq!(names[0], "Merge Sort");
+    names.dedup();
+    assert_eq!(names.len(), STRATEGIES.len());
+}
@@ -0,0 +1,147 @@
+//! Radix, Counting and Bucket Sort specific tests
+
+mod common;
+
+use common::{assert_sorted, assert_stable, run, sweep, BUDGETS};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::{BudgetUnit, Sorter};
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::{
+    bucket::BucketSort, counting::CountingSort, insertion::InsertionSort,
+    radix::{LsdRadixSort, MsdRadixSort},
+};
+
+fn sorters() -> Vec<Box<dyn Sorter>> {
+    vec![
+        Box::new(LsdRadixSort::new()),
+        Box::new(MsdRadixSort::new()),
+        Box::new(CountingSort::new()),
+        Box::new(BucketSort::new()),
+    ]
+}
+
+#[test]
+fn test_non_comparison_sorts_spend_operations() {
+    assert!(sorters().iter().all(|sorter| sorter.budget_unit() == BudgetUnit::Operations));
+    // Keys grow with the array, so the larger sizes need a second digit pass
+    sweep(13, &[2, 17, 256, 300], sorters);
+}
+
+#[test]
+fn test_non_comparison_sorts_keep_ties_in_order() {
+    let generator = ArrayGenerator::new(13);
+    for distribution in [Distribution::FewUnique, Distribution::WithDuplicates] {
+        let data = generator.generate(200, &distribution);
+        for budget in BUDGETS {
+            for mut sorter in sorters() {
+                run(sorter.as_mut(), data.clone(), budget);
+                assert_sorted(sorter.as_ref(), &data);
+                assert_stable(sorter.as_ref());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_non_comparison_sorts_handle_extreme_keys() {
+    let data = vec![i32::MAX, -1, 0, i32::MIN, 42, -42, i32::MIN, 1, i32::MAX - 1];
+    let mut expected = data.clone();
+    expected.sort();
+
+    for mut sorter in sorters() {
+        run(sorter.as_mut(), data.clone(), 7);
+        assert_eq!(sorter.get_array(), &expected, "{}", sorter.name());
+    }
+}
+
+#[test]
+fn test_radix_sorts_never_compare() {
+    let data = ArrayGenerator::new(3).generate(200, &Distribution::Shuffled);
+    for mut sorter in [Box::new(LsdRadixSort::new()) as Box<dyn Sorter>, Box::new(MsdRadixSort::new()), Box::new(CountingSort::new())] {
+        run(sorter.as_mut(), data.clone(), 16);
+        let telemetry = sorter.get_telemetry();
+        assert_eq!(telemetry.total_comparisons, 0, "{}", sorter.name());
+        assert!(telemetry.total_moves > 0);
+    }
+}
+
+#[test]
+fn test_lsd_radix_sort_reports_digit_passes() {
+    // Keys 0..300 need nine bits, so two byte-wide passes
+    let data: Vec<i32> = (0..300).rev().collect();
+    let mut sorter = LsdRadixSort::new();
+    let seen = run(&mut sorter, data, 16);
+
+    assert!(seen.iter().any(|t| t.markers.digit_pass == Some((2, 2))));
+    assert!(seen.iter().any(|t| t.markers.mode_label().as_deref() == Some("digit pass 1/2")));
+    assert!(seen.iter().any(|t| !t.markers.buckets.is_empty()));
+    // One scan plus counting and writing every element per pass
+    assert_eq!(sorter.get_telemetry().total_moves, 300 * 5);
+}
+
+#[test]
+fn test_non_comparison_sorts_report_auxiliary_buckets() {
+    let data = ArrayGenerator::new(5).generate(256, &Distribution::Shuffled);
+    let data_bytes = data.len() * std::mem::size_of::<i32>();
+
+    for mut sorter in sorters() {
+        let seen = run(sorter.as_mut(), data.clone(), 32);
+        assert!(
+            seen.iter().any(|t| t.memory_current > data_bytes * 2),
+            "{} never showed its buffer",
+            sorter.name()
+        );
+
+        // Buffers are freed once sorting is done
+        let telemetry = sorter.get_telemetry();
+        assert_eq!(sorter.get_memory_usage(), data_bytes, "{}", sorter.name());
+        assert!(telemetry.memory_peak > data_bytes * 2);
+    }
+}
+
+#[test]
+fn test_counting_sort_bounds_its_counts_for_wide_ranges() {
+    let data = vec![i32::MIN, 0, i32::MAX, -7, 7];
+    let mut sorter = CountingSort::new();
+    let seen = run(&mut sorter, data, 4);
+
+    assert!(seen.iter().any(|t| t.markers.digit_pass == Some((2, 2))));
+    assert!(sorter.get_telemetry().memory_peak < 2 * (1 << 16) * std::mem::size_of::<usize>() + 1024);
+}
+
+#[test]
+fn test_bucket_sort_beats_insertion_sort_on_uniform_input() {
+    let data = ArrayGenerator::new(9).generate(400, &Distribution::Shuffled);
+
+    let mut bucket = BucketSort::new();
+    run(&mut bucket, data.clone(), 16);
+    let mut insertion = InsertionSort::new();
+    insertion.reset(data);
+    while !insertion.is_complete() {
+        insertion.step(16);
+    }
+
+    let bucket_work = bucket.get_telemetry().total_comparisons + bucket.get_telemetry().total_moves;
+    assert!(bucket_work < insertion.get_telemetry().total_comparisons);
+}
+
+#[test]
+fn test_non_comparison_sorts_accept_other_integer_keys() {
+    let data: Vec<u64> = (0..100).map(|i| (i * 7919) % 97 + u64::MAX / 2).collect();
+    let mut expected = data.clone();
+    expected.sort();
+
+    let sorters: [Box<dyn Sorter<u64>>; 4] = [
+        Box::new(LsdRadixSort::for_keys()),
+        Box::new(MsdRadixSort::for_keys()),
+        Box::new(CountingSort::for_keys()),
+        Box::new(BucketSort::for_keys()),
+    ];
+    for mut sorter in sorters {
+        sorter.reset(data.clone());
+        while !sorter.is_complete() {
+            sorter.step(16);
+        }
+        assert_eq!(sorter.get_array(), &expected, "{}", sorter.name());
+    }
+}
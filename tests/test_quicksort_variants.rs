@@ -0,0 +1,110 @@
+//! Quick Sort pivot strategy and partition scheme tests
+
+mod common;
+
+use common::{run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::quick::{PartitionScheme, PivotStrategy, QuickSort};
+
+const PIVOTS: [PivotStrategy; 5] = [
+    PivotStrategy::First,
+    PivotStrategy::Last,
+    PivotStrategy::Random { seed: 7 },
+    PivotStrategy::MedianOfThree,
+    PivotStrategy::Ninther,
+];
+
+const SCHEMES: [PartitionScheme; 3] = [PartitionScheme::Lomuto, PartitionScheme::Hoare, PartitionScheme::ThreeWay];
+
+fn variants() -> Vec<QuickSort> {
+    PIVOTS
+        .iter()
+        .flat_map(|&pivot| SCHEMES.iter().map(move |&scheme| QuickSort::new().with_pivot(pivot).with_partition(scheme)))
+        .collect()
+}
+
+#[test]
+fn test_quicksort_variants_sort_every_distribution() {
+    // The ninther samples nine elements from 40 on; smaller ranges use the median of three
+    sweep(17, &[2, 3, 39, 40, 41, 130], || {
+        variants().into_iter().map(|sorter| Box::new(sorter) as Box<dyn Sorter>).collect()
+    });
+}
+
+#[test]
+fn test_median_pivots_avoid_quadratic_sorted_input() {
+    let data = ArrayGenerator::new(3).generate(300, &Distribution::Sorted);
+    let last = run(&mut QuickSort::new(), data.clone(), 16).pop().unwrap().total_comparisons;
+    assert!(last >= 300 * 299 / 2);
+
+    for pivot in [PivotStrategy::MedianOfThree, PivotStrategy::Ninther, PivotStrategy::Random { seed: 1 }] {
+        let comparisons = run(&mut QuickSort::new().with_pivot(pivot), data.clone(), 16).pop().unwrap().total_comparisons;
+        assert!(comparisons * 5 < last, "{:?} took {} comparisons", pivot, comparisons);
+    }
+}
+
+#[test]
+fn test_three_way_partitioning_wins_on_few_unique_keys() {
+    let data = ArrayGenerator::new(5).generate(300, &Distribution::FewUnique);
+    let lomuto = run(&mut QuickSort::new().with_pivot(PivotStrategy::MedianOfThree), data.clone(), 16).pop().unwrap();
+    let three_way = run(
+        &mut QuickSort::new()
+            .with_pivot(PivotStrategy::MedianOfThree)
+            .with_partition(PartitionScheme::ThreeWay),
+        data,
+        16,
+    )
+    .pop()
+    .unwrap();
+
+    assert!(three_way.total_comparisons * 2 < lomuto.total_comparisons);
+}
+
+#[test]
+fn test_hoare_partitioning_splits_equal_keys_evenly() {
+    let data = vec![4; 256];
+    let hoare = run(&mut QuickSort::new().with_partition(PartitionScheme::Hoare), data.clone(), 16).pop().unwrap();
+    let lomuto = run(&mut QuickSort::new(), data, 16).pop().unwrap();
+
+    // Lomuto moves every equal key left, so each partition peels off one element
+    assert!(lomuto.total_comparisons >= 256 * 255 / 2);
+    assert!(hoare.total_comparisons < 256 * 16);
+}
+
+#[test]
+fn test_random_pivot_is_deterministic_per_seed() {
+    let data = ArrayGenerator::new(9).generate(200, &Distribution::Shuffled);
+    let comparisons = |seed| {
+        let mut sorter = QuickSort::new().with_pivot(PivotStrategy::Random { seed });
+        run(&mut sorter, data.clone(), 8);
+        sorter.get_telemetry().total_comparisons
+    };
+
+    assert_eq!(comparisons(42), comparisons(42));
+    // Resetting reseeds the generator
+    let mut sorter = QuickSort::new().with_pivot(PivotStrategy::Random { seed: 42 });
+    run(&mut sorter, data.clone(), 8);
+    let first = sorter.get_telemetry().total_comparisons;
+    run(&mut sorter, data.clone(), 8);
+    assert_eq!(sorter.get_telemetry().total_comparisons, first);
+}
+
+#[test]
+fn test_variants_have_distinct_names() {
+    let mut names: Vec<String> = variants().iter().map(|sorter| sorter.name().to_string()).collect();
+    assert_eq!(names[SCHEMES.len()], "Quick Sort");
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), PIVOTS.len() * SCHEMES.len());
+}
+
+#[test]
+fn test_variants_mark_pivot_while_partitioning() {
+    let data = ArrayGenerator::new(2).generate(64, &Distribution::Shuffled);
+    for mut sorter in variants() {
+        let seen = run(&mut sorter, data.clone(), 4);
+        assert!(seen.iter().any(|t| t.markers.pivot.is_some()), "{}", sorter.name());
+    }
+}
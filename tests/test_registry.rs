@@ -0,0 +1,68 @@
+//! Tests for registering third-party sorters in the global registry
+
+use sorting_race::lib::controller::RaceController;
+use sorting_race::models::{
+    config::RunConfiguration, display_mode::DisplayMode, lane::LaneSpec, session::AlgorithmType,
+    traits::Sorter,
+};
+use sorting_race::services::{
+    fairness::comparison::ComparisonFairness,
+    lanes::create_lanes,
+    registry::{SorterInfo, register_sorter, registered_sorters},
+    sorters::insertion::InsertionSort,
+};
+use std::sync::Once;
+
+static REGISTER: Once = Once::new();
+
+fn register_custom_sorter() {
+    REGISTER.call_once(|| {
+        register_sorter(
+            SorterInfo::new("in-house", "In-House Sort")
+                .with_complexity("O(n)", "O(n²)", "O(n²)")
+                .with_memory("O(1)")
+                .with_stability(true),
+            || Box::new(InsertionSort::new()),
+        )
+        .unwrap();
+    });
+}
+
+#[test]
+fn test_registered_sorter_is_enumerated() {
+    register_custom_sorter();
+
+    let algorithm = AlgorithmType::from_key("in-house").unwrap();
+    assert_eq!(algorithm.to_string(), "In-House Sort");
+    assert!(algorithm.info().stable);
+    assert!(AlgorithmType::all().contains(&algorithm));
+    assert!(registered_sorters().iter().any(|info| info.key == "in-house"));
+
+    // Registered sorters race only when chosen
+    assert!(!LaneSpec::defaults().iter().any(|lane| lane.algorithm == algorithm));
+    assert!(!DisplayMode::new().available_algorithms.contains(&algorithm));
+}
+
+#[test]
+fn test_registered_sorter_races() {
+    register_custom_sorter();
+
+    let lanes = LaneSpec::parse_list("in-house,quick").unwrap();
+    let algorithms: Vec<Box<dyn Sorter>> = create_lanes(&lanes);
+    assert_eq!(algorithms[0].name(), "In-House Sort");
+
+    let mut controller = RaceController::new(algorithms, Box::new(ComparisonFairness::new(8)), 1);
+    let config = RunConfiguration::new().with_array_size(20).with_algorithms(lanes);
+    controller.start_race(&config, (0..20).rev().collect()).unwrap();
+    controller.run_to_completion(None);
+
+    assert!(controller.get_algorithms().iter().all(|algorithm| algorithm.is_complete()));
+}
+
+#[test]
+fn test_duplicate_registration_is_rejected() {
+    register_custom_sorter();
+
+    let result = register_sorter(SorterInfo::new("in-house", "Again"), || Box::new(InsertionSort::new()));
+    assert!(result.is_err());
+}
@@ -1,7 +1,9 @@
 //! Shell Sort specific tests
 
+use sorting_race::models::config::Distribution;
 use sorting_race::models::traits::Sorter;
-use sorting_race::services::sorters::shell::ShellSort;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::shell::{GapSequence, ShellSort};
 
 #[test]
 fn test_shell_sort_basic_sorting() {
@@ -150,3 +152,76 @@ fn test_shell_sort_name() {
     let shell_sort = ShellSort::new();
     assert_eq!(shell_sort.name(), "Shell Sort");
 }
+
+#[test]
+fn test_gap_sequences_match_their_definitions() {
+    assert_eq!(GapSequence::Knuth.gaps(100), vec![40, 13, 4, 1]);
+    assert_eq!(GapSequence::Shell.gaps(100), vec![50, 25, 12, 6, 3, 1]);
+    assert_eq!(GapSequence::Hibbard.gaps(100), vec![63, 31, 15, 7, 3, 1]);
+    assert_eq!(GapSequence::Sedgewick.gaps(100), vec![77, 23, 8, 1]);
+    assert_eq!(GapSequence::Tokuda.gaps(500), vec![233, 103, 46, 20, 9, 4, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(2000), vec![1577, 701, 301, 132, 57, 23, 10, 4, 1]);
+    assert_eq!(GapSequence::Pratt.gaps(13), vec![12, 9, 8, 6, 4, 3, 2, 1]);
+
+    for sequence in GapSequence::all() {
+        assert_eq!(sequence.gaps(0), vec![1]);
+        assert_eq!(GapSequence::from_key(sequence.key()), Some(sequence));
+    }
+}
+
+#[test]
+fn test_every_gap_sequence_sorts_and_reports_its_gaps() {
+    let generator = ArrayGenerator::new(21);
+    for distribution in Distribution::all() {
+        let data = generator.generate(150, &distribution);
+        let mut expected = data.clone();
+        expected.sort();
+
+        for sequence in GapSequence::all() {
+            let mut shell_sort = ShellSort::new().with_gaps(sequence);
+            shell_sort.reset(data.clone());
+            let mut gaps_seen = Vec::new();
+            while !shell_sort.is_complete() {
+                let result = shell_sort.step(3);
+                assert!(result.comparisons_used <= 3);
+                if let Some(gap) = shell_sort.get_telemetry().markers.gap
+                    && gaps_seen.last() != Some(&gap)
+                {
+                    gaps_seen.push(gap);
+                }
+            }
+
+            assert_eq!(shell_sort.get_array(), &expected, "{} {:?}", shell_sort.name(), distribution);
+            // Every pass shows up, except passes finished within a single step
+            assert!(gaps_seen.iter().all(|gap| sequence.gaps(150).contains(gap)));
+            assert!(gaps_seen.windows(2).all(|pair| pair[0] > pair[1]));
+        }
+    }
+}
+
+#[test]
+fn test_gap_sequences_change_comparison_counts() {
+    let data = ArrayGenerator::new(4).generate(1000, &Distribution::Shuffled);
+    let comparisons = |sequence| {
+        let mut shell_sort = ShellSort::new().with_gaps(sequence);
+        shell_sort.reset(data.clone());
+        while !shell_sort.is_complete() {
+            shell_sort.step(64);
+        }
+        shell_sort.get_telemetry().total_comparisons
+    };
+
+    // Shell's halving gaps share factors, so later passes redo work
+    assert!(comparisons(GapSequence::Ciura) < comparisons(GapSequence::Shell));
+    assert!(comparisons(GapSequence::Tokuda) < comparisons(GapSequence::Shell));
+    // Pratt's many passes cost more comparisons on small arrays
+    assert!(comparisons(GapSequence::Pratt) > comparisons(GapSequence::Ciura));
+}
+
+#[test]
+fn test_gap_sequence_names() {
+    assert_eq!(ShellSort::new().with_gaps(GapSequence::Knuth).name(), "Shell Sort");
+    assert_eq!(ShellSort::new().with_gaps(GapSequence::Ciura).name(), "Shell Sort (Ciura)");
+    assert_eq!(ShellSort::new().with_option("pratt").unwrap().gap_sequence(), GapSequence::Pratt);
+    assert!(ShellSort::new().with_option("fibonacci").is_none());
+}
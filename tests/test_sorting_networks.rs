@@ -0,0 +1,91 @@
+//! Bitonic and odd-even merge sorting network tests
+
+mod common;
+
+use common::{run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::network::NetworkSort;
+
+fn networks() -> [NetworkSort; 2] {
+    [NetworkSort::bitonic(), NetworkSort::odd_even_merge()]
+}
+
+#[test]
+fn test_networks_sort_every_distribution() {
+    // Sizes off a power of two exercise the padded comparators
+    sweep(29, &[2, 3, 7, 17, 64, 101], || {
+        networks().into_iter().map(|sorter| Box::new(sorter) as Box<dyn Sorter>).collect()
+    });
+}
+
+#[test]
+fn test_networks_sort_every_zero_one_input() {
+    // A comparator network sorts everything if it sorts every 0-1 input
+    for size in 2..=11 {
+        for bits in 0u32..1 << size {
+            let data: Vec<i32> = (0..size).map(|bit| ((bits >> bit) & 1) as i32).collect();
+            let mut expected = data.clone();
+            expected.sort();
+            for mut sorter in networks() {
+                run(&mut sorter, data.clone(), 64);
+                assert_eq!(sorter.get_array(), &expected, "{} on {:?}", sorter.name(), data);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_networks_are_data_oblivious() {
+    let generator = ArrayGenerator::new(3);
+    // Comparators and depth for 8 and 16 elements
+    let known = [(8, 24, 19, 6), (16, 80, 63, 10)];
+    for (size, bitonic, odd_even_merge, depth) in known {
+        for distribution in Distribution::all() {
+            let data = generator.generate(size, &distribution);
+            let [mut first, mut second] = networks();
+            run(&mut first, data.clone(), 16);
+            run(&mut second, data, 16);
+
+            assert_eq!(first.get_telemetry().total_comparisons, bitonic);
+            assert_eq!(second.get_telemetry().total_comparisons, odd_even_merge);
+            assert_eq!(first.get_telemetry().parallel_depth, Some(depth));
+            assert_eq!(second.get_telemetry().parallel_depth, Some(depth));
+        }
+    }
+}
+
+#[test]
+fn test_layers_are_highlighted_together() {
+    let data = ArrayGenerator::new(8).generate(40, &Distribution::Shuffled);
+    for mut sorter in networks() {
+        let seen = run(&mut sorter, data.clone(), 4);
+        let mut last_depth = 0;
+        for telemetry in seen.iter().filter(|t| !t.markers.comparators.is_empty()) {
+            // Comparators in a layer touch disjoint pairs, all highlighted
+            let mut touched: Vec<usize> = telemetry.markers.comparators.iter().flat_map(|&(low, high)| [low, high]).collect();
+            touched.sort_unstable();
+            assert!(touched.windows(2).all(|w| w[0] < w[1]), "{}", sorter.name());
+            assert_eq!(telemetry.highlights.len(), touched.len());
+            assert!(telemetry.markers.comparators.iter().all(|&(low, high)| low < high && high < 40));
+
+            let (layer, layers) = telemetry.markers.network_layer.unwrap();
+            assert!(layer <= layers);
+            // Depth counts layers started, so the current layer may not be
+            // counted yet
+            let depth = telemetry.parallel_depth.unwrap();
+            assert!(depth >= last_depth && (depth == layer as u64 || depth + 1 == layer as u64));
+            last_depth = depth;
+        }
+        assert!(seen.iter().any(|t| t.markers.comparators.len() > 10));
+        assert_eq!(seen.last().unwrap().progress_hint, 1.0);
+    }
+}
+
+#[test]
+fn test_sequential_sorters_report_no_depth() {
+    let mut sorter = sorting_race::services::sorters::quick::QuickSort::new();
+    run(&mut sorter, vec![3, 1, 2], 16);
+    assert_eq!(sorter.get_telemetry().parallel_depth, None);
+}
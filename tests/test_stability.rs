@@ -0,0 +1,45 @@
+//! Stability checks for the built-in sorters on inputs with many equal keys
+
+use sorting_race::models::{config::Distribution, element::Comparator};
+use sorting_race::services::{
+    generator::ArrayGenerator,
+    registry::{create_sorter, registered_sorters},
+    verify::{Stability, check_stability},
+};
+
+fn run_stability_check(key: &str, data: Vec<i32>) -> Stability {
+    let mut sorter = create_sorter(key).unwrap();
+    sorter.reset(data);
+    while !sorter.is_complete() {
+        sorter.step(8);
+    }
+    check_stability(sorter.get_array(), sorter.get_origins(), &Comparator::natural())
+}
+
+#[test]
+fn test_builtin_stability_matches_metadata() {
+    let data = ArrayGenerator::new(7).generate(200, &Distribution::FewUnique);
+
+    for info in registered_sorters() {
+        let expected = if info.stable { Stability::Stable } else { Stability::Unstable };
+        assert_eq!(run_stability_check(info.key, data.clone()), expected, "{}", info.name);
+    }
+}
+
+#[test]
+fn test_origins_follow_elements() {
+    let data = ArrayGenerator::new(3).generate(100, &Distribution::WithDuplicates);
+
+    for info in registered_sorters() {
+        let mut sorter = create_sorter(info.key).unwrap();
+        sorter.reset(data.clone());
+        while !sorter.is_complete() {
+            sorter.step(5);
+        }
+
+        let origins = sorter.get_origins().unwrap();
+        for (value, &origin) in sorter.get_array().iter().zip(origins) {
+            assert_eq!(*value, data[origin], "{}", info.name);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Tim Sort specific tests
+
+mod common;
+
+use common::{assert_stable, run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::{merge::MergeSort, tim::TimSort};
+
+#[test]
+fn test_tim_sort_sorts_around_the_minimum_run() {
+    // Below 32 elements Tim Sort is one binary insertion sort; from 32 on it
+    // extends short runs and merges them
+    sweep(7, &[2, 31, 32, 33, 65, 200], || vec![Box::new(TimSort::new())]);
+}
+
+#[test]
+fn test_tim_sort_is_stable() {
+    // Few keys spread over many runs, so ties meet in every merge
+    let data: Vec<i32> = (0..300).map(|i| (i * 37) % 5).collect();
+    for budget in [1, 4, 64] {
+        let mut sorter = TimSort::new();
+        run(&mut sorter, data.clone(), budget);
+        assert_stable(&sorter);
+    }
+}
+
+#[test]
+fn test_tim_sort_reports_natural_runs() {
+    // Two ascending runs and a strictly descending one
+    let mut data: Vec<i32> = (0..40).collect();
+    data.extend(20..60);
+    data.extend((0..40).rev());
+
+    let mut sorter = TimSort::new();
+    sorter.reset(data);
+    while sorter.get_telemetry().markers.runs.len() < 3 && !sorter.is_complete() {
+        sorter.step(1);
+    }
+
+    let markers = sorter.get_telemetry().markers;
+    assert_eq!(markers.runs, vec![(0, 39), (40, 79), (80, 119)]);
+    assert!(!markers.run_stack.is_empty());
+
+    // The descending run was reversed in place
+    assert_eq!(&sorter.get_array()[80..83], &[0, 1, 2]);
+}
+
+#[test]
+fn test_tim_sort_gallops_through_interleaved_blocks() {
+    // Alternating blocks make one side win many times in a row
+    let mut data: Vec<i32> = Vec::new();
+    for block in 0..8 {
+        data.extend(block * 20..block * 20 + 10);
+    }
+    for block in 0..8 {
+        data.extend(block * 20 + 10..block * 20 + 20);
+    }
+
+    let mut sorter = TimSort::new();
+    sorter.reset(data);
+    let mut galloped = false;
+    while !sorter.is_complete() {
+        sorter.step(1);
+        galloped |= sorter.get_telemetry().markers.galloping;
+    }
+
+    assert!(galloped);
+    assert!(sorter.get_array().windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_tim_sort_beats_merge_sort_on_nearly_sorted_input() {
+    let data = ArrayGenerator::new(42).generate(500, &Distribution::NearlySorted { swap_percent: 10.0 });
+
+    let mut tim = TimSort::new();
+    run(&mut tim, data.clone(), 16);
+    let mut merge = MergeSort::new();
+    run(&mut merge, data, 16);
+
+    assert!(tim.get_telemetry().total_comparisons < merge.get_telemetry().total_comparisons);
+}
@@ -59,9 +59,11 @@ impl Sorter for MockSorter {
         Telemetry {
             total_comparisons: self.comparisons,
             total_moves: self.moves,
+            total_writes: self.moves * 2,
+            parallel_depth: None,
             memory_current: 1024,
             memory_peak: 2048,
-            highlights: if self.step_count % 2 == 0 {
+            highlights: if self.step_count.is_multiple_of(2) {
                 vec![0, 1]
             } else {
                 vec![2, 3]
@@ -0,0 +1,92 @@
+//! Cycle Sort and write count tests
+
+mod common;
+
+use common::{run, sweep};
+use sorting_race::models::config::Distribution;
+use sorting_race::models::traits::Sorter;
+use sorting_race::services::generator::ArrayGenerator;
+use sorting_race::services::sorters::{
+    bubble::BubbleSort, cycle::CycleSort, heap::HeapSort, insertion::InsertionSort,
+    merge::MergeSort, quick::QuickSort, selection::SelectionSort, shell::ShellSort,
+};
+
+#[test]
+fn test_cycle_sort_sorts_every_distribution() {
+    sweep(31, &[2, 3, 17, 64, 101], || vec![Box::new(CycleSort::new())]);
+}
+
+#[test]
+fn test_cycle_sort_never_writes_more_than_once_per_element() {
+    // Duplicates make Cycle Sort skip past equal keys before each write
+    let generator = ArrayGenerator::new(31);
+    for distribution in Distribution::all() {
+        let data = generator.generate(101, &distribution);
+        let mut sorter = CycleSort::new();
+        run(&mut sorter, data, 5);
+        assert!(sorter.get_telemetry().total_writes <= 101, "{:?}", distribution);
+    }
+}
+
+#[test]
+fn test_cycle_sort_writes_each_misplaced_element_once() {
+    // Distinct keys: every element not already in place is written exactly once
+    let data: Vec<i32> = vec![4, 1, 2, 3, 0, 5, 9, 7, 8, 6];
+    let misplaced = data.iter().enumerate().filter(|&(index, &value)| index as i32 != value).count();
+
+    let mut sorter = CycleSort::new();
+    run(&mut sorter, data, 3);
+    assert_eq!(sorter.get_array(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(sorter.get_telemetry().total_writes, misplaced as u64);
+
+    run(&mut sorter, (0..50).collect(), 3);
+    assert_eq!(sorter.get_telemetry().total_writes, 0);
+}
+
+#[test]
+fn test_swap_sorts_write_two_elements_per_swap() {
+    let data = ArrayGenerator::new(5).generate(80, &Distribution::Shuffled);
+    let sorters: [Box<dyn Sorter>; 5] = [
+        Box::new(BubbleSort::new()),
+        Box::new(InsertionSort::new()),
+        Box::new(SelectionSort::new()),
+        Box::new(ShellSort::new()),
+        Box::new(HeapSort::new()),
+    ];
+    for mut sorter in sorters {
+        run(sorter.as_mut(), data.clone(), 16);
+        let telemetry = sorter.get_telemetry();
+        assert_eq!(telemetry.total_writes, telemetry.total_moves * 2, "{}", sorter.name());
+    }
+}
+
+#[test]
+fn test_merge_sort_counts_buffer_writes() {
+    // Every level copies its range into the buffer and writes it back
+    let size = 64;
+    let data = ArrayGenerator::new(9).generate(size, &Distribution::Shuffled);
+    let mut merge = MergeSort::new();
+    run(&mut merge, data, 16);
+    let levels = size.ilog2() as u64;
+    assert!(merge.get_telemetry().total_writes >= 2 * size as u64 * levels);
+}
+
+#[test]
+fn test_cycle_sort_writes_least_on_shuffled_input() {
+    let data = ArrayGenerator::new(17).generate(120, &Distribution::Shuffled);
+    let mut cycle = CycleSort::new();
+    run(&mut cycle, data.clone(), 16);
+    let cycle_writes = cycle.get_telemetry().total_writes;
+
+    let others: [Box<dyn Sorter>; 5] = [
+        Box::new(SelectionSort::new()),
+        Box::new(InsertionSort::new()),
+        Box::new(HeapSort::new()),
+        Box::new(MergeSort::new()),
+        Box::new(QuickSort::new()),
+    ];
+    for mut sorter in others {
+        run(sorter.as_mut(), data.clone(), 16);
+        assert!(cycle_writes < sorter.get_telemetry().total_writes, "{}", sorter.name());
+    }
+}